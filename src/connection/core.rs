@@ -79,11 +79,11 @@ pub async fn use_database(connection: WrappedConnection, database: String) -> Re
 /// * `password` - The password to be used for signing in
 /// 
 /// # Returns
-/// * `Ok(String)` - Simple message that the connection has been signed into
+/// * `Ok(String)` - The token for the session that was signed into
 pub async fn sign_in(connection: WrappedConnection, username: String, password: String) -> Result<String, String> {
-    connection.connection.signin(Root {
+    let token = connection.connection.signin(Root {
         username: username.as_str(),
         password: password.as_str(),
     }).await.map_err(|e| e.to_string())?;
-    return Ok("signed in".to_string())
+    return Ok(token.into_insecure_token())
 }
@@ -61,7 +61,7 @@ pub fn rust_use_database_future(py: Python, connection: WrappedConnection, datab
 /// * `password` - The password to be used for signing in
 /// 
 /// # Returns
-/// * `Ok(())` - If the sign in was successful
+/// * `Ok(String)` - The token for the session that was signed into
 #[pyfunction]
 pub fn rust_sign_in_future(py: Python, connection: WrappedConnection, username: String, password: String) -> Result<&PyAny, PyErr> {
     py_future_wrapper!(py, sign_in(connection, username, password))
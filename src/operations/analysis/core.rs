@@ -0,0 +1,600 @@
+//! Defines the core functions for analysing a database for operations and admin tooling. In this
+//! module we can do the following:
+//! 
+//! * Report the tables and indexes that a log of queries never used
+//! * Estimate the number of records and the storage size of every table
+//! * Profile the fields of a table or a query with per-field statistics
+//! * Sample the records of a table at random or systematically
+use serde_json::{json, Map};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use ring::rand::{SecureRandom, SystemRandom};
+use surrealdb::sql::statements::SelectStatement;
+use surrealdb::sql::{parse, Explain, Expression, Part, Statement, Thing, Value as SurrealValue};
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::{execute, limit_selects, shape_rows, validate_identifier, ArrayMode, Flatten};
+use crate::operations::reference::core::FETCH_BATCH_SIZE;
+
+/// The number of records sampled per table by `storage_report` if no sample size is given.
+pub const DEFAULT_SAMPLE_SIZE: usize = 1000;
+
+/// The number of most common values reported per field by `profile_rows` if no number is given.
+pub const DEFAULT_TOP_VALUES: usize = 5;
+
+
+/// How `sample_table` picks the records of its sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SampleMethod {
+    /// Every record has the same chance of being picked.
+    Random,
+    /// Every k-th record in ID order is picked starting from a random record of the first k.
+    Systematic,
+}
+
+impl SampleMethod {
+    /// Gets the method from its name.
+    /// 
+    /// # Arguments
+    /// * `name` - The name of the method, `random` or `systematic`
+    /// 
+    /// # Returns
+    /// * `Ok(SampleMethod)` - The method
+    pub fn from_name(name: &str) -> Result<SampleMethod, String> {
+        match name.to_lowercase().as_str() {
+            "random" => Ok(SampleMethod::Random),
+            "systematic" => Ok(SampleMethod::Systematic),
+            other => Err(format!("unknown sample method: {}", other))
+        }
+    }
+}
+
+
+/// What has been seen of a field across the profiled rows.
+#[derive(Default)]
+struct ColumnStats {
+    present: usize,
+    values: HashMap<String, (serde_json::Value, usize)>,
+    min: Option<serde_json::Value>,
+    max: Option<serde_json::Value>,
+}
+
+
+/// Reports the tables and indexes of the database that a log of queries never used, so unused parts
+/// of a schema can be pruned. The tables a query writes to or reads from are taken from its statements
+/// and the indexes a select uses are taken from its query plan. Selects are planned with `EXPLAIN`
+/// against the live database and no other statement is sent to it. Planning computes the sources,
+/// condition, limit and start of a select, so selects with anything there that could run a
+/// statement or a function, such as `SELECT * FROM (DELETE user)`, are not planned. Queries that can
+/// not be parsed and selects that are not or can not be planned are counted as skipped.
+/// 
+/// # Arguments
+/// * `connection` - The connection to analyse the database with
+/// * `queries` - The queries run against the database over the period being analysed
+/// 
+/// # Returns
+/// * `Ok(String)` - An object with the `unused_tables`, the `unused_indexes` as `table.index` and the
+///   number of `skipped` queries
+pub async fn usage_report(connection: WrappedConnection, queries: Vec<String>) -> Result<String, String> {
+    let mut used_tables = BTreeSet::new();
+    let mut used_indexes = BTreeSet::new();
+    let mut skipped = 0;
+    for text in queries {
+        let query = match parse(&text) {
+            Ok(query) => query,
+            Err(_) => {
+                skipped += 1;
+                continue
+            }
+        };
+        for statement in query.0.0 {
+            let what = match &statement {
+                Statement::Select(select) => select.what.0.clone(),
+                Statement::Create(create) => create.what.0.clone(),
+                Statement::Update(update) => update.what.0.clone(),
+                Statement::Delete(delete) => delete.what.0.clone(),
+                Statement::Insert(insert) => vec![insert.into.clone()],
+                Statement::Relate(relate) => vec![relate.kind.clone(), relate.from.clone(), relate.with.clone()],
+                _ => vec![]
+            };
+            used_tables.extend(what.iter().filter_map(table_of));
+
+            if let Statement::Select(mut select) = statement {
+                if !is_plannable(&select) {
+                    skipped += 1;
+                    continue
+                }
+                select.explain = Some(Explain(false));
+                match execute(connection.clone(), Statement::Select(select).to_string(), None).await {
+                    Ok(plans) => {
+                        for step in plans.iter().filter_map(|plan| plan.as_array()).flatten() {
+                            let table = step["detail"]["table"].as_str();
+                            let index = step["detail"]["plan"]["index"].as_str();
+                            if let (Some(table), Some(index)) = (table, index) {
+                                used_indexes.insert(format!("{}.{}", table, index));
+                            }
+                        }
+                    },
+                    Err(_) => skipped += 1
+                }
+            }
+        }
+    }
+
+    let mut unused_tables = vec![];
+    let mut unused_indexes = vec![];
+    for table in defined_keys(connection.clone(), "INFO FOR DB;".to_string(), "tables").await? {
+        if !used_tables.contains(&table) {
+            unused_tables.push(table.clone());
+        }
+        let sql = format!("INFO FOR TABLE {};", table);
+        for index in defined_keys(connection.clone(), sql, "indexes").await? {
+            let index = format!("{}.{}", table, index);
+            if !used_indexes.contains(&index) {
+                unused_indexes.push(index);
+            }
+        }
+    }
+    Ok(json!({"unused_tables": unused_tables, "unused_indexes": unused_indexes, "skipped": skipped}).to_string())
+}
+
+
+/// Checks that planning a select computes nothing that could change the database. The sources must
+/// be tables, records or ranges and the condition, limit and start must not hold subqueries, function
+/// calls or blocks, as these are computed when the select is planned.
+fn is_plannable(select: &SelectStatement) -> bool {
+    select.what.0.iter().all(|what| table_of(what).is_some())
+        && select.cond.as_ref().is_none_or(|cond| is_static(&cond.0))
+        && select.limit.as_ref().is_none_or(|limit| is_static(&limit.0))
+        && select.start.as_ref().is_none_or(|start| is_static(&start.0))
+}
+
+
+/// Checks that computing a value can not run a statement or a function.
+fn is_static(value: &SurrealValue) -> bool {
+    match value {
+        SurrealValue::Subquery(_) | SurrealValue::Function(_) | SurrealValue::Model(_)
+            | SurrealValue::Future(_) | SurrealValue::Block(_) | SurrealValue::Query(_) => false,
+        SurrealValue::Expression(expression) => match expression.as_ref() {
+            Expression::Binary { l, r, .. } => is_static(l) && is_static(r),
+            Expression::Unary { v, .. } => is_static(v)
+        },
+        SurrealValue::Cast(cast) => is_static(&cast.1),
+        SurrealValue::Array(array) => array.iter().all(is_static),
+        SurrealValue::Object(object) => object.values().all(is_static),
+        SurrealValue::Idiom(idiom) => idiom.iter().all(|part| match part {
+            Part::Where(value) | Part::Value(value) | Part::Start(value) => is_static(value),
+            Part::Method(_, arguments) => arguments.iter().all(is_static),
+            Part::Graph(graph) => graph.cond.as_ref().is_none_or(|cond| is_static(&cond.0)),
+            _ => true
+        }),
+        _ => true
+    }
+}
+
+
+/// Estimates the size of every table of the database. The records of each table are counted and the
+/// average serialized size of a sample of them is used to estimate the size of the whole table, so
+/// the estimate is exact for tables no bigger than the sample.
+/// 
+/// # Arguments
+/// * `connection` - The connection to analyse the database with
+/// * `sample_size` - The number of records sampled per table, defaults to `DEFAULT_SAMPLE_SIZE`
+/// 
+/// # Returns
+/// * `Ok(String)` - A list with the `table`, the number of `records`, the number `sampled`, the
+///   `average_bytes` of a record, the `estimated_bytes` of the table and if the estimate is `exact`
+pub async fn storage_report(connection: WrappedConnection, sample_size: Option<usize>) -> Result<String, String> {
+    let sample_size = sample_size.unwrap_or(DEFAULT_SAMPLE_SIZE);
+    if sample_size == 0 {
+        return Err("sample size must be greater than zero".to_string())
+    }
+    let mut report = vec![];
+    for table in defined_keys(connection.clone(), "INFO FOR DB;".to_string(), "tables").await? {
+        let sql = format!("SELECT count() FROM {} GROUP ALL; SELECT * FROM {} LIMIT {};", table, table, sample_size);
+        let outcome = execute(connection.clone(), sql, None).await?;
+        let records = outcome[0][0]["count"].as_u64().unwrap_or(0);
+        let sample = outcome[1].as_array().cloned().unwrap_or_default();
+        let sampled_bytes: usize = sample.iter().map(|record| record.to_string().len()).sum();
+        let average_bytes = if sample.is_empty() { 0 } else { sampled_bytes / sample.len() };
+        let exact = sample.len() as u64 >= records;
+        report.push(json!({
+            "table": table,
+            "records": records,
+            "sampled": sample.len(),
+            "average_bytes": average_bytes,
+            "estimated_bytes": if exact { sampled_bytes as u64 } else { average_bytes as u64 * records },
+            "exact": exact,
+        }));
+    }
+    Ok(serde_json::Value::Array(report).to_string())
+}
+
+
+/// Profiles the fields of a sample of the records of a table, see `profile_rows`.
+/// 
+/// # Arguments
+/// * `connection` - The connection to sample the records with
+/// * `table` - The table to profile
+/// * `sample_size` - The maximum number of records sampled, defaults to `DEFAULT_SAMPLE_SIZE`
+/// * `top` - The number of most common values reported per field, defaults to `DEFAULT_TOP_VALUES`
+/// 
+/// # Returns
+/// * `Ok(String)` - The profile of the sampled records
+pub async fn profile_table(connection: WrappedConnection, table: String, sample_size: Option<usize>, top: Option<usize>) -> Result<String, String> {
+    validate_identifier(&table)?;
+    let sql = "SELECT * FROM type::table($table) LIMIT $limit;".to_string();
+    let bindings = json!({"table": table, "limit": sample_size.unwrap_or(DEFAULT_SAMPLE_SIZE)});
+    let rows = shape_rows(execute(connection, sql, Some(bindings)).await?, None);
+    Ok(profile_rows(&rows, top.unwrap_or(DEFAULT_TOP_VALUES)).to_string())
+}
+
+
+/// Profiles the fields of the rows of a query, see `profile_rows`. Select statements without a limit
+/// are limited to the sample size so the query does not scan more than is profiled.
+/// 
+/// # Arguments
+/// * `connection` - The connection to perform the query on
+/// * `sql` - The query whose rows are profiled
+/// * `bindings` - The bindings to use for the query
+/// * `sample_size` - The maximum number of rows profiled, defaults to `DEFAULT_SAMPLE_SIZE`
+/// * `top` - The number of most common values reported per field, defaults to `DEFAULT_TOP_VALUES`
+/// 
+/// # Returns
+/// * `Ok(String)` - The profile of the rows
+pub async fn profile_query(connection: WrappedConnection, sql: String, bindings: Option<serde_json::Value>, sample_size: Option<usize>, top: Option<usize>) -> Result<String, String> {
+    let sample_size = sample_size.unwrap_or(DEFAULT_SAMPLE_SIZE);
+    let sql = limit_selects(&sql, sample_size as u64)?;
+    let mut rows = shape_rows(execute(connection, sql, bindings).await?, None);
+    rows.truncate(sample_size);
+    Ok(profile_rows(&rows, top.unwrap_or(DEFAULT_TOP_VALUES)).to_string())
+}
+
+
+/// Samples the records of a table. Only the record IDs of the table are read to pick the sample,
+/// the picked records are then fetched by their IDs so the rest of the table is never sent over the
+/// connection. The sample is returned in ID order.
+/// 
+/// # Arguments
+/// * `connection` - The connection to sample the records with
+/// * `table` - The table to sample
+/// * `size` - The number of records in the sample, the whole table if it is not bigger
+/// * `method` - How the records are picked
+/// 
+/// # Returns
+/// * `Ok(String)` - The sampled records
+pub async fn sample_table(connection: WrappedConnection, table: String, size: usize, method: SampleMethod) -> Result<String, String> {
+    validate_identifier(&table)?;
+    let mut response = connection.connection.query("SELECT VALUE id FROM type::table($table);")
+        .bind(("table", table))
+        .await.map_err(|e| e.to_string())?;
+    let ids: SurrealValue = response.take(0).map_err(|e| e.to_string())?;
+    let ids: Vec<Thing> = match ids {
+        SurrealValue::Array(ids) => ids.0.into_iter().filter_map(|id| match id {
+            SurrealValue::Thing(id) => Some(id),
+            _ => None
+        }).collect(),
+        _ => vec![]
+    };
+
+    let picked = pick(ids.len(), size, method)?;
+    let ids: Vec<Thing> = picked.into_iter().map(|index| ids[index].clone()).collect();
+    let mut records = vec![];
+    for batch in ids.chunks(FETCH_BATCH_SIZE) {
+        let mut response = connection.connection.query("SELECT * FROM $ids;")
+            .bind(("ids", batch.to_vec()))
+            .await.map_err(|e| e.to_string())?;
+        let batch: SurrealValue = response.take(0).map_err(|e| e.to_string())?;
+        if let serde_json::Value::Array(batch) = batch.into_json() {
+            records.extend(batch);
+        }
+    }
+    Ok(serde_json::Value::Array(records).to_string())
+}
+
+
+/// Picks the positions of the records of a sample.
+/// 
+/// # Arguments
+/// * `total` - The number of records to pick from
+/// * `size` - The number of records to pick
+/// * `method` - How the records are picked
+/// 
+/// # Returns
+/// * `Ok(Vec<usize>)` - The picked positions in ascending order
+fn pick(total: usize, size: usize, method: SampleMethod) -> Result<Vec<usize>, String> {
+    if size >= total {
+        return Ok((0..total).collect())
+    }
+    if size == 0 {
+        return Ok(vec![])
+    }
+    let rng = SystemRandom::new();
+    let random = |below: usize| -> Result<usize, String> {
+        let mut bytes = [0u8; 8];
+        rng.fill(&mut bytes).map_err(|_| "failed to generate a random number".to_string())?;
+        Ok((u64::from_le_bytes(bytes) % below as u64) as usize)
+    };
+    let mut picked = match method {
+        SampleMethod::Random => {
+            let mut positions: Vec<usize> = (0..total).collect();
+            for index in 0..size {
+                let other = index + random(total - index)?;
+                positions.swap(index, other);
+            }
+            positions.truncate(size);
+            positions
+        },
+        SampleMethod::Systematic => {
+            let step = total / size;
+            let start = random(step)?;
+            (0..size).map(|index| start + index * step).collect()
+        }
+    };
+    picked.sort_unstable();
+    Ok(picked)
+}
+
+
+/// Computes statistics for every field of a list of rows. Nested objects are profiled by their dotted
+/// path such as `address.city`. Each field has the `count` of rows with a value, the number of `nulls`
+/// and the `null_rate` counting rows without the field as null, the number of `distinct` values, the
+/// `min` and `max` of its scalar values and its `top` most common values with their `count`. Numbers
+/// sort before strings for the `min` and `max` of fields with mixed types. When the rows are a sample
+/// the distinct count is that of the sample.
+/// 
+/// # Arguments
+/// * `rows` - The rows to profile
+/// * `top` - The number of most common values reported per field
+/// 
+/// # Returns
+/// * `Value` - An object with the number of rows `sampled` and the statistics of the `fields`
+pub fn profile_rows(rows: &[serde_json::Value], top: usize) -> serde_json::Value {
+    let flatten = Flatten { separator: ".".to_string(), arrays: ArrayMode::Keep };
+    let mut columns: BTreeMap<String, ColumnStats> = BTreeMap::new();
+    for row in rows {
+        if let serde_json::Value::Object(row) = flatten.row(row.clone()) {
+            for (path, value) in row {
+                let column = columns.entry(path).or_default();
+                if value.is_null() {
+                    continue
+                }
+                column.present += 1;
+                if !value.is_array() && !value.is_object() {
+                    if column.min.as_ref().is_none_or(|min| compare(&value, min) == Ordering::Less) {
+                        column.min = Some(value.clone());
+                    }
+                    if column.max.as_ref().is_none_or(|max| compare(&value, max) == Ordering::Greater) {
+                        column.max = Some(value.clone());
+                    }
+                }
+                column.values.entry(value.to_string()).or_insert((value, 0)).1 += 1;
+            }
+        }
+    }
+
+    let mut fields = Map::new();
+    for (path, column) in columns {
+        let distinct = column.values.len();
+        let mut values: Vec<(String, (serde_json::Value, usize))> = column.values.into_iter().collect();
+        values.sort_by(|a, b| (b.1).1.cmp(&(a.1).1).then_with(|| a.0.cmp(&b.0)));
+        let top_values: Vec<serde_json::Value> = values.into_iter()
+            .take(top)
+            .map(|(_, (value, count))| json!({"value": value, "count": count}))
+            .collect();
+        let nulls = rows.len() - column.present;
+        fields.insert(path, json!({
+            "count": column.present,
+            "nulls": nulls,
+            "null_rate": nulls as f64 / rows.len() as f64,
+            "distinct": distinct,
+            "min": column.min,
+            "max": column.max,
+            "top": top_values,
+        }));
+    }
+    json!({"sampled": rows.len(), "fields": fields})
+}
+
+
+/// Orders scalar values with booleans before numbers and numbers before strings.
+fn compare(a: &serde_json::Value, b: &serde_json::Value) -> Ordering {
+    use serde_json::Value;
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()).unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        _ => rank(a).cmp(&rank(b))
+    }
+}
+
+
+/// Gets the position of the type of a scalar value in the order used by `compare`.
+fn rank(value: &serde_json::Value) -> u8 {
+    match value {
+        serde_json::Value::Bool(_) => 0,
+        serde_json::Value::Number(_) => 1,
+        _ => 2
+    }
+}
+
+
+/// Gets the table a statement target refers to, such as `user` for `user`, `user:1` or `user:1..5`.
+pub fn table_of(value: &SurrealValue) -> Option<String> {
+    match value {
+        SurrealValue::Table(table) => Some(table.0.clone()),
+        SurrealValue::Thing(thing) => Some(thing.tb.clone()),
+        SurrealValue::Range(range) => Some(range.tb.clone()),
+        _ => None
+    }
+}
+
+
+/// Gets the names of the definitions of a kind from the result of an `INFO` statement.
+async fn defined_keys(connection: WrappedConnection, sql: String, kind: &str) -> Result<Vec<String>, String> {
+    let info = execute(connection, sql, None).await?;
+    Ok(info.first()
+        .and_then(|info| info[kind].as_object())
+        .map(|definitions| definitions.keys().cloned().collect())
+        .unwrap_or_default())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::core::make_connection;
+    use crate::operations::query::core::query;
+    use tokio::runtime::Runtime;
+    use serde_json::{from_str, Value};
+
+    #[test]
+    fn test_usage_report() {
+        let runtime = Runtime::new().unwrap();
+
+        let (report, posts) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "
+                DEFINE TABLE user; DEFINE TABLE post; DEFINE TABLE audit;
+                DEFINE INDEX email ON user FIELDS email UNIQUE;
+                DEFINE INDEX age ON user FIELDS age;
+                DEFINE INDEX title ON post FIELDS title;
+                CREATE user:1 SET email = 'a', age = 3;
+            ".to_string(), None).await.unwrap();
+
+            let queries = vec![
+                "SELECT * FROM user WHERE email = 'a';".to_string(),
+                "CREATE post:1 SET title = 'x'; DELETE post WHERE title = 'y';".to_string(),
+                "SELECT * FROM user WHERE email = $email;".to_string(),
+                "SELEC nonsense".to_string(),
+                "SELECT * FROM (DELETE user);".to_string(),
+                "SELECT * FROM user WHERE age = (DELETE user:1) LIMIT fn::limit();".to_string(),
+            ];
+            let report: Value = from_str(&usage_report(connection.clone(), queries).await.unwrap()).unwrap();
+            let posts: Value = from_str(&query(connection, "SELECT * FROM post; SELECT VALUE id FROM user;".to_string(), None).await.unwrap()).unwrap();
+            (report, posts)
+        });
+
+        assert_eq!(report, json!({
+            "unused_tables": ["audit"],
+            "unused_indexes": ["post.title", "user.age"],
+            "skipped": 3,
+        }));
+        assert_eq!(posts, json!([[], ["user:1"]]));
+    }
+
+    #[test]
+    fn test_storage_report() {
+        let runtime = Runtime::new().unwrap();
+
+        let (report, invalid) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "
+                DEFINE TABLE empty;
+                FOR $i IN [1, 2, 3, 4] { CREATE type::thing('user', $i) SET name = 'abcd' };
+                CREATE post:1 SET title = 'x';
+            ".to_string(), None).await.unwrap();
+
+            let report: Value = from_str(&storage_report(connection.clone(), Some(2)).await.unwrap()).unwrap();
+            let invalid = storage_report(connection, Some(0)).await;
+            (report, invalid)
+        });
+
+        // {"id":"user:1","name":"abcd"} and {"id":"post:1","title":"x"} are 29 and 27 bytes
+        assert_eq!(report, json!([
+            {"table": "empty", "records": 0, "sampled": 0, "average_bytes": 0, "estimated_bytes": 0, "exact": true},
+            {"table": "post", "records": 1, "sampled": 1, "average_bytes": 27, "estimated_bytes": 27, "exact": true},
+            {"table": "user", "records": 4, "sampled": 2, "average_bytes": 29, "estimated_bytes": 116, "exact": false},
+        ]));
+        assert!(invalid.is_err());
+    }
+
+
+    #[test]
+    fn test_profile_rows() {
+        let rows = vec![
+            json!({"id": "user:1", "age": 30, "name": {"first": "Tobie"}, "tags": ["a"]}),
+            json!({"id": "user:2", "age": 25.5, "name": {"first": "Jaime"}, "tags": ["a"]}),
+            json!({"id": "user:3", "age": null, "name": {"first": "Tobie"}}),
+            json!({"id": "user:4", "age": "unknown"}),
+        ];
+        let profile = profile_rows(&rows, 1);
+        assert_eq!(profile["sampled"], 4);
+        assert_eq!(profile["fields"]["age"], json!({
+            "count": 3, "nulls": 1, "null_rate": 0.25, "distinct": 3, "min": 25.5, "max": "unknown",
+            "top": [{"value": "unknown", "count": 1}]
+        }));
+        assert_eq!(profile["fields"]["name.first"], json!({
+            "count": 3, "nulls": 1, "null_rate": 0.25, "distinct": 2, "min": "Jaime", "max": "Tobie",
+            "top": [{"value": "Tobie", "count": 2}]
+        }));
+        assert_eq!(profile["fields"]["tags"]["min"], Value::Null);
+        assert_eq!(profile["fields"]["tags"]["top"], json!([{"value": ["a"], "count": 2}]));
+        assert_eq!(profile_rows(&[], 5), json!({"sampled": 0, "fields": {}}));
+    }
+
+    #[test]
+    fn test_profile_table_and_query() {
+        let runtime = Runtime::new().unwrap();
+
+        let (table, selected) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "
+                CREATE user:1 SET age = 1;
+                CREATE user:2 SET age = 2;
+                CREATE user:3 SET age = 3;
+            ".to_string(), None).await.unwrap();
+            let table = profile_table(connection.clone(), "user".to_string(), Some(2), None).await.unwrap();
+            let selected = profile_query(connection, "SELECT age FROM user WHERE age > $age;".to_string(), Some(json!({"age": 1})), None, None).await.unwrap();
+            (from_str::<Value>(&table).unwrap(), from_str::<Value>(&selected).unwrap())
+        });
+        assert_eq!(table["sampled"], 2);
+        assert_eq!(table["fields"]["id"]["distinct"], 2);
+        assert_eq!(selected["sampled"], 2);
+        assert_eq!(selected["fields"]["age"]["min"], 2);
+        assert_eq!(selected["fields"]["age"]["max"], 3);
+    }
+
+    #[test]
+    fn test_pick() {
+        assert_eq!(pick(3, 5, SampleMethod::Random).unwrap(), vec![0, 1, 2]);
+        assert!(pick(3, 0, SampleMethod::Systematic).unwrap().is_empty());
+
+        let random = pick(100, 10, SampleMethod::Random).unwrap();
+        assert_eq!(random.len(), 10);
+        assert!(random.windows(2).all(|pair| pair[0] < pair[1]) && random[9] < 100);
+
+        let systematic = pick(100, 10, SampleMethod::Systematic).unwrap();
+        assert!(systematic[0] < 10);
+        assert!(systematic.windows(2).all(|pair| pair[1] - pair[0] == 10));
+        assert!(SampleMethod::from_name("cluster").is_err());
+    }
+
+    #[test]
+    fn test_sample_table() {
+        let runtime = Runtime::new().unwrap();
+
+        let (sample, all) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "
+                CREATE user:1 SET age = 1; CREATE user:2 SET age = 2; CREATE user:3 SET age = 3;
+                CREATE user:4 SET age = 4; CREATE user:5 SET age = 5; CREATE user:6 SET age = 6;
+            ".to_string(), None).await.unwrap();
+            let sample = sample_table(connection.clone(), "user".to_string(), 3, SampleMethod::Systematic).await.unwrap();
+            let all = sample_table(connection, "user".to_string(), 10, SampleMethod::Random).await.unwrap();
+            (from_str::<Value>(&sample).unwrap(), from_str::<Value>(&all).unwrap())
+        });
+        let ages: Vec<i64> = sample.as_array().unwrap().iter().map(|user| user["age"].as_i64().unwrap()).collect();
+        assert_eq!(ages.len(), 3);
+        assert!(ages == vec![1, 3, 5] || ages == vec![2, 4, 6]);
+        assert_eq!(all.as_array().unwrap().len(), 6);
+    }
+}
@@ -0,0 +1,3 @@
+//! Defines the operations for analysing how the tables of a database are used.
+pub mod core;
+pub mod python;
@@ -0,0 +1,90 @@
+//! Python entry points for analysing how the tables of a database are used.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use serde_json::value::Value;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{profile_query, profile_table, sample_table, storage_report, usage_report, SampleMethod};
+use crate::py_future_wrapper;
+
+
+/// Reports the tables and indexes that a log of queries never used in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `queries` - The queries run against the database over the period being analysed
+/// 
+/// # Returns
+/// * `Ok(String)` - The unused tables and indexes
+#[pyfunction]
+pub fn rust_usage_report_future<'a>(py: Python<'a>, connection: WrappedConnection, queries: Vec<String>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, usage_report(connection, queries))
+}
+
+
+/// Estimates the number of records and the storage size of every table in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `sample_size` - The number of records sampled per table
+/// 
+/// # Returns
+/// * `Ok(String)` - The estimate for each table
+#[pyfunction]
+pub fn rust_storage_report_future<'a>(py: Python<'a>, connection: WrappedConnection, sample_size: Option<usize>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, storage_report(connection, sample_size))
+}
+
+
+/// Profiles the fields of a sample of the records of a table in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to profile
+/// * `sample_size` - The maximum number of records sampled
+/// * `top` - The number of most common values reported per field
+/// 
+/// # Returns
+/// * `Ok(String)` - The statistics of each field
+#[pyfunction]
+pub fn rust_profile_table_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, sample_size: Option<usize>, top: Option<usize>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, profile_table(connection, table, sample_size, top))
+}
+
+
+/// Profiles the fields of the rows of a query in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `sql` - The query whose rows are profiled
+/// * `bindings` - The bindings to use for the query
+/// * `sample_size` - The maximum number of rows profiled
+/// * `top` - The number of most common values reported per field
+/// 
+/// # Returns
+/// * `Ok(String)` - The statistics of each field
+#[pyfunction]
+pub fn rust_profile_query_future<'a>(py: Python<'a>, connection: WrappedConnection, sql: String, bindings: Option<&'a PyAny>, sample_size: Option<usize>, top: Option<usize>) -> Result<&'a PyAny, PyErr> {
+    let bindings: Option<Value> = match bindings {
+        Some(bindings) => Some(serde_json::from_str(&bindings.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?),
+        None => None
+    };
+    py_future_wrapper!(py, profile_query(connection, sql, bindings, sample_size, top))
+}
+
+
+/// Samples the records of a table in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to sample
+/// * `size` - The number of records in the sample
+/// * `method` - How the records are picked, `random` or `systematic` (defaults to `random`)
+/// 
+/// # Returns
+/// * `Ok(String)` - The sampled records
+#[pyfunction]
+pub fn rust_sample_table_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, size: usize, method: Option<String>) -> Result<&'a PyAny, PyErr> {
+    let method = SampleMethod::from_name(method.as_deref().unwrap_or("random")).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    py_future_wrapper!(py, sample_table(connection, table, size, method))
+}
@@ -1,9 +1,12 @@
-//! Defines the core functions for the auth operations against the database.
+//! Defines the core functions for the auth operations against the database. SurrealDB 1.x does not
+//! issue refresh tokens, so a session is refreshed by signing in or up again, or by authenticating
+//! with a new token, before the current token expires.
 use surrealdb::opt::auth::Scope;
 use serde_json::value::Value;
 
 use super::interface::WrappedJwt;
 use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::execute;
 
 
 /// Signs up to a specific authentication scope in an async manner.
@@ -16,15 +19,14 @@ use crate::connection::interface::WrappedConnection;
 /// 
 /// # Returns
 /// * `Ok(String)` - The token for the signup
-pub async fn sign_up(connection: WrappedConnection, params: Value, namespace: String, database: String, scope: String) -> Result<WrappedJwt, String> {
+pub async fn sign_up(connection: WrappedConnection, params: Value, namespace: String, database: String, scope: String) -> Result<String, String> {
     let token = connection.connection.signup(Scope {
         namespace: namespace.as_str(),
         database: database.as_str(),
         scope: scope.as_str(),
         params: params,
     }).await.map_err(|e| e.to_string())?;
-    let token = WrappedJwt {jwt: token};
-    return Ok(token)
+    return Ok(token.into_insecure_token())
 }
 
 
@@ -53,3 +55,63 @@ pub async fn authenticate(connection: WrappedConnection, jwt: WrappedJwt) -> Res
     connection.connection.authenticate(jwt.jwt).await.map_err(|e| e.to_string())?;
     return Ok(())
 }
+
+
+/// Gets the authentication information of the current session in an async manner.
+/// 
+/// # Arguments
+/// * `connection` - The connection to get the authentication information for
+/// 
+/// # Returns
+/// * `Ok(String)` - The authenticated record, scope, token claims and session of the connection
+pub async fn auth_info(connection: WrappedConnection) -> Result<String, String> {
+    let sql = "RETURN { auth: $auth, scope: $scope, token: $token, session: $session };".to_string();
+    let mut outcome = execute(connection, sql, None).await?;
+    Ok(outcome.pop().unwrap_or(Value::Null).to_string())
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::operations::query::core::query;
+    use crate::connection::core::{make_connection, sign_in};
+    use tokio::runtime::Runtime;
+    use serde_json::from_str;
+
+
+    #[test]
+    fn test_sign_in_returns_token() {
+        let runtime = Runtime::new().unwrap();
+
+        let token = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            query(connection.clone(), "DEFINE USER tobie ON ROOT PASSWORD 'secret' ROLES OWNER;".to_string(), None).await.unwrap();
+            let token = sign_in(connection.clone(), "tobie".to_string(), "secret".to_string()).await.unwrap();
+            authenticate(connection.clone(), WrappedJwt {jwt: token.clone().into()}).await.unwrap();
+            invalidate(connection).await.unwrap();
+            token
+        });
+
+        assert_eq!(token.split('.').count(), 3);
+    }
+
+    #[test]
+    fn test_auth_info() {
+        let runtime = Runtime::new().unwrap();
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            auth_info(connection).await.unwrap()
+        });
+
+        let outcome: Value = from_str(&outcome).unwrap();
+        assert_eq!(outcome["auth"], Value::Null);
+        assert_eq!(outcome["session"]["ns"], "test_namespace");
+        assert_eq!(outcome["session"]["db"], "test_database");
+    }
+
+}
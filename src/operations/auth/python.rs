@@ -2,10 +2,11 @@
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
 use serde_json::value::Value;
+use surrealdb::opt::auth::Jwt;
 
 use crate::connection::interface::WrappedConnection;
 
-use super::core::{sign_up, invalidate, authenticate};
+use super::core::{sign_up, invalidate, authenticate, auth_info};
 use super::interface::WrappedJwt;
 use crate::py_future_wrapper;
 
@@ -43,11 +44,25 @@ pub fn rust_invalidate_future(py: Python, connection: WrappedConnection) -> Resu
 /// 
 /// # Arguments
 /// * `connection` - The connection to be authenticated
-/// * `jwt` - The JWT token to be used for authentication
+/// * `jwt` - The encoded JWT token to be used for authentication
 /// 
 /// # Returns
 /// * `Ok(())` - The operation was successful
 #[pyfunction]
-pub fn rust_authenticate_future(py: Python, connection: WrappedConnection, jwt: WrappedJwt) -> Result<&PyAny, PyErr> {
+pub fn rust_authenticate_future(py: Python, connection: WrappedConnection, jwt: String) -> Result<&PyAny, PyErr> {
+    let jwt = WrappedJwt {jwt: Jwt::from(jwt)};
     py_future_wrapper!(py, authenticate(connection, jwt))
 }
+
+
+/// Gets the authentication information of the current session.
+/// 
+/// # Arguments
+/// * `connection` - The connection to get the authentication information for
+/// 
+/// # Returns
+/// * `Ok(String)` - The authenticated record, scope, token claims and session of the connection
+#[pyfunction]
+pub fn rust_auth_info_future<'a>(py: Python<'a>, connection: WrappedConnection) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, auth_info(connection))
+}
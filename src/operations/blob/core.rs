@@ -0,0 +1,213 @@
+//! Defines the core functions for a content-addressable blob store. Each blob is a record in the
+//! `blob` table whose ID is the SHA-256 hash of its content, so storing the same content twice
+//! stores it once. Records are linked to blobs with `attachment` edges and blobs without any
+//! attachment can be garbage collected. In this module we can do the following:
+//! 
+//! * Store a blob and get its hash
+//! * Get the content of a blob by its hash
+//! * Link and unlink a record and a blob
+//! * Delete blobs that are not linked to any record
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_json::value::Value;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use surrealdb::sql::thing;
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::execute;
+
+/// The table the blobs are stored in.
+pub const BLOB_TABLE: &str = "blob";
+
+/// The edge table linking records to blobs.
+pub const ATTACHMENT_TABLE: &str = "attachment";
+
+
+/// Stores a blob if a blob with the same content is not already stored.
+/// 
+/// # Arguments
+/// * `connection` - The connection to store the blob with
+/// * `content` - The content of the blob
+/// 
+/// # Returns
+/// * `Ok(String)` - The hex encoded SHA-256 hash of the content
+pub async fn store_blob(connection: WrappedConnection, content: Vec<u8>) -> Result<String, String> {
+    let hash = hex::encode(Sha256::digest(&content));
+    let sql = format!(
+        "INSERT IGNORE INTO {table} {{ id: $hash, content: $content, size: $size, created_at: time::now() }} RETURN NONE;",
+        table = BLOB_TABLE
+    );
+    let bindings = json!({"hash": hash, "content": STANDARD.encode(&content), "size": content.len()});
+    execute(connection, sql, Some(bindings)).await?;
+    Ok(hash)
+}
+
+
+/// Gets the content of a blob base64 encoded as it is stored.
+/// 
+/// # Arguments
+/// * `connection` - The connection to get the blob with
+/// * `hash` - The hash of the blob
+/// 
+/// # Returns
+/// * `Ok(Option<String>)` - The base64 encoded content of the blob or `None` if it is not stored
+pub async fn get_encoded_blob(connection: WrappedConnection, hash: String) -> Result<Option<String>, String> {
+    let sql = format!("SELECT VALUE content FROM {};", blob_record(&hash)?);
+    let mut outcome = execute(connection, sql, None).await?;
+    match outcome.pop() {
+        Some(Value::Array(mut rows)) if !rows.is_empty() => match rows.remove(0) {
+            Value::String(content) => Ok(Some(content)),
+            _ => Err(format!("invalid content for blob {}", hash))
+        },
+        _ => Ok(None)
+    }
+}
+
+
+/// Links a record to a blob, linking the same record and blob twice only links them once.
+/// 
+/// # Arguments
+/// * `connection` - The connection to link the record with
+/// * `record` - The ID of the record such as `user:tobie`
+/// * `hash` - The hash of the blob
+/// 
+/// # Returns
+/// * `Ok(())` - The record was linked to the blob
+pub async fn link_blob(connection: WrappedConnection, record: String, hash: String) -> Result<(), String> {
+    let record = thing(&record).map_err(|e| e.to_string())?;
+    let blob = blob_record(&hash)?;
+
+    // RELATE does not check the blob exists so this is checked first
+    let exists = execute(connection.clone(), format!("SELECT VALUE id FROM {};", blob), None).await?;
+    if exists.first().and_then(|ids| ids.as_array()).is_none_or(|ids| ids.is_empty()) {
+        return Err(format!("blob {} does not exist", hash))
+    }
+    let sql = format!(
+        "LET $links = (SELECT VALUE id FROM {attachment} WHERE in = {record} AND out = {blob});
+        IF $links = [] {{ RELATE {record}->{attachment}->{blob} RETURN NONE }};",
+        blob = blob, attachment = ATTACHMENT_TABLE, record = record
+    );
+    execute(connection, sql, None).await?;
+    Ok(())
+}
+
+
+/// Removes the link between a record and a blob, the blob itself is kept until it is garbage collected.
+/// 
+/// # Arguments
+/// * `connection` - The connection to unlink the record with
+/// * `record` - The ID of the record
+/// * `hash` - The hash of the blob
+/// 
+/// # Returns
+/// * `Ok(())` - The record is not linked to the blob
+pub async fn unlink_blob(connection: WrappedConnection, record: String, hash: String) -> Result<(), String> {
+    let record = thing(&record).map_err(|e| e.to_string())?;
+    let sql = format!(
+        "DELETE {attachment} WHERE in = {record} AND out = {blob};",
+        attachment = ATTACHMENT_TABLE, record = record, blob = blob_record(&hash)?
+    );
+    execute(connection, sql, None).await?;
+    Ok(())
+}
+
+
+/// Deletes the blobs that are not linked to any record.
+/// 
+/// # Arguments
+/// * `connection` - The connection to collect the blobs with
+/// * `min_age` - Blobs stored less than this many seconds ago are kept so they can still be linked
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of blobs deleted
+pub async fn gc_blobs(connection: WrappedConnection, min_age: u64) -> Result<usize, String> {
+    let sql = format!(
+        "DELETE {blob} WHERE array::len(<-{attachment}) = 0 AND created_at <= time::now() - duration::from::secs($min_age) RETURN BEFORE;",
+        blob = BLOB_TABLE, attachment = ATTACHMENT_TABLE
+    );
+    let mut outcome = execute(connection, sql, Some(json!({"min_age": min_age}))).await?;
+    Ok(outcome.pop().and_then(|deleted| deleted.as_array().map(|rows| rows.len())).unwrap_or(0))
+}
+
+
+/// Gets the record ID of a blob after checking the hash is a hex encoded SHA-256 hash.
+fn blob_record(hash: &str) -> Result<String, String> {
+    if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("invalid blob hash: {}", hash))
+    }
+    Ok(format!("{}:`{}`", BLOB_TABLE, hash))
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::operations::query::core::query;
+    use crate::connection::core::make_connection;
+    use tokio::runtime::Runtime;
+    use serde_json::from_str;
+
+
+    #[test]
+    fn test_store_and_get_blob() {
+        let runtime = Runtime::new().unwrap();
+
+        let (first, second, content, missing, count) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            let first = store_blob(connection.clone(), b"hello".to_vec()).await.unwrap();
+            let second = store_blob(connection.clone(), b"hello".to_vec()).await.unwrap();
+            let content = get_encoded_blob(connection.clone(), first.clone()).await.unwrap();
+            let missing = get_encoded_blob(connection.clone(), "0".repeat(64)).await.unwrap();
+            let count = query(connection.clone(), "SELECT count() FROM blob GROUP ALL;".to_string(), None).await.unwrap();
+            (first, second, content, missing, count)
+        });
+
+        assert_eq!(first, "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824");
+        assert_eq!(first, second);
+        assert_eq!(content, Some(STANDARD.encode(b"hello")));
+        assert_eq!(missing, None);
+        let count: Value = from_str(&count).unwrap();
+        assert_eq!(count[0][0]["count"], 1);
+    }
+
+    #[test]
+    fn test_link_and_gc_blobs() {
+        let runtime = Runtime::new().unwrap();
+
+        runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "CREATE user:tobie; CREATE user:jaime;".to_string(), None).await.unwrap();
+            let linked = store_blob(connection.clone(), b"linked".to_vec()).await.unwrap();
+            let unlinked = store_blob(connection.clone(), b"unlinked".to_vec()).await.unwrap();
+
+            link_blob(connection.clone(), "user:tobie".to_string(), linked.clone()).await.unwrap();
+            link_blob(connection.clone(), "user:tobie".to_string(), linked.clone()).await.unwrap();
+            link_blob(connection.clone(), "user:jaime".to_string(), unlinked.clone()).await.unwrap();
+            unlink_blob(connection.clone(), "user:jaime".to_string(), unlinked.clone()).await.unwrap();
+            assert!(link_blob(connection.clone(), "user:tobie".to_string(), "0".repeat(64)).await.is_err());
+
+            let links = query(connection.clone(), "SELECT count() FROM attachment GROUP ALL;".to_string(), None).await.unwrap();
+            let links: Value = from_str(&links).unwrap();
+            assert_eq!(links[0][0]["count"], 1);
+
+            // recently stored blobs are kept
+            assert_eq!(gc_blobs(connection.clone(), 3600).await.unwrap(), 0);
+            assert_eq!(gc_blobs(connection.clone(), 0).await.unwrap(), 1);
+            assert!(get_encoded_blob(connection.clone(), unlinked).await.unwrap().is_none());
+            assert!(get_encoded_blob(connection.clone(), linked).await.unwrap().is_some());
+        });
+    }
+
+    #[test]
+    fn test_invalid_hash() {
+        assert!(blob_record("abc").is_err());
+        assert!(blob_record(&"g".repeat(64)).is_err());
+    }
+
+}
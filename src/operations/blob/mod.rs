@@ -0,0 +1,3 @@
+//! Defines the operations for a content-addressable blob store.
+pub mod core;
+pub mod python;
@@ -0,0 +1,86 @@
+//! Python entry points for the content-addressable blob store.
+use pyo3::prelude::*;
+use pyo3::types::{PyAny, PyBytes};
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{
+    store_blob,
+    get_encoded_blob,
+    link_blob,
+    unlink_blob,
+    gc_blobs
+};
+use crate::py_future_wrapper;
+
+
+/// Stores a blob in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `content` - The content of the blob
+/// 
+/// # Returns
+/// * `Ok(String)` - The hash of the blob
+#[pyfunction]
+pub fn rust_store_blob_future<'a>(py: Python<'a>, connection: WrappedConnection, content: &'a PyBytes) -> Result<&'a PyAny, PyErr> {
+    let content = content.as_bytes().to_vec();
+    py_future_wrapper!(py, store_blob(connection, content))
+}
+
+
+/// Gets the content of a blob in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `hash` - The hash of the blob
+/// 
+/// # Returns
+/// * `Ok(Option<String>)` - The base64 encoded content of the blob or `None` if it is not stored
+#[pyfunction]
+pub fn rust_get_blob_future<'a>(py: Python<'a>, connection: WrappedConnection, hash: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, get_encoded_blob(connection, hash))
+}
+
+
+/// Links a record to a blob in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `record` - The ID of the record
+/// * `hash` - The hash of the blob
+/// 
+/// # Returns
+/// * `Ok(())` - The record was linked to the blob
+#[pyfunction]
+pub fn rust_link_blob_future<'a>(py: Python<'a>, connection: WrappedConnection, record: String, hash: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, link_blob(connection, record, hash))
+}
+
+
+/// Removes the link between a record and a blob in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `record` - The ID of the record
+/// * `hash` - The hash of the blob
+/// 
+/// # Returns
+/// * `Ok(())` - The record is not linked to the blob
+#[pyfunction]
+pub fn rust_unlink_blob_future<'a>(py: Python<'a>, connection: WrappedConnection, record: String, hash: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, unlink_blob(connection, record, hash))
+}
+
+
+/// Deletes the blobs that are not linked to any record in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `min_age` - Blobs stored less than this many seconds ago are kept
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of blobs deleted
+#[pyfunction]
+pub fn rust_gc_blobs_future<'a>(py: Python<'a>, connection: WrappedConnection, min_age: u64) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, gc_blobs(connection, min_age))
+}
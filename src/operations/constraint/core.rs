@@ -0,0 +1,116 @@
+//! Defines the core functions for case-insensitive unique constraints. The constraint is a computed
+//! field holding the normalised (trimmed and lowercased) value with a unique index on it, and lookups
+//! apply the same normalisation so they can use that index. In this module we can do the following:
+//! 
+//! * Define a case-insensitive unique constraint on a field
+//! * Find records by a case-insensitive value of a constrained field
+use serde_json::value::Value;
+use serde_json::json;
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::{execute, validate_field_path, validate_identifier};
+
+
+/// Gets the name of the computed field holding the normalised value of a field.
+/// 
+/// # Arguments
+/// * `field` - The field path being constrained
+/// 
+/// # Returns
+/// * `String` - The name of the computed field such as `email_ci`
+pub fn normalised_field(field: &str) -> String {
+    format!("{}_ci", field.replace('.', "_"))
+}
+
+
+/// Defines a case-insensitive unique constraint on a field of a table.
+/// 
+/// # Arguments
+/// * `connection` - The connection to define the constraint with
+/// * `table` - The table the field belongs to
+/// * `field` - The field path to be unique regardless of case such as `email`
+/// 
+/// # Returns
+/// * `Ok(())` - The constraint was defined
+pub async fn define_ci_unique(connection: WrappedConnection, table: String, field: String) -> Result<(), String> {
+    validate_identifier(&table)?;
+    validate_field_path(&field)?;
+    let normalised = normalised_field(&field);
+    let sql = format!(
+        "DEFINE FIELD {normalised} ON TABLE {table} VALUE IF type::is::string({field}) THEN string::lowercase(string::trim({field})) ELSE NONE END; \
+         DEFINE INDEX {table}_{normalised}_unique ON TABLE {table} FIELDS {normalised} UNIQUE;",
+        normalised = normalised, table = table, field = field
+    );
+    execute(connection, sql, None).await?;
+    Ok(())
+}
+
+
+/// Finds the records of a table whose field matches a value regardless of case and surrounding whitespace.
+/// 
+/// # Arguments
+/// * `connection` - The connection to find the records with
+/// * `table` - The table to search
+/// * `field` - The constrained field path
+/// * `value` - The value to match
+/// 
+/// # Returns
+/// * `Ok(String)` - The matching records
+pub async fn find_by_ci(connection: WrappedConnection, table: String, field: String, value: String) -> Result<String, String> {
+    validate_field_path(&field)?;
+    let sql = format!(
+        "SELECT * FROM type::table($table) WHERE {} = string::lowercase(string::trim($value));",
+        normalised_field(&field)
+    );
+    let mut outcome = execute(connection, sql, Some(json!({"table": table, "value": value}))).await?;
+    Ok(outcome.pop().unwrap_or(Value::Array(vec![])).to_string())
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::operations::query::core::query;
+    use crate::connection::core::make_connection;
+    use tokio::runtime::Runtime;
+    use serde_json::from_str;
+
+
+    #[test]
+    fn test_ci_unique() {
+        let runtime = Runtime::new().unwrap();
+
+        let (duplicate, without_email, found) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            define_ci_unique(connection.clone(), "user".to_string(), "email".to_string()).await.unwrap();
+            query(connection.clone(), "CREATE user:1 SET email = 'Tobie@SurrealDB.com';".to_string(), None).await.unwrap();
+            let duplicate = query(connection.clone(), "CREATE user:2 SET email = ' tobie@surrealdb.com';".to_string(), None).await;
+            let without_email = query(connection.clone(), "CREATE user:3 SET name = 'Jaime'; CREATE user:4 SET name = 'Dave';".to_string(), None).await;
+            let found = find_by_ci(connection, "user".to_string(), "email".to_string(), "TOBIE@surrealdb.com ".to_string()).await.unwrap();
+            (duplicate, without_email, found)
+        });
+
+        assert!(duplicate.is_err());
+        assert!(without_email.is_ok());
+        let found: Value = from_str(&found).unwrap();
+        assert_eq!(found.as_array().unwrap().len(), 1);
+        assert_eq!(found[0]["id"], "user:1");
+        assert_eq!(found[0]["email"], "Tobie@SurrealDB.com");
+    }
+
+    #[test]
+    fn test_define_ci_unique_invalid_table() {
+        let runtime = Runtime::new().unwrap();
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            define_ci_unique(connection, "user; REMOVE TABLE user".to_string(), "email".to_string()).await
+        });
+        assert!(outcome.is_err());
+    }
+
+}
@@ -0,0 +1,3 @@
+//! Defines the operations for setting up and querying constraints on tables.
+pub mod core;
+pub mod python;
@@ -0,0 +1,37 @@
+//! Python entry points for setting up and querying constraints on tables.
+use pyo3::prelude::*;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{define_ci_unique, find_by_ci};
+use crate::py_future_wrapper;
+
+
+/// Defines a case-insensitive unique constraint on a field of a table in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table the field belongs to
+/// * `field` - The field path to be unique regardless of case
+/// 
+/// # Returns
+/// * `Ok(())` - The constraint was defined
+#[pyfunction]
+pub fn rust_define_ci_unique_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, field: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, define_ci_unique(connection, table, field))
+}
+
+
+/// Finds the records of a table whose field matches a value regardless of case in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to search
+/// * `field` - The constrained field path
+/// * `value` - The value to match
+/// 
+/// # Returns
+/// * `Ok(String)` - The matching records
+#[pyfunction]
+pub fn rust_find_by_ci_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, field: String, value: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, find_by_ci(connection, table, field, value))
+}
@@ -0,0 +1,143 @@
+//! Defines the core functions for atomic counters and sequences. Every change is performed by a single
+//! `UPDATE` statement so concurrent callers never race on a read-modify-write. In this module we can do
+//! the following:
+//! 
+//! * Increment or decrement a numeric field on a record
+//! * Get the next value of a named sequence
+use serde_json::value::Value;
+use serde_json::json;
+use surrealdb::sql::thing;
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::{execute, validate_field_path};
+
+
+/// The table the named sequences are stored in.
+pub const SEQUENCE_TABLE: &str = "sequence";
+
+/// The number of times a counter update is retried when the transaction conflicts.
+const MAX_RETRIES: usize = 5;
+
+
+/// Atomically adds an amount to a numeric field of a record, creating the record if needed.
+/// 
+/// # Arguments
+/// * `connection` - The connection to perform the update with
+/// * `record` - The ID of the record holding the counter such as `counter:visits`
+/// * `field` - The field of the counter
+/// * `amount` - The amount to add, negative amounts decrement the counter
+/// 
+/// # Returns
+/// * `Ok(String)` - The value of the counter after the update
+pub async fn increment(connection: WrappedConnection, record: String, field: String, amount: Value) -> Result<String, String> {
+    let record = thing(&record).map_err(|e| e.to_string())?;
+    validate_field_path(&field)?;
+    if !amount.is_number() {
+        return Err(format!("counter amount must be a number: {}", amount))
+    }
+    let sql = format!("UPDATE {} SET {} += $amount RETURN VALUE {};", record, field, field);
+
+    let mut attempt = 0;
+    loop {
+        match execute(connection.clone(), sql.clone(), Some(json!({"amount": amount}))).await {
+            Ok(mut outcome) => {
+                let value = match outcome.pop() {
+                    Some(Value::Array(mut values)) => values.pop().unwrap_or(Value::Null),
+                    Some(other) => other,
+                    None => Value::Null
+                };
+                return Ok(value.to_string())
+            },
+            Err(error) if attempt < MAX_RETRIES && is_conflict(&error) => attempt += 1,
+            Err(error) => return Err(error)
+        }
+    }
+}
+
+
+/// Gets the next value of a named sequence, starting at 1.
+/// 
+/// # Arguments
+/// * `connection` - The connection to perform the update with
+/// * `name` - The name of the sequence
+/// 
+/// # Returns
+/// * `Ok(String)` - The next value of the sequence
+pub async fn next_value(connection: WrappedConnection, name: String) -> Result<String, String> {
+    let record = surrealdb::sql::Thing::from((SEQUENCE_TABLE, name.as_str()));
+    increment(connection, record.to_string(), "value".to_string(), json!(1)).await
+}
+
+
+/// Checks if an error was caused by a conflicting transaction and can be retried.
+/// 
+/// # Arguments
+/// * `error` - The error message returned by the database
+/// 
+/// # Returns
+/// * `bool` - True if the operation can be retried
+fn is_conflict(error: &str) -> bool {
+    let error = error.to_lowercase();
+    error.contains("conflict") || error.contains("can be retried")
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::connection::core::make_connection;
+    use tokio::runtime::Runtime;
+    use serde_json::from_str;
+
+
+    #[test]
+    fn test_increment() {
+        let runtime = Runtime::new().unwrap();
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            increment(connection.clone(), "counter:visits".to_string(), "total".to_string(), json!(5)).await.unwrap();
+            increment(connection.clone(), "counter:visits".to_string(), "total".to_string(), json!(2)).await.unwrap();
+            increment(connection, "counter:visits".to_string(), "total".to_string(), json!(-3)).await.unwrap()
+        });
+
+        let outcome: Value = from_str(&outcome).unwrap();
+        assert_eq!(outcome, 4);
+    }
+
+    #[test]
+    fn test_increment_invalid_field() {
+        let runtime = Runtime::new().unwrap();
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            increment(connection, "counter:visits".to_string(), "total = 0; DELETE user".to_string(), json!(1)).await
+        });
+        assert!(outcome.is_err());
+    }
+
+    #[test]
+    fn test_next_value() {
+        let runtime = Runtime::new().unwrap();
+
+        let (first, second, other) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            let first = next_value(connection.clone(), "invoice".to_string()).await.unwrap();
+            let second = next_value(connection.clone(), "invoice".to_string()).await.unwrap();
+            let other = next_value(connection, "order number".to_string()).await.unwrap();
+            (first, second, other)
+        });
+
+        assert_eq!(first, "1");
+        assert_eq!(second, "2");
+        assert_eq!(other, "1");
+    }
+
+}
@@ -0,0 +1,3 @@
+//! Defines the operations for atomic counters and named sequences stored in the database.
+pub mod core;
+pub mod python;
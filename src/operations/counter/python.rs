@@ -0,0 +1,39 @@
+//! Python entry points for atomic counters and sequences.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use serde_json::value::Value;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{increment, next_value};
+use crate::py_future_wrapper;
+
+
+/// Atomically adds an amount to a numeric field of a record in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `record` - The ID of the record holding the counter
+/// * `field` - The field of the counter
+/// * `amount` - The amount to add, negative amounts decrement the counter
+/// 
+/// # Returns
+/// * `Ok(String)` - The value of the counter after the update
+#[pyfunction]
+pub fn rust_increment_future<'a>(py: Python<'a>, connection: WrappedConnection, record: String, field: String, amount: &'a PyAny) -> Result<&'a PyAny, PyErr> {
+    let amount: Value = serde_json::from_str(&amount.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    py_future_wrapper!(py, increment(connection, record, field, amount))
+}
+
+
+/// Gets the next value of a named sequence in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `name` - The name of the sequence
+/// 
+/// # Returns
+/// * `Ok(String)` - The next value of the sequence
+#[pyfunction]
+pub fn rust_next_value_future<'a>(py: Python<'a>, connection: WrappedConnection, name: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, next_value(connection, name))
+}
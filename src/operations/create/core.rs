@@ -3,12 +3,17 @@
 //! module we can do the following:
 //! 
 //! * Create a record in the database
+//! * Insert a list of records into a table in chunks
 use serde_json::value::Value;
 use surrealdb::opt::Resource;
 use surrealdb::sql::Range;
 use crate::connection::interface::WrappedConnection;
 
 
+/// The default number of records sent to the database in a single insert.
+pub const DEFAULT_INSERT_CHUNK_SIZE: usize = 1000;
+
+
 /// Creates a record in the database.
 /// 
 /// # Arguments
@@ -25,6 +30,57 @@ pub async fn create(connection: WrappedConnection, table_name: String, data: Val
 }
 
 
+/// An insert that failed part way. The chunks before the failing one were inserted and are not rolled
+/// back, so their IDs are kept to let the caller retry only the records that were not inserted.
+#[derive(Debug)]
+pub struct InsertError {
+    pub error: String,
+    pub inserted: Vec<Value>,
+}
+
+impl From<InsertError> for String {
+    fn from(error: InsertError) -> String {
+        match error.inserted.len() {
+            0 => error.error,
+            inserted => format!("{} after inserting {} records", error.error, inserted)
+        }
+    }
+}
+
+
+/// Inserts a list of records into a table in chunks so each request stays bounded. Each chunk is
+/// inserted atomically but the chunks are not, see `InsertError`.
+/// 
+/// # Arguments
+/// * `connection` - The connection performing the operation on the database
+/// * `table_name` - The name of the table to insert the records into
+/// * `records` - The records to be inserted
+/// * `chunk_size` - The maximum number of records sent per insert, defaults to `DEFAULT_INSERT_CHUNK_SIZE`
+/// 
+/// # Returns
+/// * `Ok(String)` - A JSON list of the IDs of the inserted records
+pub async fn insert(connection: WrappedConnection, table_name: String, records: Vec<Value>, chunk_size: Option<usize>) -> Result<String, InsertError> {
+    let chunk_size = chunk_size.unwrap_or(DEFAULT_INSERT_CHUNK_SIZE);
+    let mut ids = Vec::with_capacity(records.len());
+    if chunk_size == 0 {
+        return Err(InsertError {error: "chunk size must be greater than zero".to_string(), inserted: ids})
+    }
+    for chunk in records.chunks(chunk_size) {
+        let outcome = connection.connection.insert(Resource::from(table_name.as_str()))
+                                           .content(Value::Array(chunk.to_vec()))
+                                           .await;
+        let outcome = match outcome {
+            Ok(outcome) => outcome,
+            Err(error) => return Err(InsertError {error: error.to_string(), inserted: ids})
+        };
+        if let Value::Array(created) = outcome.into_json() {
+            ids.extend(created.into_iter().map(|record| record["id"].clone()));
+        }
+    }
+    Ok(Value::Array(ids).to_string())
+}
+
+
 /// Delete all records, or a specific record
 ///
 /// # Arguments
@@ -53,7 +109,7 @@ mod tests {
     use crate::operations::query::core::query;
     use crate::connection::core::make_connection;
 	use tokio::runtime::Runtime;
-    use serde_json::{from_str, Value};
+    use serde_json::{from_str, json, Value};
 
 
     fn generate_json(name: &str, age: i32) -> Value {
@@ -110,6 +166,62 @@ mod tests {
         assert_eq!(outcome[0].as_array().unwrap().len(), 2);
     }
 
+    #[test]
+    fn test_insert() {
+        let runtime = Runtime::new().unwrap();
+        let records: Vec<Value> = (0..5).map(|i| generate_json("John Doe", i)).collect();
+
+        let (ids, outcome) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+            let ids = insert(connection.clone(), "user".to_string(), records, Some(2)).await.unwrap();
+            (ids, query(connection.clone(), "SELECT * FROM user;".to_string(), None).await.unwrap())
+        });
+
+        let ids: Value = from_str(&ids).unwrap();
+        let outcome: Value = from_str(&outcome).unwrap();
+        assert_eq!(ids.as_array().unwrap().len(), 5);
+        assert!(ids[0].as_str().unwrap().starts_with("user:"));
+        assert_eq!(outcome[0].as_array().unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_insert_zero_chunk_size() {
+        let runtime = Runtime::new().unwrap();
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            insert(connection, "user".to_string(), vec![generate_json("John Doe", 1)], Some(0)).await
+        });
+        assert!(outcome.is_err());
+    }
+
+    #[test]
+    fn test_insert_keeps_inserted_ids_on_error() {
+        let runtime = Runtime::new().unwrap();
+        let records = vec![
+            json!({"id": "first", "age": 1}),
+            json!({"id": "second", "age": 2}),
+            json!({"id": "third", "age": "three"}),
+        ];
+
+        let (outcome, stored) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "DEFINE TABLE user SCHEMAFULL; DEFINE FIELD age ON user TYPE int;".to_string(), None).await.unwrap();
+            let outcome = insert(connection.clone(), "user".to_string(), records, Some(2)).await;
+            (outcome, query(connection.clone(), "SELECT * FROM user;".to_string(), None).await.unwrap())
+        });
+
+        let error = outcome.unwrap_err();
+        assert_eq!(error.inserted, vec![json!("user:first"), json!("user:second")]);
+        assert!(String::from(error).ends_with("after inserting 2 records"));
+        let stored: Value = from_str(&stored).unwrap();
+        assert_eq!(stored[0].as_array().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_all_records() {
         let runtime = Runtime::new().unwrap();
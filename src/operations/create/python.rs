@@ -4,7 +4,7 @@ use pyo3::types::PyAny;
 use serde_json::value::Value;
 
 use crate::connection::interface::WrappedConnection;
-use super::core::{create, delete};
+use super::core::{create, delete, insert};
 use crate::py_future_wrapper;
 
 
@@ -25,6 +25,29 @@ pub fn rust_create_future<'a>(py: Python<'a>, connection: WrappedConnection, tab
 }
 
 
+/// Inserts a list of records into a table in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table_name` - The name of the table to insert the records into
+/// * `data` - A JSON list of the records to be inserted
+/// * `chunk_size` - The maximum number of records sent per insert
+/// 
+/// # Returns
+/// * `Ok(String)` - A JSON list of the IDs of the inserted records
+/// * `Err(PyRuntimeError)` - Raised with the error and a JSON list of the IDs of the records inserted
+///   before the error as its two arguments
+#[pyfunction]
+pub fn rust_insert_future<'a>(py: Python<'a>, connection: WrappedConnection, table_name: String, data: &'a PyAny, chunk_size: Option<usize>) -> Result<&'a PyAny, PyErr> {
+    let data: Vec<Value> = serde_json::from_str(&data.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        insert(connection, table_name, data, chunk_size).await.map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.error, Value::Array(e.inserted).to_string()))
+        })
+    })
+}
+
+
 /// Deletes a record or range of records from the database in an non-async manner.
 /// 
 /// # Arguments
@@ -0,0 +1,161 @@
+//! Defines the core functions for diagnosing problems with the extension and a connection. The checks
+//! do not stop at the first failure but report on every step so a bug report can include the whole
+//! report. In this module we can do the following:
+//! 
+//! * Make a test connection to a URL and read the version of the database
+//! * Round trip every supported type through a query and compare what comes back
+use serde_json::value::Value;
+use serde_json::json;
+
+use crate::connection::core::make_connection;
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::execute;
+
+
+/// The URL that is connected to if no URL is given.
+const DEFAULT_URL: &str = "memory";
+
+/// The namespace and database used for an in-memory test connection.
+const DIAGNOSTICS_NAME: &str = "diagnostics";
+
+
+/// The values that are bound to a query and should come back unchanged.
+fn bound_checks() -> Vec<(&'static str, Value)> {
+    vec![
+        ("null", Value::Null),
+        ("bool", json!(true)),
+        ("int", json!(9007199254740993u64)),
+        ("float", json!(1.5)),
+        ("string", json!("héllo ⟨wörld⟩ \"quoted\"\n")),
+        ("array", json!([1, "two", null, [3.5]])),
+        ("object", json!({"nested": {"a": [1]}, "empty": {}})),
+    ]
+}
+
+
+/// The SurrealQL values with no JSON equivalent and what they should be converted to.
+fn literal_checks() -> Vec<(&'static str, &'static str, Value)> {
+    vec![
+        ("record", "user:diagnostics", json!("user:diagnostics")),
+        ("datetime", "<datetime> \"2024-01-02T03:04:05Z\"", json!("2024-01-02T03:04:05Z")),
+        ("uuid", "<uuid> \"0189d3f3-6cbe-7ab2-9c2f-62f3c6e1f8a1\"", json!("0189d3f3-6cbe-7ab2-9c2f-62f3c6e1f8a1")),
+        ("duration", "1h30m", json!("1h30m")),
+        ("decimal", "1.25dec", json!("1.25")),
+        ("geometry", "(1.5, 2.5)", json!({"type": "Point", "coordinates": [1.5, 2.5]})),
+    ]
+}
+
+
+/// Runs the diagnostics and reports on each step. Failures are recorded in the report rather than
+/// returned as errors.
+/// 
+/// # Arguments
+/// * `url` - The URL to make a test connection to, an in-memory database is used if `None`
+/// 
+/// # Returns
+/// * `Ok(String)` - The report with whether everything is `ok`, the `version` of the extension and
+///   the results of the `connection` and `serialization` checks
+pub async fn diagnostics(url: Option<String>) -> Result<String, String> {
+    let url = url.unwrap_or_else(|| DEFAULT_URL.to_string());
+    let (connection, connection_report) = test_connection(&url).await;
+    let serialization_report = match connection {
+        Some(connection) => round_trip(connection).await,
+        None => json!({"ok": false, "error": "no connection to run the checks on"})
+    };
+    Ok(json!({
+        "ok": connection_report["ok"] == json!(true) && serialization_report["ok"] == json!(true),
+        "version": env!("CARGO_PKG_VERSION"),
+        "connection": connection_report,
+        "serialization": serialization_report,
+    }).to_string())
+}
+
+
+/// Makes a test connection and reads the version of the database.
+/// 
+/// # Arguments
+/// * `url` - The URL to connect to
+/// 
+/// # Returns
+/// * `(Option<WrappedConnection>, Value)` - The connection if it could be made and the report on it
+async fn test_connection(url: &str) -> (Option<WrappedConnection>, Value) {
+    let connection = match make_connection(url.to_string()).await {
+        Ok(connection) => connection,
+        Err(error) => return (None, json!({"url": url, "ok": false, "error": error}))
+    };
+    if url == DEFAULT_URL {
+        let selected = async {
+            connection.connection.use_ns(DIAGNOSTICS_NAME).await?;
+            connection.connection.use_db(DIAGNOSTICS_NAME).await
+        }.await;
+        if let Err(error) = selected {
+            return (None, json!({"url": url, "ok": false, "error": error.to_string()}))
+        }
+    }
+    let report = match connection.connection.version().await {
+        Ok(version) => json!({"url": url, "ok": true, "server_version": version.to_string()}),
+        Err(error) => json!({"url": url, "ok": false, "error": error.to_string()})
+    };
+    (Some(connection), report)
+}
+
+
+/// Sends every supported type through a query and checks what comes back.
+/// 
+/// # Arguments
+/// * `connection` - The connection to run the queries on
+/// 
+/// # Returns
+/// * `Value` - Whether every type came back as expected and what was sent and received for each type
+async fn round_trip(connection: WrappedConnection) -> Value {
+    let mut checks = vec![];
+    for (name, value) in bound_checks() {
+        let received = execute(connection.clone(), "RETURN $value;".to_string(), Some(json!({"value": value}))).await;
+        checks.push((name, value, received));
+    }
+    for (name, literal, expected) in literal_checks() {
+        let received = execute(connection.clone(), format!("RETURN {};", literal), None).await;
+        checks.push((name, expected, received));
+    }
+
+    let mut ok = true;
+    let mut types = serde_json::Map::new();
+    for (name, expected, received) in checks {
+        let report = match received.map(|mut output| output.swap_remove(0)) {
+            Ok(received) => json!({"ok": received == expected, "expected": expected, "received": received}),
+            Err(error) => json!({"ok": false, "expected": expected, "error": error})
+        };
+        ok &= report["ok"] == json!(true);
+        types.insert(name.to_string(), report);
+    }
+    json!({"ok": ok, "types": types})
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+    use tokio::runtime::Runtime;
+
+    #[test]
+    fn test_diagnostics() {
+        let runtime = Runtime::new().unwrap();
+
+        let (memory, unreachable) = runtime.block_on(async {
+            let memory: Value = from_str(&diagnostics(None).await.unwrap()).unwrap();
+            let unreachable: Value = from_str(&diagnostics(Some("nothing://localhost".to_string())).await.unwrap()).unwrap();
+            (memory, unreachable)
+        });
+
+        assert_eq!(memory["ok"], json!(true));
+        assert_eq!(memory["connection"]["url"], json!("memory"));
+        assert!(memory["connection"]["server_version"].is_string());
+        assert_eq!(memory["serialization"]["types"].as_object().unwrap().len(), 13);
+
+        assert_eq!(unreachable["ok"], json!(false));
+        assert_eq!(unreachable["connection"]["ok"], json!(false));
+        assert!(unreachable["connection"]["error"].is_string());
+        assert_eq!(unreachable["serialization"]["ok"], json!(false));
+    }
+}
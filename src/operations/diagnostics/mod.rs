@@ -0,0 +1,3 @@
+//! Defines the operations for checking that the extension and a database connection work end to end.
+pub mod core;
+pub mod python;
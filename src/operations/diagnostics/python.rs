@@ -0,0 +1,19 @@
+//! Python entry points for checking that the extension and a database connection work end to end.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+
+use super::core::diagnostics;
+use crate::py_future_wrapper;
+
+
+/// Runs the diagnostics in an non-async manner.
+/// 
+/// # Arguments
+/// * `url` - The URL to make a test connection to, an in-memory database is used if `None`
+/// 
+/// # Returns
+/// * `Ok(String)` - The report of the connection and serialization checks
+#[pyfunction]
+pub fn rust_diagnostics_future<'a>(py: Python<'a>, url: Option<String>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, diagnostics(url))
+}
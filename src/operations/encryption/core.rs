@@ -0,0 +1,277 @@
+//! Defines the core functions for client-side field encryption. Each value is serialised to JSON and
+//! sealed with AES-256-GCM using the table and field path as associated data, so an encrypted value
+//! cannot be moved to another field or table unnoticed. The record ID is not bound as the same data
+//! can be written to a whole table and new records only get their ID from the database, so a value
+//! can still be copied between records of the same table and field. Fields that have to stay
+//! joinable are tokenized instead with an HMAC-SHA256 of the value, which is the same for the same
+//! value and key but cannot be reversed.
+//! In this module we can do the following:
+//! 
+//! * Encrypt fields of a record or a list of records
+//! * Decrypt fields of a record or a list of records
+//! * Tokenize fields of a record or a list of records
+//! * Tokenize a single value to look up tokenized fields
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::value::Value;
+
+use crate::operations::query::core::validate_field_path;
+
+/// The prefix of a string holding an encrypted value.
+pub const ENCRYPTED_PREFIX: &str = "enc:v2:";
+
+/// The prefix of a string holding a token.
+pub const TOKEN_PREFIX: &str = "tok:v1:";
+
+
+/// Encrypts the fields of a record or a list of records.
+/// 
+/// # Arguments
+/// * `data` - The record or list of records
+/// * `fields` - The field paths to encrypt such as `ssn` or `card.number`
+/// * `key` - The base64 encoded 32 byte key
+/// * `table` - The table the data is written to
+/// 
+/// # Returns
+/// * `Ok(Value)` - The data with the fields replaced by encrypted strings, missing and null fields
+///   and values that already decrypt with the key are left alone
+pub fn encrypt_fields(mut data: Value, fields: Vec<String>, key: String, table: String) -> Result<Value, String> {
+    let key = parse_key(&key)?;
+    let rng = SystemRandom::new();
+    apply(&mut data, &fields, |field, value| {
+        let associated_data = associated_data(&table, field);
+        // a string only counts as encrypted when it opens, user data can start with the prefix too
+        let encrypted = value.as_str()
+            .and_then(|s| s.strip_prefix(ENCRYPTED_PREFIX))
+            .is_some_and(|sealed| open(&key, field, &associated_data, sealed).is_ok());
+        if value.is_null() || encrypted {
+            return Ok(())
+        }
+        *value = Value::String(seal(&key, &rng, &associated_data, value)?);
+        Ok(())
+    })?;
+    Ok(data)
+}
+
+
+/// Decrypts the fields of a record or a list of records.
+/// 
+/// # Arguments
+/// * `data` - The record or list of records
+/// * `fields` - The field paths to decrypt
+/// * `key` - The base64 encoded 32 byte key used to encrypt the fields
+/// * `table` - The table the data is read from
+/// 
+/// # Returns
+/// * `Ok(Value)` - The data with the original values restored, fields that are not encrypted are left alone
+pub fn decrypt_fields(mut data: Value, fields: Vec<String>, key: String, table: String) -> Result<Value, String> {
+    let key = parse_key(&key)?;
+    apply(&mut data, &fields, |field, value| {
+        if let Some(sealed) = value.as_str().and_then(|s| s.strip_prefix(ENCRYPTED_PREFIX)) {
+            *value = open(&key, field, &associated_data(&table, field), sealed)?;
+        }
+        Ok(())
+    })?;
+    Ok(data)
+}
+
+
+/// Replaces the fields of a record or a list of records with their tokens.
+/// 
+/// # Arguments
+/// * `data` - The record or list of records
+/// * `fields` - The field paths to tokenize
+/// * `key` - The base64 encoded key for the HMAC
+/// 
+/// # Returns
+/// * `Ok(Value)` - The data with the fields replaced by tokens, missing, null and tokenized fields are left alone
+pub fn tokenize_fields(mut data: Value, fields: Vec<String>, key: String) -> Result<Value, String> {
+    let key = STANDARD.decode(key).map_err(|e| format!("invalid tokenization key: {}", e))?;
+    apply(&mut data, &fields, |_, value| {
+        if value.is_null() || value.as_str().is_some_and(|s| s.starts_with(TOKEN_PREFIX)) {
+            return Ok(())
+        }
+        *value = Value::String(tokenize(&key, value)?);
+        Ok(())
+    })?;
+    Ok(data)
+}
+
+
+/// Gets the token of a value so records can be looked up by a tokenized field.
+/// 
+/// # Arguments
+/// * `value` - The value to tokenize
+/// * `key` - The base64 encoded key for the HMAC
+/// 
+/// # Returns
+/// * `Ok(String)` - The token of the value
+pub fn tokenize_value(value: Value, key: String) -> Result<String, String> {
+    let key = STANDARD.decode(key).map_err(|e| format!("invalid tokenization key: {}", e))?;
+    tokenize(&key, &value)
+}
+
+
+/// Computes the token of a value, the value is serialised to JSON first so `1` and `"1"` differ.
+fn tokenize(key: &[u8], value: &Value) -> Result<String, String> {
+    if key.is_empty() {
+        return Err("tokenization key cannot be empty".to_string())
+    }
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).map_err(|e| e.to_string())?;
+    mac.update(&serde_json::to_vec(value).map_err(|e| e.to_string())?);
+    Ok(format!("{}{}", TOKEN_PREFIX, hex::encode(mac.finalize().into_bytes())))
+}
+
+
+/// Builds the AES-256-GCM key from its base64 encoding.
+fn parse_key(key: &str) -> Result<LessSafeKey, String> {
+    let bytes = STANDARD.decode(key).map_err(|e| format!("invalid encryption key: {}", e))?;
+    let key = UnboundKey::new(&AES_256_GCM, &bytes).map_err(|_| "encryption key must be 32 bytes".to_string())?;
+    Ok(LessSafeKey::new(key))
+}
+
+
+/// Calls the function for every field present in the record or list of records.
+fn apply<F>(data: &mut Value, fields: &[String], mut function: F) -> Result<(), String>
+where
+    F: FnMut(&str, &mut Value) -> Result<(), String>
+{
+    for field in fields {
+        validate_field_path(field)?;
+    }
+    let records = match data {
+        Value::Array(records) => records.iter_mut().collect(),
+        Value::Object(_) => vec![data],
+        _ => return Err("data must be a record or a list of records".to_string())
+    };
+    for record in records {
+        for field in fields {
+            let value = field.split('.').try_fold(&mut *record, |value, key| value.get_mut(key));
+            if let Some(value) = value {
+                function(field, value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+
+/// Gets the associated data a value is sealed with, the table and the field path.
+fn associated_data(table: &str, field: &str) -> String {
+    format!("{}:{}", table, field)
+}
+
+
+/// Encrypts a single value, the nonce is stored in front of the ciphertext.
+fn seal(key: &LessSafeKey, rng: &SystemRandom, associated_data: &str, value: &Value) -> Result<String, String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut nonce).map_err(|_| "failed to generate a nonce".to_string())?;
+    let mut sealed = serde_json::to_vec(value).map_err(|e| e.to_string())?;
+    key.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::from(associated_data.as_bytes()), &mut sealed)
+        .map_err(|_| format!("failed to encrypt {}", associated_data))?;
+    let mut output = nonce.to_vec();
+    output.extend(sealed);
+    Ok(format!("{}{}", ENCRYPTED_PREFIX, STANDARD.encode(output)))
+}
+
+
+/// Decrypts a single value sealed by `seal` with the same associated data.
+fn open(key: &LessSafeKey, field: &str, associated_data: &str, sealed: &str) -> Result<Value, String> {
+    let error = || format!("failed to decrypt field {}", field);
+    let mut bytes = STANDARD.decode(sealed).map_err(|_| error())?;
+    if bytes.len() < NONCE_LEN {
+        return Err(error())
+    }
+    let mut ciphertext = bytes.split_off(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(&bytes).map_err(|_| error())?;
+    let plaintext = key.open_in_place(nonce, Aad::from(associated_data.as_bytes()), &mut ciphertext).map_err(|_| error())?;
+    serde_json::from_slice(plaintext).map_err(|_| error())
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use serde_json::json;
+
+    fn key() -> String {
+        STANDARD.encode([7u8; 32])
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data = json!([
+            {"id": "user:1", "ssn": "123-45-6789", "card": {"number": 4111, "name": "Tobie"}},
+            {"id": "user:2", "ssn": null}
+        ]);
+        let fields = vec!["ssn".to_string(), "card.number".to_string()];
+
+        let encrypted = encrypt_fields(data.clone(), fields.clone(), key(), "user".to_string()).unwrap();
+        assert!(encrypted[0]["ssn"].as_str().unwrap().starts_with(ENCRYPTED_PREFIX));
+        assert!(encrypted[0]["card"]["number"].as_str().unwrap().starts_with(ENCRYPTED_PREFIX));
+        assert_eq!(encrypted[0]["card"]["name"], "Tobie");
+        assert_eq!(encrypted[1]["ssn"], Value::Null);
+
+        // encrypting again leaves the encrypted values alone
+        let twice = encrypt_fields(encrypted.clone(), fields.clone(), key(), "user".to_string()).unwrap();
+        assert_eq!(twice, encrypted);
+
+        let decrypted = decrypt_fields(encrypted, fields, key(), "user".to_string()).unwrap();
+        assert_eq!(decrypted, data);
+    }
+
+    #[test]
+    fn test_decrypt_wrong_key_field_or_table() {
+        let ssn = vec!["ssn".to_string()];
+        let encrypted = encrypt_fields(json!({"ssn": "123"}), ssn.clone(), key(), "user".to_string()).unwrap();
+        let other_key = STANDARD.encode([8u8; 32]);
+        assert!(decrypt_fields(encrypted.clone(), ssn.clone(), other_key, "user".to_string()).is_err());
+
+        let moved = json!({"other": encrypted["ssn"]});
+        assert!(decrypt_fields(moved, vec!["other".to_string()], key(), "user".to_string()).is_err());
+        assert!(decrypt_fields(encrypted, ssn, key(), "admin".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_prefixed_plaintext() {
+        let data = json!({"ssn": format!("{}123", ENCRYPTED_PREFIX)});
+        let ssn = vec!["ssn".to_string()];
+
+        let encrypted = encrypt_fields(data.clone(), ssn.clone(), key(), "user".to_string()).unwrap();
+        assert_ne!(encrypted, data);
+        assert_eq!(decrypt_fields(encrypted, ssn, key(), "user".to_string()).unwrap(), data);
+    }
+
+    #[test]
+    fn test_invalid_key() {
+        let outcome = encrypt_fields(json!({"ssn": "123"}), vec!["ssn".to_string()], STANDARD.encode([7u8; 16]), "user".to_string());
+        assert_eq!(outcome.unwrap_err(), "encryption key must be 32 bytes");
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let data = json!([
+            {"id": "user:1", "email": "tobie@surrealdb.com", "age": 1},
+            {"id": "user:2", "email": "tobie@surrealdb.com", "age": null}
+        ]);
+        let fields = vec!["email".to_string(), "age".to_string()];
+
+        let tokenized = tokenize_fields(data, fields.clone(), key()).unwrap();
+        let token = tokenize_value(json!("tobie@surrealdb.com"), key()).unwrap();
+        assert!(token.starts_with(TOKEN_PREFIX));
+        assert_eq!(tokenized[0]["email"], token);
+        assert_eq!(tokenized[1]["email"], token);
+        assert_eq!(tokenized[1]["age"], Value::Null);
+        assert_ne!(tokenized[0]["age"], tokenize_value(json!("1"), key()).unwrap());
+        assert_ne!(token, tokenize_value(json!("tobie@surrealdb.com"), STANDARD.encode([8u8; 32])).unwrap());
+
+        // tokenizing again leaves the tokens alone
+        assert_eq!(tokenize_fields(tokenized.clone(), fields, key()).unwrap(), tokenized);
+    }
+
+}
@@ -0,0 +1,3 @@
+//! Defines the helpers for encrypting fields on the client before they are sent to the database.
+pub mod core;
+pub mod python;
@@ -0,0 +1,73 @@
+//! Python entry points for client-side field encryption.
+use pyo3::prelude::*;
+use serde_json::value::Value;
+
+use super::core::{encrypt_fields, decrypt_fields, tokenize_fields, tokenize_value};
+
+
+/// Encrypts the fields of a record or a list of records.
+/// 
+/// # Arguments
+/// * `data` - The JSON of the record or list of records
+/// * `fields` - The field paths to encrypt
+/// * `key` - The base64 encoded 32 byte key
+/// * `table` - The table the data is written to
+/// 
+/// # Returns
+/// * `Ok(String)` - The JSON of the data with the fields encrypted
+#[pyfunction]
+pub fn rust_encrypt_fields(data: String, fields: Vec<String>, key: String, table: String) -> Result<String, PyErr> {
+    let data: Value = serde_json::from_str(&data).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    let data = encrypt_fields(data, fields, key, table).map_err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>)?;
+    Ok(data.to_string())
+}
+
+
+/// Decrypts the fields of a record or a list of records.
+/// 
+/// # Arguments
+/// * `data` - The JSON of the record or list of records
+/// * `fields` - The field paths to decrypt
+/// * `key` - The base64 encoded 32 byte key
+/// * `table` - The table the data is read from
+/// 
+/// # Returns
+/// * `Ok(String)` - The JSON of the data with the fields decrypted
+#[pyfunction]
+pub fn rust_decrypt_fields(data: String, fields: Vec<String>, key: String, table: String) -> Result<String, PyErr> {
+    let data: Value = serde_json::from_str(&data).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    let data = decrypt_fields(data, fields, key, table).map_err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>)?;
+    Ok(data.to_string())
+}
+
+
+/// Replaces the fields of a record or a list of records with their tokens.
+/// 
+/// # Arguments
+/// * `data` - The JSON of the record or list of records
+/// * `fields` - The field paths to tokenize
+/// * `key` - The base64 encoded key for the HMAC
+/// 
+/// # Returns
+/// * `Ok(String)` - The JSON of the data with the fields tokenized
+#[pyfunction]
+pub fn rust_tokenize_fields(data: String, fields: Vec<String>, key: String) -> Result<String, PyErr> {
+    let data: Value = serde_json::from_str(&data).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    let data = tokenize_fields(data, fields, key).map_err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>)?;
+    Ok(data.to_string())
+}
+
+
+/// Gets the token of a value.
+/// 
+/// # Arguments
+/// * `value` - The JSON of the value
+/// * `key` - The base64 encoded key for the HMAC
+/// 
+/// # Returns
+/// * `Ok(String)` - The token of the value
+#[pyfunction]
+pub fn rust_tokenize_value(value: String, key: String) -> Result<String, PyErr> {
+    let value: Value = serde_json::from_str(&value).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    tokenize_value(value, key).map_err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>)
+}
@@ -0,0 +1,134 @@
+//! Defines the core functions for invoking custom functions (`fn::`) and ML models (`ml::`) on the database.
+//! In this module we can do the following:
+//! 
+//! * Run a custom function or ML model with arguments
+use serde_json::value::Value;
+use serde_json::Map;
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::execute;
+
+
+/// Runs a custom function or ML model on the database.
+/// 
+/// # Arguments
+/// * `connection` - The connection to run the function with
+/// * `name` - The name of the function such as `fn::greet`, `greet` or `ml::model<1.0.0>`
+/// * `args` - The arguments to be passed to the function
+/// 
+/// # Returns
+/// * `Ok(String)` - The result of the function
+pub async fn run_function(connection: WrappedConnection, name: String, args: Vec<Value>) -> Result<String, String> {
+    let (sql, bindings) = build_function_call(&name, args)?;
+    let mut outcome = execute(connection, sql, Some(bindings)).await?;
+    let result = outcome.pop().unwrap_or(Value::Null);
+    Ok(result.to_string())
+}
+
+
+/// Builds the SurrealQL statement and bindings for a function call.
+/// 
+/// # Arguments
+/// * `name` - The name of the function, `fn::` is assumed if no prefix is given
+/// * `args` - The arguments to be bound to the function call
+/// 
+/// # Returns
+/// * `Ok((String, Value))` - The statement and the bindings for the arguments
+pub fn build_function_call(name: &str, args: Vec<Value>) -> Result<(String, Value), String> {
+    let name = if name.starts_with("fn::") || name.starts_with("ml::") {
+        name.to_string()
+    } else {
+        format!("fn::{}", name)
+    };
+    validate_function_name(&name)?;
+
+    let mut bindings = Map::new();
+    let mut params = Vec::with_capacity(args.len());
+    for (index, arg) in args.into_iter().enumerate() {
+        let key = format!("arg{}", index);
+        params.push(format!("${}", key));
+        bindings.insert(key, arg);
+    }
+    let sql = format!("RETURN {}({});", name, params.join(", "));
+    Ok((sql, Value::Object(bindings)))
+}
+
+
+/// Checks that the function name only contains identifiers separated by `::` so it can be
+/// safely put into a statement, ML models can have a `<version>` suffix.
+/// 
+/// # Arguments
+/// * `name` - The fully qualified name of the function
+/// 
+/// # Returns
+/// * `Ok(())` - The name is valid
+fn validate_function_name(name: &str) -> Result<(), String> {
+    let (path, version) = match name.strip_prefix("ml::") {
+        Some(model) => match model.split_once('<') {
+            Some((path, version)) => {
+                let version = version.strip_suffix('>').ok_or(format!("invalid model version in: {}", name))?;
+                (path, Some(version))
+            },
+            None => (model, None)
+        },
+        None => (&name[4..], None)
+    };
+    let valid_path = path.split("::").all(|part| {
+        !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    });
+    let valid_version = version.is_none_or(|v| {
+        !v.is_empty() && v.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+    });
+    if !valid_path || !valid_version {
+        return Err(format!("invalid function name: {}", name))
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::operations::query::core::query;
+    use crate::connection::core::make_connection;
+    use tokio::runtime::Runtime;
+    use serde_json::{from_str, json};
+
+
+    #[test]
+    fn test_build_function_call() {
+        let (sql, bindings) = build_function_call("greet", vec![json!("Tobie"), json!(1)]).unwrap();
+        assert_eq!(sql, "RETURN fn::greet($arg0, $arg1);");
+        assert_eq!(bindings, json!({"arg0": "Tobie", "arg1": 1}));
+
+        let (sql, _) = build_function_call("ml::house_price<1.0.0>", vec![json!({"size": 10})]).unwrap();
+        assert_eq!(sql, "RETURN ml::house_price<1.0.0>($arg0);");
+    }
+
+    #[test]
+    fn test_build_function_call_invalid_name() {
+        assert!(build_function_call("greet(); DELETE user", vec![]).is_err());
+        assert!(build_function_call("fn::", vec![]).is_err());
+        assert!(build_function_call("ml::model<1.0", vec![]).is_err());
+    }
+
+    #[test]
+    fn test_run_function() {
+        let runtime = Runtime::new().unwrap();
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            let definition = "DEFINE FUNCTION fn::greet($name: string, $age: int) { RETURN $name + ' is ' + <string> $age; };";
+            query(connection.clone(), definition.to_string(), None).await.unwrap();
+            run_function(connection, "greet".to_string(), vec![json!("Tobie"), json!(1)]).await.unwrap()
+        });
+
+        let outcome: Value = from_str(&outcome).unwrap();
+        assert_eq!(outcome, "Tobie is 1");
+    }
+
+}
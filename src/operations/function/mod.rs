@@ -0,0 +1,3 @@
+//! Defines the operations for running custom functions and ML models against the database.
+pub mod core;
+pub mod python;
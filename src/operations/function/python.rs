@@ -0,0 +1,24 @@
+//! Python entry points for running custom functions and ML models against the database.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use serde_json::value::Value;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::run_function;
+use crate::py_future_wrapper;
+
+
+/// Runs a custom function or ML model on the database in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `name` - The name of the function such as `fn::greet`, `greet` or `ml::model<1.0.0>`
+/// * `args` - A JSON list of the arguments to be passed to the function
+/// 
+/// # Returns
+/// * `Ok(String)` - The result of the function
+#[pyfunction]
+pub fn rust_run_function_future<'a>(py: Python<'a>, connection: WrappedConnection, name: String, args: &'a PyAny) -> Result<&'a PyAny, PyErr> {
+    let args: Vec<Value> = serde_json::from_str(&args.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    py_future_wrapper!(py, run_function(connection, name, args))
+}
@@ -0,0 +1,137 @@
+//! Defines the core functions for spotting queries that look like user data was formatted into them
+//! instead of being bound as parameters. The checks are heuristics, a query that trips them is not
+//! necessarily an injection but it is one that should be using bindings. In this module we can do
+//! the following:
+//! 
+//! * Inspect a query for comments, conditions on string literals and conditions that are always true
+use surrealdb::sql::{parse, Cond, Expression, Statement, Subquery, Value as SurrealValue};
+
+
+/// Inspects a query for the literal patterns left behind when user data is formatted into a query.
+/// The query is flagged if it contains a comment, which is how injected SQL cuts off the rest of a
+/// query, if a `WHERE` clause compares a field to a string literal rather than a parameter, or if a
+/// `WHERE` clause compares two literals such as `1 = 1`, which makes the condition always true.
+/// 
+/// # Arguments
+/// * `sql` - The SQL query to inspect
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - A description of each suspicious pattern found, empty if there are none
+pub fn inspect_query(sql: &str) -> Result<Vec<String>, String> {
+    let query = parse(sql).map_err(|e| e.to_string())?;
+    let mut findings = Vec::new();
+    if let Some(marker) = find_comment(sql) {
+        findings.push(format!("the query contains a comment starting with `{}`", marker));
+    }
+    for statement in query.0.0.iter() {
+        match statement {
+            Statement::Select(select) => inspect_condition(&select.cond, &mut findings),
+            Statement::Update(update) => inspect_condition(&update.cond, &mut findings),
+            Statement::Delete(delete) => inspect_condition(&delete.cond, &mut findings),
+            _ => {}
+        }
+    }
+    Ok(findings)
+}
+
+
+/// Finds the first comment outside of the quoted strings and identifiers of a query.
+/// 
+/// # Arguments
+/// * `sql` - The SQL query to search
+/// 
+/// # Returns
+/// * `Option<&'static str>` - The marker that starts the comment if there is one
+fn find_comment(sql: &str) -> Option<&'static str> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut closing: Option<char> = None;
+    let mut index = 0;
+    while index < chars.len() {
+        let current = chars[index];
+        let next = chars.get(index + 1).copied();
+        match closing {
+            Some(_) if current == '\\' => index += 1,
+            Some(end) if current == end => closing = None,
+            Some(_) => {}
+            None => match (current, next) {
+                ('\'', _) | ('"', _) | ('`', _) => closing = Some(current),
+                ('⟨', _) => closing = Some('⟩'),
+                ('-', Some('-')) => return Some("--"),
+                ('/', Some('/')) => return Some("//"),
+                ('/', Some('*')) => return Some("/*"),
+                ('#', _) => return Some("#"),
+                _ => {}
+            }
+        }
+        index += 1;
+    }
+    None
+}
+
+
+/// Inspects the `WHERE` clause of a statement if it has one.
+fn inspect_condition(cond: &Option<Cond>, findings: &mut Vec<String>) {
+    if let Some(cond) = cond {
+        inspect_value(&cond.0, findings);
+    }
+}
+
+
+/// Inspects the comparisons in a condition and the conditions of its subqueries.
+fn inspect_value(value: &SurrealValue, findings: &mut Vec<String>) {
+    match value {
+        SurrealValue::Expression(expression) => match expression.as_ref() {
+            Expression::Binary { l, r, .. } => {
+                if is_literal(l) && is_literal(r) {
+                    findings.push(format!("the condition `{}` compares two literals", expression));
+                } else if let Some(literal) = [l, r].iter().find(|side| side.is_strand()) {
+                    findings.push(format!("the condition `{}` compares against the string literal {}", expression, literal));
+                }
+                inspect_value(l, findings);
+                inspect_value(r, findings);
+            },
+            Expression::Unary { v, .. } => inspect_value(v, findings)
+        },
+        SurrealValue::Subquery(subquery) => match subquery.as_ref() {
+            Subquery::Value(value) => inspect_value(value, findings),
+            Subquery::Select(select) => inspect_condition(&select.cond, findings),
+            Subquery::Update(update) => inspect_condition(&update.cond, findings),
+            Subquery::Delete(delete) => inspect_condition(&delete.cond, findings),
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+
+/// Checks if a value is written out in the query rather than read from a field or a parameter.
+fn is_literal(value: &SurrealValue) -> bool {
+    matches!(value, SurrealValue::Strand(_) | SurrealValue::Number(_) | SurrealValue::Bool(_))
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_inspect_query() {
+        assert!(inspect_query("SELECT * FROM user WHERE name = $name AND age > 18;").unwrap().is_empty());
+        assert!(inspect_query("CREATE user SET note = 'a -- b \\' // c', tag = \"#1\";").unwrap().is_empty());
+
+        let findings = inspect_query("SELECT * FROM user WHERE name = 'bob' OR 1 = 1;").unwrap();
+        assert_eq!(findings, vec![
+            "the condition `name = 'bob'` compares against the string literal 'bob'".to_string(),
+            "the condition `1 = 1` compares two literals".to_string()
+        ]);
+
+        let findings = inspect_query("DELETE user WHERE id = $id OR (SELECT * FROM admin WHERE 'a' = 'a'); -- '").unwrap();
+        assert_eq!(findings, vec![
+            "the query contains a comment starting with `--`".to_string(),
+            "the condition `'a' = 'a'` compares two literals".to_string()
+        ]);
+
+        assert!(inspect_query("SELECT * FROM user WHERE name = 'bob").is_err());
+    }
+}
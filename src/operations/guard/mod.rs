@@ -0,0 +1,3 @@
+//! Defines the operations for guarding the database against queries with user data formatted into them.
+pub mod core;
+pub mod python;
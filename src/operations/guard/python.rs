@@ -0,0 +1,17 @@
+//! Python entry points for guarding the database against queries with user data formatted into them.
+use pyo3::prelude::*;
+
+use super::core::inspect_query;
+
+
+/// Inspects a query for the literal patterns left behind when user data is formatted into a query.
+/// 
+/// # Arguments
+/// * `sql` - The SQL query to inspect
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - A description of each suspicious pattern found
+#[pyfunction]
+pub fn rust_inspect_query(sql: String) -> Result<Vec<String>, PyErr> {
+    inspect_query(&sql).map_err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>)
+}
@@ -0,0 +1,348 @@
+//! Defines the core functions for reading tables as they were in the past. SurrealDB 1.x parses
+//! the `VERSION` clause of a select but ignores it, so past versions are rebuilt by replaying the
+//! change feed of a table instead. This needs the table to be defined with a `CHANGEFEED` that is
+//! long enough to still hold the changes being read. In this module we can do the following:
+//! 
+//! * Read a table as it was at a versionstamp or a point in time
+//! * Get the timeline of the states of a record and the changes between them
+use serde_json::value::Value;
+use serde_json::{json, Map};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use surrealdb::sql::{thing, Datetime, Thing};
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::{execute, validate_identifier};
+
+
+/// The number of change sets read from a change feed at once.
+const CHANGES_PAGE_SIZE: usize = 1000;
+
+
+/// Reads a table as it was after the change with a versionstamp or at a point in time.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the table with
+/// * `table` - The table to read
+/// * `versionstamp` - The versionstamp of the last change to include
+/// * `timestamp` - The time to read the table at, used if `versionstamp` is `None`
+/// 
+/// # Returns
+/// * `Ok(String)` - The records of the table at that version in record ID order
+pub async fn read_as_of(connection: WrappedConnection, table: String, versionstamp: Option<u64>, timestamp: Option<String>) -> Result<String, String> {
+    validate_identifier(&table)?;
+    ensure_change_feed(connection.clone(), &table).await?;
+    let until = match (versionstamp, timestamp) {
+        (Some(versionstamp), None) => versionstamp,
+        (None, Some(timestamp)) => match first_change_after(connection.clone(), &table, &timestamp).await? {
+            Some(versionstamp) => match versionstamp.checked_sub(1) {
+                Some(until) => until,
+                // nothing was changed before the first versionstamp so the table was empty
+                None => return Ok(Value::Array(vec![]).to_string())
+            },
+            None => u64::MAX
+        },
+        _ => return Err("exactly one of versionstamp and timestamp must be given".to_string())
+    };
+
+    let mut records = HashMap::new();
+    for (_, changes) in replay(connection, &table, until).await? {
+        for change in changes {
+            if let Some(record) = change.get("update") {
+                let id = record_id(record)?;
+                records.insert(id.to_string(), (id, record.clone()));
+            }
+            else if let Some(record) = change.get("delete") {
+                records.remove(&record_id(record)?.to_string());
+            }
+        }
+    }
+
+    let mut records: Vec<(Thing, Value)> = records.into_values().collect();
+    records.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(Value::Array(records.into_iter().map(|(_, record)| record).collect()).to_string())
+}
+
+
+/// Gets the timeline of a record from the change feed of its table. Each entry has the `versionstamp`
+/// of the change, the `action` that was taken (`create`, `update` or `delete`), the `state` of the
+/// record after the change (`null` once deleted) and the `diff` from the previous state as JSON Patch
+/// operations.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the change feed with
+/// * `record_id` - The ID of the record such as `user:tobie`
+/// * `since` - The versionstamp of the first change to include, all changes are included if `None`
+/// 
+/// # Returns
+/// * `Ok(String)` - The entries of the timeline in versionstamp order
+pub async fn record_history(connection: WrappedConnection, record_id: String, since: Option<u64>) -> Result<String, String> {
+    let id = thing(&record_id).map_err(|_| format!("invalid record ID: {}", record_id))?;
+    validate_identifier(&id.tb)?;
+    ensure_change_feed(connection.clone(), &id.tb).await?;
+    let since = since.unwrap_or(0);
+
+    // earlier changes are still replayed so the first entry has the correct diff
+    let mut state = Value::Null;
+    let mut timeline = vec![];
+    for (versionstamp, changes) in replay(connection, &id.tb, u64::MAX).await? {
+        for change in changes {
+            let (action, next) = match (change.get("update"), change.get("delete")) {
+                (Some(record), _) if self::record_id(record)? == id => {
+                    (if state.is_null() { "create" } else { "update" }, record.clone())
+                },
+                (_, Some(record)) if self::record_id(record)? == id => ("delete", Value::Null),
+                _ => continue
+            };
+            if versionstamp >= since {
+                timeline.push(json!({
+                    "versionstamp": versionstamp,
+                    "action": action,
+                    "state": next,
+                    "diff": diff(&state, &next),
+                }));
+            }
+            state = next;
+        }
+    }
+    Ok(Value::Array(timeline).to_string())
+}
+
+
+/// Works out the JSON Patch operations that turn one value into another. Objects are compared field
+/// by field while any other values, including arrays, are replaced as a whole when they differ.
+/// 
+/// # Arguments
+/// * `before` - The value before the change, `null` if there was no value
+/// * `after` - The value after the change, `null` if there is no value
+/// 
+/// # Returns
+/// * `Vec<Value>` - The patch operations
+pub fn diff(before: &Value, after: &Value) -> Vec<Value> {
+    let mut operations = vec![];
+    match (before, after) {
+        (Value::Null, Value::Null) => {},
+        (Value::Null, after) => operations.push(json!({"op": "add", "path": "", "value": after})),
+        (_, Value::Null) => operations.push(json!({"op": "remove", "path": ""})),
+        (before, after) => diff_into(&mut operations, "", before, after)
+    }
+    operations
+}
+
+
+/// Adds the patch operations that turn one value at a path into another.
+fn diff_into(operations: &mut Vec<Value>, path: &str, before: &Value, after: &Value) {
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => diff_objects(operations, path, before, after),
+        (before, after) if before != after => {
+            operations.push(json!({"op": "replace", "path": path, "value": after}))
+        },
+        _ => {}
+    }
+}
+
+
+/// Adds the patch operations that turn one object at a path into another.
+fn diff_objects(operations: &mut Vec<Value>, path: &str, before: &Map<String, Value>, after: &Map<String, Value>) {
+    for (key, value) in before.iter() {
+        let pointer = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+        match after.get(key) {
+            Some(next) => diff_into(operations, &pointer, value, next),
+            None => operations.push(json!({"op": "remove", "path": pointer}))
+        }
+    }
+    for (key, value) in after.iter().filter(|(key, _)| !before.contains_key(*key)) {
+        let pointer = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+        operations.push(json!({"op": "add", "path": pointer, "value": value}));
+    }
+}
+
+
+/// Reads the change sets of a table from its change feed.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the change feed with
+/// * `table` - The table to read the change feed of
+/// * `until` - The versionstamp of the last change set to read
+/// 
+/// # Returns
+/// * `Ok(Vec<(u64, Vec<Value>)>)` - The versionstamp and changes of each change set in versionstamp order
+async fn replay(connection: WrappedConnection, table: &str, until: u64) -> Result<Vec<(u64, Vec<Value>)>, String> {
+    let mut replayed = vec![];
+    let mut since = 0;
+    loop {
+        let change_sets = changes(connection.clone(), table, since).await?;
+        let done = change_sets.len() < CHANGES_PAGE_SIZE;
+        for mut change_set in change_sets {
+            let versionstamp = change_set["versionstamp"].as_u64().ok_or("invalid change set in change feed")?;
+            if versionstamp > until {
+                return Ok(replayed)
+            }
+            since = versionstamp + 1;
+            let changes = match change_set["changes"].take() {
+                Value::Array(changes) => changes,
+                _ => vec![]
+            };
+            replayed.push((versionstamp, changes));
+        }
+        if done {
+            return Ok(replayed)
+        }
+    }
+}
+
+
+/// Checks that a table records its changes in a change feed.
+/// 
+/// # Arguments
+/// * `connection` - The connection to check the table with
+/// * `table` - The table to check
+/// 
+/// # Returns
+/// * `Ok(())` - The table has a change feed
+async fn ensure_change_feed(connection: WrappedConnection, table: &str) -> Result<(), String> {
+    let info = execute(connection, "INFO FOR DB;".to_string(), None).await?;
+    let definition = info.first().and_then(|info| info["tables"][table].as_str()).unwrap_or("");
+    if !definition.contains(" CHANGEFEED ") {
+        return Err(format!(
+            "past versions of table {} can not be read as it has no change feed, define it with DEFINE TABLE {} CHANGEFEED <duration>",
+            table, table
+        ))
+    }
+    Ok(())
+}
+
+
+/// Gets the versionstamp of the first change made to a table at or after a point in time.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the change feed with
+/// * `table` - The table to read the change feed of
+/// * `timestamp` - The point in time
+/// 
+/// # Returns
+/// * `Ok(Option<u64>)` - The versionstamp, `None` if there were no changes since
+async fn first_change_after(connection: WrappedConnection, table: &str, timestamp: &str) -> Result<Option<u64>, String> {
+    let datetime = Datetime::try_from(timestamp).map_err(|_| format!("invalid timestamp: {}", timestamp))?;
+    let sql = format!("SHOW CHANGES FOR TABLE {} SINCE d{} LIMIT 1;", table, datetime);
+    let change_sets = execute(connection, sql, None).await.map_err(|e| match e.contains("no versionstamp associated") {
+        true => format!("the database has not recorded a versionstamp for {} yet", timestamp),
+        false => e
+    })?;
+    Ok(change_sets.first().and_then(|change_sets| change_sets[0]["versionstamp"].as_u64()))
+}
+
+
+/// Gets a page of change sets from the change feed of a table.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the change feed with
+/// * `table` - The table to read the change feed of
+/// * `since` - The versionstamp to start from
+/// 
+/// # Returns
+/// * `Ok(Vec<Value>)` - The change sets in versionstamp order
+async fn changes(connection: WrappedConnection, table: &str, since: u64) -> Result<Vec<Value>, String> {
+    let sql = format!("SHOW CHANGES FOR TABLE {} SINCE {} LIMIT {};", table, since, CHANGES_PAGE_SIZE);
+    match execute(connection, sql, None).await?.pop() {
+        Some(Value::Array(change_sets)) => Ok(change_sets),
+        _ => Ok(vec![])
+    }
+}
+
+
+/// Gets the ID of a record in a change.
+fn record_id(record: &Value) -> Result<Thing, String> {
+    let id = record["id"].as_str().ok_or("change without a record ID in change feed")?;
+    thing(id).map_err(|e| e.to_string())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::core::make_connection;
+    use crate::operations::query::core::query;
+    use tokio::runtime::Runtime;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_read_as_of() {
+        let runtime = Runtime::new().unwrap();
+
+        let (feed, versions, unmapped, missing, no_feed) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "DEFINE TABLE user CHANGEFEED 1h; CREATE user:10 SET a = 1; UPDATE user:10 SET a = 2; CREATE user:2 SET a = 5; DELETE user:10;".to_string(), None).await.unwrap();
+            query(connection.clone(), "CREATE post:1;".to_string(), None).await.unwrap();
+
+            let feed: Value = from_str(&query(connection.clone(), "SHOW CHANGES FOR TABLE user SINCE 0;".to_string(), None).await.unwrap()).unwrap();
+            let mut versions = vec![];
+            for change_set in feed[0].as_array().unwrap() {
+                let versionstamp = change_set["versionstamp"].as_u64().unwrap();
+                versions.push(from_str::<Value>(&read_as_of(connection.clone(), "user".to_string(), Some(versionstamp), None).await.unwrap()).unwrap());
+            }
+            // no timestamps have been mapped to versionstamps yet in a fresh in-memory database
+            let unmapped = read_as_of(connection.clone(), "user".to_string(), None, Some("2999-01-01T00:00:00Z".to_string())).await;
+            let missing = read_as_of(connection.clone(), "user".to_string(), None, None).await;
+            let no_feed = read_as_of(connection.clone(), "post".to_string(), Some(1), None).await;
+            (feed, versions, unmapped, missing, no_feed)
+        });
+
+        assert_eq!(feed[0].as_array().unwrap().len(), 5);
+        assert_eq!(versions, vec![
+            json!([]),
+            json!([{"id": "user:10", "a": 1}]),
+            json!([{"id": "user:10", "a": 2}]),
+            json!([{"id": "user:2", "a": 5}, {"id": "user:10", "a": 2}]),
+            json!([{"id": "user:2", "a": 5}]),
+        ]);
+        assert!(unmapped.unwrap_err().contains("has not recorded a versionstamp"));
+        assert!(missing.is_err());
+        assert!(no_feed.unwrap_err().contains("no change feed"));
+    }
+
+    #[test]
+    fn test_record_history() {
+        let runtime = Runtime::new().unwrap();
+
+        let (history, since, invalid) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "DEFINE TABLE user CHANGEFEED 1h; CREATE user:tobie SET name = 'Tobie', age = 30; CREATE user:jaime SET age = 20;".to_string(), None).await.unwrap();
+            query(connection.clone(), "UPDATE user:tobie SET age = 31, tags = ['admin'], name = NONE; DELETE user:tobie;".to_string(), None).await.unwrap();
+
+            let history: Value = from_str(&record_history(connection.clone(), "user:tobie".to_string(), None).await.unwrap()).unwrap();
+            let last = history[2]["versionstamp"].as_u64().unwrap();
+            let since: Value = from_str(&record_history(connection.clone(), "user:tobie".to_string(), Some(last)).await.unwrap()).unwrap();
+            let invalid = record_history(connection, "not a record".to_string(), None).await;
+            (history, since, invalid)
+        });
+
+        let actions: Vec<&str> = history.as_array().unwrap().iter().map(|entry| entry["action"].as_str().unwrap()).collect();
+        assert_eq!(actions, vec!["create", "update", "delete"]);
+        assert_eq!(history[0]["state"], json!({"id": "user:tobie", "name": "Tobie", "age": 30}));
+        assert_eq!(history[0]["diff"], json!([{"op": "add", "path": "", "value": {"id": "user:tobie", "name": "Tobie", "age": 30}}]));
+        assert_eq!(history[1]["state"], json!({"id": "user:tobie", "age": 31, "tags": ["admin"]}));
+        assert_eq!(history[1]["diff"], json!([
+            {"op": "replace", "path": "/age", "value": 31},
+            {"op": "remove", "path": "/name"},
+            {"op": "add", "path": "/tags", "value": ["admin"]},
+        ]));
+        assert_eq!(history[2]["state"], Value::Null);
+        assert_eq!(since, json!([history[2]]));
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff(&json!({"a": {"b": 1, "c/d": 2}}), &json!({"a": {"b": 2}})), vec![
+            json!({"op": "replace", "path": "/a/b", "value": 2}),
+            json!({"op": "remove", "path": "/a/c~1d"}),
+        ]);
+        assert_eq!(diff(&json!({"a": [1, 2]}), &json!({"a": [1, 2]})), Vec::<Value>::new());
+        assert_eq!(diff(&json!({"a": 1}), &Value::Null), vec![json!({"op": "remove", "path": ""})]);
+    }
+}
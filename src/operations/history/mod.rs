@@ -0,0 +1,3 @@
+//! Defines the operations for reading the past versions of records from table change feeds.
+pub mod core;
+pub mod python;
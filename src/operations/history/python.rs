@@ -0,0 +1,38 @@
+//! Python entry points for reading the past versions of records.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{read_as_of, record_history};
+use crate::py_future_wrapper;
+
+
+/// Reads a table as it was at a versionstamp or a point in time in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to read
+/// * `versionstamp` - The versionstamp of the last change to include
+/// * `timestamp` - The time to read the table at, used if `versionstamp` is not provided
+/// 
+/// # Returns
+/// * `Ok(String)` - The records of the table at that version
+#[pyfunction]
+pub fn rust_read_as_of_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, versionstamp: Option<u64>, timestamp: Option<String>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, read_as_of(connection, table, versionstamp, timestamp))
+}
+
+
+/// Gets the timeline of the states of a record in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `record_id` - The ID of the record
+/// * `since` - The versionstamp of the first change to include, all changes are included if not provided
+/// 
+/// # Returns
+/// * `Ok(String)` - The entries of the timeline
+#[pyfunction]
+pub fn rust_record_history_future<'a>(py: Python<'a>, connection: WrappedConnection, record_id: String, since: Option<u64>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, record_history(connection, record_id, since))
+}
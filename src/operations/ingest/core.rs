@@ -0,0 +1,514 @@
+//! Defines the core functions for importing records from files exported by other databases. A file
+//! is read in Rust, each row is mapped with an optional field mapping and the records are inserted
+//! in chunks. In this module we can do the following:
+//! 
+//! * Read NDJSON and CSV files into records
+//! * Read a file into records with a field mapping
+//! * Map the fields of a record with a declarative field mapping
+//! * Import a file into a table
+//! * Materialize the results of a query into a table
+//! 
+//! # Field Mapping
+//! A mapping is an object with the target field as the key and the source field or a spec as the value.
+//! Target fields can be nested with `.` and a spec can convert the value with `type` which can be
+//! `string`, `int`, `float`, `bool` or `json`. Without a mapping the rows are inserted as they are.
+//! ```json
+//! {
+//!     "id": "user_id",
+//!     "name.first": "first_name",
+//!     "age": {"from": "age", "type": "int"}
+//! }
+//! ```
+use serde_json::value::Value;
+use serde_json::Map;
+use std::fs;
+use surrealdb::error::Db;
+use surrealdb::sql::{parse, Statement, Value as SurrealValue};
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::create::core::insert;
+use crate::operations::query::core::{execute, validate_field_path, validate_identifier};
+
+
+/// The formats of the files that can be imported.
+#[derive(Debug, PartialEq)]
+pub enum FileFormat {
+    Ndjson,
+    Csv,
+}
+
+impl FileFormat {
+    /// Gets the format from its name.
+    /// 
+    /// # Arguments
+    /// * `name` - The name of the format such as `ndjson` or `csv`
+    /// 
+    /// # Returns
+    /// * `Ok(FileFormat)` - The format
+    pub fn from_name(name: &str) -> Result<FileFormat, String> {
+        match name.to_lowercase().as_str() {
+            "ndjson" | "jsonl" => Ok(FileFormat::Ndjson),
+            "csv" => Ok(FileFormat::Csv),
+            "parquet" => Err("parquet files are not supported, convert them to NDJSON or CSV first".to_string()),
+            other => Err(format!("unknown file format: {}", other))
+        }
+    }
+}
+
+
+/// What `materialize` does when the target table already exists.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MaterializeMode {
+    /// The target table must not exist yet.
+    Create,
+    /// The records of the target table are deleted before the results are written.
+    Replace,
+    /// The results are added to the records of the target table.
+    Append,
+}
+
+impl MaterializeMode {
+    /// Gets the mode from its name.
+    /// 
+    /// # Arguments
+    /// * `name` - The name of the mode, `create`, `replace` or `append`
+    /// 
+    /// # Returns
+    /// * `Ok(MaterializeMode)` - The mode
+    pub fn from_name(name: &str) -> Result<MaterializeMode, String> {
+        match name.to_lowercase().as_str() {
+            "create" => Ok(MaterializeMode::Create),
+            "replace" => Ok(MaterializeMode::Replace),
+            "append" => Ok(MaterializeMode::Append),
+            other => Err(format!("unknown materialize mode: {}", other))
+        }
+    }
+}
+
+
+/// Imports the records of a file into a table.
+/// 
+/// # Arguments
+/// * `connection` - The connection to import the records with
+/// * `table` - The table to import the records into
+/// * `path` - The path of the file
+/// * `format` - The name of the format of the file
+/// * `mapping` - The field mapping applied to every row, rows are imported as they are if `None`
+/// * `chunk_size` - The maximum number of records sent per insert
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of records imported
+pub async fn import_file(connection: WrappedConnection, table: String, path: String, format: String, mapping: Option<Value>, chunk_size: Option<usize>) -> Result<usize, String> {
+    validate_identifier(&table)?;
+    let records = read_file(&path, &format, mapping)?;
+    let count = records.len();
+    if count > 0 {
+        insert(connection, table, records, chunk_size).await?;
+    }
+    Ok(count)
+}
+
+
+/// Writes the rows of the select statements of a query into a table, an ELT step that would otherwise
+/// need a loop in Python. The rows are copied by the database in one transaction so they never pass
+/// through the client, keep their types such as record links and datetimes, and a failure part way
+/// leaves the target table as it was. The selects are run before the target table is touched so
+/// they can read from the target when replacing it.
+/// 
+/// # Arguments
+/// * `connection` - The connection to run the query and write the table with
+/// * `sql` - The select statements whose rows are written, the rows of all of them are written
+/// * `bindings` - The bindings to use for the query
+/// * `table` - The table to write the rows into
+/// * `mode` - What to do if the table already exists
+/// * `keep_ids` - If true rows keep the key of their record ID in the target table, so `user:1` is
+///   written as `table:1`, otherwise the target records get new IDs
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of records written
+pub async fn materialize(connection: WrappedConnection, sql: String, bindings: Option<Value>, table: String, mode: MaterializeMode, keep_ids: bool) -> Result<usize, String> {
+    validate_identifier(&table)?;
+    let query = parse(&sql).map_err(|e| e.to_string())?;
+    let mut selects = vec![];
+    for statement in query.0.0.into_iter() {
+        match statement {
+            Statement::Select(select) => selects.push(select),
+            other => return Err(format!("only select statements can be materialized, found: {}", other))
+        }
+    }
+    if selects.is_empty() {
+        return Err("no select statement to materialize".to_string())
+    }
+
+    if mode == MaterializeMode::Create {
+        let info = execute(connection.clone(), "INFO FOR DB;".to_string(), None).await?;
+        let defined = info.first().and_then(|info| info["tables"].get(&table)).is_some();
+        let sql = format!("SELECT count() FROM {} GROUP ALL;", table);
+        let stored = execute(connection.clone(), sql, None).await?[0][0]["count"].as_u64().unwrap_or(0);
+        if defined || stored > 0 {
+            return Err(format!("table {} already exists", table))
+        }
+    }
+
+    // rows without a record ID, such as grouped rows, get a new ID in the target table
+    let rows = match keep_ids {
+        true => "*, IF type::is::record(id) THEN meta::id(id) END AS id",
+        false => "* OMIT id"
+    };
+    let mut statements = vec!["BEGIN TRANSACTION;".to_string()];
+    for (index, select) in selects.iter().enumerate() {
+        statements.push(format!("LET $materialized_{} = (SELECT {} FROM ({}));", index, rows, select));
+    }
+    if mode == MaterializeMode::Replace {
+        statements.push(format!("DELETE {};", table));
+    }
+    // records are created one by one as INSERT skips records that break a unique index without an error
+    let mut counts = vec![];
+    for index in 0..selects.len() {
+        statements.push(format!("FOR $row IN $materialized_{} {{ CREATE {} CONTENT $row; }};", index, table));
+        counts.push(format!("array::len($materialized_{})", index));
+    }
+    statements.push("COMMIT TRANSACTION;".to_string());
+    statements.push(format!("RETURN {};", counts.join(" + ")));
+
+    let mut response = match bindings {
+        Some(bindings) => connection.connection.query(statements.join("\n")).bind(bindings).await,
+        None => connection.connection.query(statements.join("\n")).await
+    }.map_err(|e| e.to_string())?;
+
+    // the statement that failed the transaction has the cause, the others were just not executed
+    let mut errors: Vec<(usize, surrealdb::Error)> = response.take_errors().into_iter().collect();
+    errors.sort_by_key(|(index, _)| *index);
+    let not_executed = |error: &surrealdb::Error| matches!(error, surrealdb::Error::Db(Db::QueryNotExecuted));
+    if let Some((_, error)) = errors.iter().find(|(_, error)| !not_executed(error)).or(errors.first()) {
+        return Err(error.to_string())
+    }
+    let count: SurrealValue = response.take(response.num_statements() - 1).map_err(|e| e.to_string())?;
+    count.into_json()
+        .as_u64()
+        .map(|count| count as usize)
+        .ok_or_else(|| "the materialized rows could not be counted".to_string())
+}
+
+
+/// Reads the records of a file and applies the field mapping to them.
+/// 
+/// # Arguments
+/// * `path` - The path of the file
+/// * `format` - The name of the format of the file
+/// * `mapping` - The field mapping applied to every row, rows are returned as they are if `None`
+/// 
+/// # Returns
+/// * `Ok(Vec<Value>)` - The records of the file
+pub fn read_file(path: &str, format: &str, mapping: Option<Value>) -> Result<Vec<Value>, String> {
+    let format = FileFormat::from_name(format)?;
+    let mapping = match mapping {
+        Some(Value::Object(mapping)) => Some(mapping),
+        None | Some(Value::Null) => None,
+        Some(_) => return Err("field mapping must be an object".to_string())
+    };
+    let contents = fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path, e))?;
+    let rows = match format {
+        FileFormat::Ndjson => parse_ndjson(&contents)?,
+        FileFormat::Csv => parse_csv(&contents)?,
+    };
+    match mapping {
+        Some(mapping) => rows.into_iter().map(|row| map_record(&row, &mapping)).collect(),
+        None => Ok(rows)
+    }
+}
+
+
+/// Parses newline delimited JSON where every non-empty line is an object.
+/// 
+/// # Arguments
+/// * `contents` - The contents of the file
+/// 
+/// # Returns
+/// * `Ok(Vec<Value>)` - The rows of the file
+pub fn parse_ndjson(contents: &str) -> Result<Vec<Value>, String> {
+    contents.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| match serde_json::from_str(line) {
+            Ok(Value::Object(row)) => Ok(Value::Object(row)),
+            Ok(_) => Err(format!("line {} is not an object", index + 1)),
+            Err(e) => Err(format!("line {}: {}", index + 1, e))
+        })
+        .collect()
+}
+
+
+/// Parses CSV with a header row into objects with a string for every column. Quoted values can
+/// hold commas, newlines and quotes escaped as `""`.
+/// 
+/// # Arguments
+/// * `contents` - The contents of the file
+/// 
+/// # Returns
+/// * `Ok(Vec<Value>)` - The rows of the file
+pub fn parse_csv(contents: &str) -> Result<Vec<Value>, String> {
+    let mut rows = split_csv(contents)?.into_iter();
+    let header = match rows.next() {
+        Some(header) => header,
+        None => return Ok(vec![])
+    };
+    rows.enumerate()
+        .map(|(index, row)| {
+            if row.len() != header.len() {
+                return Err(format!("row {} has {} columns but the header has {}", index + 2, row.len(), header.len()))
+            }
+            let row: Map<String, Value> = header.iter().cloned().zip(row.into_iter().map(Value::String)).collect();
+            Ok(Value::Object(row))
+        })
+        .collect()
+}
+
+
+/// Splits CSV into rows of values, empty lines are skipped.
+fn split_csv(contents: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut chars = contents.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                value.push('"');
+                chars.next();
+            },
+            (true, '"') => quoted = false,
+            (true, c) => value.push(c),
+            (false, '"') if value.is_empty() => quoted = true,
+            (false, ',') => row.push(std::mem::take(&mut value)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {},
+            (false, '\n') => {
+                row.push(std::mem::take(&mut value));
+                if row.len() > 1 || !row[0].is_empty() {
+                    rows.push(std::mem::take(&mut row));
+                }
+                row.clear();
+            },
+            (false, c) => value.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quoted value".to_string())
+    }
+    if !row.is_empty() || !value.is_empty() {
+        row.push(value);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+
+/// Maps a row to a record with a field mapping.
+/// 
+/// # Arguments
+/// * `row` - The row read from the file
+/// * `mapping` - The target field with the source field or a spec of the source field and type
+/// 
+/// # Returns
+/// * `Ok(Value)` - The mapped record, source fields missing from the row are left out
+pub fn map_record(row: &Value, mapping: &Map<String, Value>) -> Result<Value, String> {
+    let mut record = Value::Object(Map::new());
+    for (target, spec) in mapping {
+        validate_field_path(target)?;
+        let (source, kind) = match spec {
+            Value::String(source) => (source.as_str(), None),
+            Value::Object(spec) => (
+                spec.get("from").and_then(Value::as_str).unwrap_or(target.as_str()),
+                spec.get("type").and_then(Value::as_str)
+            ),
+            _ => return Err(format!("invalid mapping for field {}", target))
+        };
+        let value = match source.split('.').try_fold(row, |value, key| value.get(key)) {
+            Some(value) => value,
+            None => continue
+        };
+        let value = match kind {
+            Some(kind) => convert(value, kind).map_err(|e| format!("field {}: {}", target, e))?,
+            None => value.clone()
+        };
+
+        let mut parts = target.split('.').peekable();
+        let mut current = &mut record;
+        while let Some(part) = parts.next() {
+            let object = current.as_object_mut().ok_or_else(|| format!("field {} conflicts with another field", target))?;
+            if parts.peek().is_none() {
+                object.insert(part.to_string(), value);
+                break
+            }
+            current = object.entry(part.to_string()).or_insert_with(|| Value::Object(Map::new()));
+        }
+    }
+    Ok(record)
+}
+
+
+/// Converts a value to a type, empty strings become `null` for anything but strings.
+fn convert(value: &Value, kind: &str) -> Result<Value, String> {
+    let text = match value {
+        Value::String(text) => text.trim().to_string(),
+        other => other.to_string()
+    };
+    if kind != "string" && (text.is_empty() || value.is_null()) {
+        return Ok(Value::Null)
+    }
+    match kind {
+        "string" => Ok(match value {
+            Value::String(text) => Value::String(text.clone()),
+            other => Value::String(other.to_string())
+        }),
+        "int" => text.parse::<i64>().map(Value::from).map_err(|_| format!("{} is not an int", text)),
+        "float" => text.parse::<f64>().map(Value::from).map_err(|_| format!("{} is not a float", text)),
+        "bool" => match text.to_lowercase().as_str() {
+            "true" | "t" | "yes" | "1" => Ok(Value::Bool(true)),
+            "false" | "f" | "no" | "0" => Ok(Value::Bool(false)),
+            _ => Err(format!("{} is not a bool", text))
+        },
+        "json" => match value {
+            Value::String(text) => serde_json::from_str(text).map_err(|e| e.to_string()),
+            other => Ok(other.clone())
+        },
+        other => Err(format!("unknown type {}", other))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::operations::query::core::query;
+    use crate::connection::core::make_connection;
+    use tokio::runtime::Runtime;
+    use serde_json::{from_str, json};
+
+
+    fn write_file(contents: &str) -> String {
+        let path = std::env::temp_dir().join(format!("ingest-{}", uuid::Uuid::new_v4()));
+        fs::write(&path, contents).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let rows = parse_csv("id,name,bio\r\n1,Tobie,\"likes \"\"Rust\"\", Go\nand more\"\n\n2,Jaime,\n").unwrap();
+        assert_eq!(rows, vec![
+            json!({"id": "1", "name": "Tobie", "bio": "likes \"Rust\", Go\nand more"}),
+            json!({"id": "2", "name": "Jaime", "bio": ""}),
+        ]);
+        assert!(parse_csv("id,name\n1\n").is_err());
+        assert!(parse_csv("id,name\n1,\"Tobie\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_ndjson() {
+        let rows = parse_ndjson("{\"id\": 1}\n\n{\"id\": 2}\n").unwrap();
+        assert_eq!(rows, vec![json!({"id": 1}), json!({"id": 2})]);
+        assert_eq!(parse_ndjson("{\"id\": 1}\n[1]").unwrap_err(), "line 2 is not an object");
+    }
+
+    #[test]
+    fn test_map_record() {
+        let mapping = json!({
+            "id": "user_id",
+            "name.first": "first_name",
+            "age": {"from": "age", "type": "int"},
+            "active": {"type": "bool"},
+            "score": {"type": "float"},
+            "missing": "nothing"
+        });
+        let row = json!({"user_id": "7", "first_name": "Tobie", "age": "32", "active": "yes", "score": ""});
+        let record = map_record(&row, mapping.as_object().unwrap()).unwrap();
+        assert_eq!(record, json!({"id": "7", "name": {"first": "Tobie"}, "age": 32, "active": true, "score": null}));
+
+        let mapping = json!({"age": {"type": "int"}});
+        assert!(map_record(&json!({"age": "old"}), mapping.as_object().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(FileFormat::from_name("CSV").unwrap(), FileFormat::Csv);
+        assert_eq!(FileFormat::from_name("jsonl").unwrap(), FileFormat::Ndjson);
+        assert!(FileFormat::from_name("parquet").is_err());
+        assert!(FileFormat::from_name("xml").is_err());
+    }
+
+    #[test]
+    fn test_import_file() {
+        let runtime = Runtime::new().unwrap();
+        let csv = write_file("user_id,first_name,age\ntobie,Tobie,32\njaime,Jaime,\n");
+        let ndjson = write_file("{\"id\": \"dave\", \"name\": {\"first\": \"Dave\"}}\n");
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            let mapping = json!({"id": "user_id", "name.first": "first_name", "age": {"type": "int"}});
+            let csv_count = import_file(connection.clone(), "user".to_string(), csv.clone(), "csv".to_string(), Some(mapping), Some(1)).await.unwrap();
+            let ndjson_count = import_file(connection.clone(), "user".to_string(), ndjson.clone(), "ndjson".to_string(), None, None).await.unwrap();
+            assert_eq!((csv_count, ndjson_count), (2, 1));
+            query(connection.clone(), "SELECT * FROM user ORDER BY id;".to_string(), None).await.unwrap()
+        });
+        fs::remove_file(csv).unwrap();
+        fs::remove_file(ndjson).unwrap();
+
+        let outcome: Value = from_str(&outcome).unwrap();
+        assert_eq!(outcome[0], json!([
+            {"id": "user:dave", "name": {"first": "Dave"}},
+            {"id": "user:jaime", "name": {"first": "Jaime"}},
+            {"age": 32, "id": "user:tobie", "name": {"first": "Tobie"}},
+        ]));
+    }
+
+    #[test]
+    fn test_materialize() {
+        let runtime = Runtime::new().unwrap();
+
+        let (outcome, exists, failed, not_select, counts) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "
+                CREATE user:1 SET name = 'Tobie', age = 32, friend = user:2, joined = <datetime> '2024-01-02T03:04:05Z';
+                CREATE user:2 SET name = 'Jaime', age = 20;
+                CREATE user:3 SET name = 'Dave', age = 40;
+            ".to_string(), None).await.unwrap();
+
+            let sql = "SELECT id, name, friend, joined FROM user WHERE age > $age;".to_string();
+            let bindings = Some(json!({"age": 30}));
+            let created = materialize(connection.clone(), sql.clone(), bindings.clone(), "adult".to_string(), MaterializeMode::Create, true).await.unwrap();
+            let exists = materialize(connection.clone(), sql.clone(), bindings.clone(), "adult".to_string(), MaterializeMode::Create, true).await;
+            let replaced = materialize(connection.clone(), sql.clone(), bindings.clone(), "adult".to_string(), MaterializeMode::Replace, true).await.unwrap();
+            let appended = materialize(connection.clone(), "SELECT name FROM user:2; SELECT count() FROM user GROUP ALL;".to_string(), None, "adult".to_string(), MaterializeMode::Append, false).await.unwrap();
+
+            // the rows written before a failure are rolled back along with the delete
+            query(connection.clone(), "DEFINE INDEX unique_name ON adult FIELDS name UNIQUE;".to_string(), None).await.unwrap();
+            let failed = materialize(connection.clone(), "SELECT name FROM user; SELECT name FROM user;".to_string(), None, "adult".to_string(), MaterializeMode::Replace, false).await;
+            let not_select = materialize(connection.clone(), "DELETE user;".to_string(), None, "adult".to_string(), MaterializeMode::Append, false).await;
+            let outcome = query(connection, "
+                SELECT id, name, type::is::record(friend) AS link, type::is::datetime(joined) AS joined FROM adult WHERE id IN [adult:1, adult:3];
+                SELECT count() FROM adult GROUP ALL;
+            ".to_string(), None).await.unwrap();
+            (outcome, exists, failed, not_select, (created, replaced, appended))
+        });
+
+        let outcome: Value = from_str(&outcome).unwrap();
+        assert_eq!(outcome[0], json!([
+            {"id": "adult:1", "name": "Tobie", "link": true, "joined": true},
+            {"id": "adult:3", "name": "Dave", "link": false, "joined": false}
+        ]));
+        assert_eq!(outcome[1][0]["count"], 4);
+        assert_eq!(exists.unwrap_err(), "table adult already exists");
+        assert!(failed.unwrap_err().contains("unique_name"));
+        assert!(not_select.unwrap_err().contains("only select statements"));
+        assert_eq!(counts, (2, 2, 2));
+        assert!(MaterializeMode::from_name("upsert").is_err());
+    }
+}
@@ -0,0 +1,3 @@
+//! Defines the operations for importing records from exported files.
+pub mod core;
+pub mod python;
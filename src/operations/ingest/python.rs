@@ -0,0 +1,53 @@
+//! Python entry points for importing records from files.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use serde_json::value::Value;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{import_file, materialize, MaterializeMode};
+use crate::py_future_wrapper;
+
+
+/// Imports the records of a file into a table in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to import the records into
+/// * `path` - The path of the file
+/// * `format` - The format of the file, `ndjson` or `csv`
+/// * `mapping` - The JSON of the field mapping, rows are imported as they are if `None`
+/// * `chunk_size` - The maximum number of records sent per insert
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of records imported
+#[pyfunction]
+pub fn rust_import_file_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, path: String, format: String, mapping: Option<String>, chunk_size: Option<usize>) -> Result<&'a PyAny, PyErr> {
+    let mapping: Option<Value> = match mapping {
+        Some(mapping) => Some(serde_json::from_str(&mapping).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?),
+        None => None
+    };
+    py_future_wrapper!(py, import_file(connection, table, path, format, mapping, chunk_size))
+}
+
+
+/// Writes the rows of a query into a table in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `sql` - The select statements whose rows are written
+/// * `table` - The table to write the rows into
+/// * `bindings` - The bindings to use for the query
+/// * `mode` - What to do if the table exists, `create`, `replace` or `append` (defaults to `create`)
+/// * `keep_ids` - If true rows keep the key of their record ID in the target table (defaults to true)
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of records written
+#[pyfunction]
+pub fn rust_materialize_future<'a>(py: Python<'a>, connection: WrappedConnection, sql: String, table: String, bindings: Option<&'a PyAny>, mode: Option<String>, keep_ids: Option<bool>) -> Result<&'a PyAny, PyErr> {
+    let bindings: Option<Value> = match bindings {
+        Some(bindings) => Some(serde_json::from_str(&bindings.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?),
+        None => None
+    };
+    let mode = MaterializeMode::from_name(mode.as_deref().unwrap_or("create")).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    py_future_wrapper!(py, materialize(connection, sql, bindings, table, mode, keep_ids.unwrap_or(true)))
+}
@@ -5,6 +5,21 @@ pub mod set;
 pub mod query;
 pub mod update;
 pub mod auth;
+pub mod function;
+pub mod counter;
+pub mod session;
+pub mod constraint;
+pub mod encryption;
+pub mod blob;
+pub mod ingest;
+pub mod schema;
+pub mod pagination;
+pub mod history;
+pub mod reference;
+pub mod analysis;
+pub mod guard;
+pub mod sketch;
+pub mod diagnostics;
 
 
 /// Adds operations python entry points to a module handling this factory.
@@ -17,14 +32,62 @@ pub mod auth;
 pub fn operations_module_factory(m: &PyModule) {
     let _ = m.add_wrapped(wrap_pyfunction!(create::python::rust_create_future));
     let _ = m.add_wrapped(wrap_pyfunction!(create::python::rust_delete_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(create::python::rust_insert_future));
     let _ = m.add_wrapped(wrap_pyfunction!(set::python::rust_set_future));
     let _ = m.add_wrapped(wrap_pyfunction!(set::python::rust_unset_future));
     let _ = m.add_wrapped(wrap_pyfunction!(query::python::rust_query_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(query::python::rust_query_rows_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(query::python::rust_query_snapshot_future));
     let _ = m.add_wrapped(wrap_pyfunction!(query::python::rust_select_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(query::python::rust_limit_selects));
     let _ = m.add_wrapped(wrap_pyfunction!(auth::python::rust_sign_up_future));
     let _ = m.add_wrapped(wrap_pyfunction!(auth::python::rust_invalidate_future));
     let _ = m.add_wrapped(wrap_pyfunction!(auth::python::rust_authenticate_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(auth::python::rust_auth_info_future));
     let _ = m.add_wrapped(wrap_pyfunction!(update::python::rust_update_future));
     let _ = m.add_wrapped(wrap_pyfunction!(update::python::rust_merge_future));
     let _ = m.add_wrapped(wrap_pyfunction!(update::python::rust_patch_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(update::python::rust_update_where_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(update::python::rust_merge_three_way_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(function::python::rust_run_function_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(counter::python::rust_increment_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(counter::python::rust_next_value_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(session::python::rust_create_session_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(session::python::rust_get_session_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(session::python::rust_touch_session_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(session::python::rust_delete_session_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(session::python::rust_purge_sessions_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(constraint::python::rust_define_ci_unique_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(constraint::python::rust_find_by_ci_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(encryption::python::rust_encrypt_fields));
+    let _ = m.add_wrapped(wrap_pyfunction!(encryption::python::rust_decrypt_fields));
+    let _ = m.add_wrapped(wrap_pyfunction!(encryption::python::rust_tokenize_fields));
+    let _ = m.add_wrapped(wrap_pyfunction!(encryption::python::rust_tokenize_value));
+    let _ = m.add_wrapped(wrap_pyfunction!(blob::python::rust_store_blob_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(blob::python::rust_get_blob_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(blob::python::rust_link_blob_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(blob::python::rust_unlink_blob_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(blob::python::rust_gc_blobs_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(ingest::python::rust_import_file_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(ingest::python::rust_materialize_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(schema::python::rust_suggest_schema_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(schema::python::rust_suggest_schema_from_file));
+    let _ = m.add_wrapped(wrap_pyfunction!(schema::python::rust_field_metadata_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(schema::python::rust_query_metadata_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(pagination::python::rust_paginate_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(pagination::python::rust_partition_table_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(history::python::rust_read_as_of_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(history::python::rust_record_history_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(reference::python::rust_fetch_references_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(reference::python::rust_check_references_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(reference::python::rust_orphaned_edges_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(analysis::python::rust_usage_report_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(analysis::python::rust_storage_report_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(analysis::python::rust_profile_table_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(analysis::python::rust_profile_query_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(analysis::python::rust_sample_table_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(guard::python::rust_inspect_query));
+    let _ = m.add_wrapped(wrap_pyfunction!(sketch::python::rust_approx_distinct_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(sketch::python::rust_approx_percentiles_future));
+    let _ = m.add_wrapped(wrap_pyfunction!(diagnostics::python::rust_diagnostics_future));
 }
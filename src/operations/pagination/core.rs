@@ -0,0 +1,254 @@
+//! Defines the core functions for keyset pagination. A page is read with a record range starting
+//! after the last record of the previous page, so reading a page does not get slower the further
+//! into the table it is and records added or deleted between pages do not shift the pages. The
+//! position is handed to the caller as an opaque continuation token. A table can also be split into
+//! record ranges that are paginated over independently by parallel workers.
+//! In this module we can do the following:
+//! 
+//! * Get a page of records from a table or record range and the token for the next page
+//! * Split a table into record ranges of about the same size
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::value::Value;
+use serde_json::json;
+use std::ops::Bound;
+use surrealdb::sql::{thing, Range};
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::{execute, validate_identifier};
+
+
+/// The position in a table that a continuation token resumes from.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Cursor {
+    resource: String,
+    last: String,
+}
+
+
+/// Gets a page of records from a table or record range in record ID order.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the page with
+/// * `resource` - The table or record range such as `user:1..100` to read
+/// * `page_size` - The maximum number of records in the page
+/// * `token` - The continuation token returned with the previous page, the first page is read if `None`
+/// 
+/// # Returns
+/// * `Ok(String)` - An object with the `rows` of the page and the `next` token, `next` is `null` after the last page
+pub async fn paginate(connection: WrappedConnection, resource: String, page_size: usize, token: Option<String>) -> Result<String, String> {
+    if page_size == 0 {
+        return Err("page size must be greater than zero".to_string())
+    }
+    let (table, mut beg, end) = match resource.parse::<Range>() {
+        Ok(range) => (range.tb, range.beg, range.end),
+        Err(_) => (resource.clone(), Bound::Unbounded, Bound::Unbounded)
+    };
+    validate_identifier(&table)?;
+    if let Some(token) = token {
+        let cursor = decode_token(&token)?;
+        if cursor.resource != resource {
+            return Err(format!("continuation token is for {} and not {}", cursor.resource, resource))
+        }
+        let last = thing(&cursor.last).map_err(|_| "invalid continuation token".to_string())?;
+        beg = Bound::Excluded(last.id);
+    }
+    let source = match (&beg, &end) {
+        (Bound::Unbounded, Bound::Unbounded) => table,
+        _ => Range::new(table, beg, end).to_string()
+    };
+    let sql = format!("SELECT * FROM {} LIMIT $limit;", source);
+    let rows = match execute(connection, sql, Some(json!({"limit": page_size}))).await?.pop() {
+        Some(Value::Array(rows)) => rows,
+        _ => vec![]
+    };
+    let next = match rows.last() {
+        Some(last) if rows.len() == page_size => {
+            let last = last["id"].as_str().ok_or_else(|| "record without an ID".to_string())?;
+            Value::String(encode_token(&Cursor { resource, last: last.to_string() })?)
+        },
+        _ => Value::Null
+    };
+    Ok(json!({"rows": rows, "next": next}).to_string())
+}
+
+
+/// Splits a table into record ranges with about the same number of records in each.
+/// 
+/// # Arguments
+/// * `connection` - The connection to split the table with
+/// * `table` - The table to split
+/// * `partitions` - The number of ranges to split the table into, fewer are returned if the table is small
+/// 
+/// # Returns
+/// * `Ok(String)` - A list of objects with the `resource` of each range and the `count` of records it had
+pub async fn partition_table(connection: WrappedConnection, table: String, partitions: usize) -> Result<String, String> {
+    validate_identifier(&table)?;
+    if partitions == 0 {
+        return Err("the number of partitions must be greater than zero".to_string())
+    }
+    let sql = format!("SELECT count() FROM {} GROUP ALL;", table);
+    let count = execute(connection.clone(), sql, None).await?
+        .pop()
+        .and_then(|counts| counts[0]["count"].as_u64())
+        .unwrap_or(0) as usize;
+
+    let mut offsets: Vec<usize> = (1..partitions).map(|index| index * count / partitions).filter(|offset| *offset > 0).collect();
+    offsets.dedup();
+
+    // tables are scanned in record ID order so the record at each offset is the start of a range
+    let mut boundaries = vec![];
+    if !offsets.is_empty() {
+        let sql = offsets.iter()
+            .map(|offset| format!("SELECT VALUE id FROM {} LIMIT 1 START {};", table, offset))
+            .collect::<Vec<String>>()
+            .join(" ");
+        for ids in execute(connection, sql, None).await? {
+            let id = ids[0].as_str().ok_or_else(|| format!("table {} changed while it was being split", table))?;
+            boundaries.push(Bound::Included(thing(id).map_err(|e| e.to_string())?.id));
+        }
+    }
+
+    let mut starts = vec![Bound::Unbounded];
+    starts.extend(boundaries.iter().cloned());
+    let mut ends: Vec<Bound<_>> = boundaries.into_iter().map(|bound| match bound {
+        Bound::Included(id) => Bound::Excluded(id),
+        other => other
+    }).collect();
+    ends.push(Bound::Unbounded);
+
+    let mut sizes = vec![];
+    let mut previous = 0;
+    for offset in offsets.iter().chain([count].iter()) {
+        sizes.push(offset - previous);
+        previous = *offset;
+    }
+    let ranges: Vec<Value> = starts.into_iter().zip(ends).zip(sizes)
+        .map(|((beg, end), size)| json!({"resource": Range::new(table.clone(), beg, end).to_string(), "count": size}))
+        .collect();
+    Ok(Value::Array(ranges).to_string())
+}
+
+
+/// Encodes a cursor as a continuation token.
+fn encode_token(cursor: &Cursor) -> Result<String, String> {
+    let json = serde_json::to_vec(cursor).map_err(|e| e.to_string())?;
+    Ok(URL_SAFE_NO_PAD.encode(json))
+}
+
+
+/// Decodes a continuation token back into a cursor.
+fn decode_token(token: &str) -> Result<Cursor, String> {
+    let json = URL_SAFE_NO_PAD.decode(token).map_err(|_| "invalid continuation token".to_string())?;
+    serde_json::from_slice(&json).map_err(|_| "invalid continuation token".to_string())
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::operations::query::core::query;
+    use crate::connection::core::make_connection;
+    use tokio::runtime::Runtime;
+    use serde_json::from_str;
+
+
+    #[test]
+    fn test_paginate() {
+        let runtime = Runtime::new().unwrap();
+
+        let ids = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "FOR $i IN [1, 2, 3, 4, 5] { CREATE type::thing('user', $i) };".to_string(), None).await.unwrap();
+
+            let mut ids = vec![];
+            let mut token = None;
+            loop {
+                let page: Value = from_str(&paginate(connection.clone(), "user".to_string(), 2, token).await.unwrap()).unwrap();
+                ids.push(page["rows"].as_array().unwrap().iter().map(|row| row["id"].clone()).collect::<Vec<Value>>());
+                token = page["next"].as_str().map(|token| token.to_string());
+
+                // records added behind the cursor do not shift the following pages
+                if ids.len() == 1 {
+                    query(connection.clone(), "CREATE user:0;".to_string(), None).await.unwrap();
+                }
+                if token.is_none() {
+                    break
+                }
+            }
+            ids
+        });
+
+        assert_eq!(ids, vec![
+            vec![json!("user:1"), json!("user:2")],
+            vec![json!("user:3"), json!("user:4")],
+            vec![json!("user:5")],
+        ]);
+    }
+
+    #[test]
+    fn test_invalid_token() {
+        let runtime = Runtime::new().unwrap();
+
+        runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            let token = encode_token(&Cursor { resource: "user".to_string(), last: "user:1".to_string() }).unwrap();
+            assert!(paginate(connection.clone(), "post".to_string(), 2, Some(token)).await.is_err());
+            assert!(paginate(connection.clone(), "user".to_string(), 2, Some("not a token".to_string())).await.is_err());
+            assert!(paginate(connection.clone(), "user".to_string(), 0, None).await.is_err());
+        });
+    }
+
+    #[test]
+    fn test_token_round_trip() {
+        let cursor = Cursor { resource: "user:1..".to_string(), last: "user:⟨a b⟩".to_string() };
+        assert_eq!(decode_token(&encode_token(&cursor).unwrap()).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_partition_table() {
+        let runtime = Runtime::new().unwrap();
+
+        let (partitions, ids, single, empty) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "FOR $i IN [1, 2, 3, 4, 5, 6, 7] { CREATE type::thing('user', $i) };".to_string(), None).await.unwrap();
+
+            let partitions: Value = from_str(&partition_table(connection.clone(), "user".to_string(), 3).await.unwrap()).unwrap();
+            let mut ids = vec![];
+            for partition in partitions.as_array().unwrap() {
+                let mut token = None;
+                loop {
+                    let resource = partition["resource"].as_str().unwrap().to_string();
+                    let page: Value = from_str(&paginate(connection.clone(), resource, 2, token).await.unwrap()).unwrap();
+                    ids.extend(page["rows"].as_array().unwrap().iter().map(|row| row["id"].clone()));
+                    token = page["next"].as_str().map(|token| token.to_string());
+                    if token.is_none() {
+                        break
+                    }
+                }
+            }
+            let single: Value = from_str(&partition_table(connection.clone(), "user".to_string(), 1).await.unwrap()).unwrap();
+            let empty: Value = from_str(&partition_table(connection.clone(), "post".to_string(), 3).await.unwrap()).unwrap();
+            (partitions, ids, single, empty)
+        });
+
+        assert_eq!(partitions, json!([
+            {"resource": "user:..3", "count": 2},
+            {"resource": "user:3..5", "count": 2},
+            {"resource": "user:5..", "count": 3},
+        ]));
+        assert_eq!(ids, (1..=7).map(|i| Value::from(format!("user:{}", i))).collect::<Vec<Value>>());
+        assert_eq!(single, json!([{"resource": "user:..", "count": 7}]));
+        assert_eq!(empty, json!([{"resource": "post:..", "count": 0}]));
+    }
+
+}
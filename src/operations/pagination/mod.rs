@@ -0,0 +1,3 @@
+//! Defines the operations for iterating over large tables a page at a time.
+pub mod core;
+pub mod python;
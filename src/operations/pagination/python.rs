@@ -0,0 +1,38 @@
+//! Python entry points for keyset pagination.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{paginate, partition_table};
+use crate::py_future_wrapper;
+
+
+/// Gets a page of records from a table or record range in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `resource` - The table or record range to read
+/// * `page_size` - The maximum number of records in the page
+/// * `token` - The continuation token returned with the previous page
+/// 
+/// # Returns
+/// * `Ok(String)` - The rows of the page and the token for the next page
+#[pyfunction]
+pub fn rust_paginate_future<'a>(py: Python<'a>, connection: WrappedConnection, resource: String, page_size: usize, token: Option<String>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, paginate(connection, resource, page_size, token))
+}
+
+
+/// Splits a table into record ranges in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to split
+/// * `partitions` - The number of ranges to split the table into
+/// 
+/// # Returns
+/// * `Ok(String)` - The record ranges and the number of records in each
+#[pyfunction]
+pub fn rust_partition_table_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, partitions: usize) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, partition_table(connection, table, partitions))
+}
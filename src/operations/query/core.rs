@@ -3,11 +3,16 @@
 //! module we can do the following:
 //! 
 //! * Perform a query on the database
+//! * Perform several report queries that all see the same snapshot of the database
+//! * Extract nested values such as `orders[*].total` from the rows of a query
+//! * Flatten nested objects in the rows of a query into dotted keys
+//! * Cap the rows returned by select statements that have no limit
 use serde_json::value::Value;
+use serde_json::Map;
 use crate::connection::interface::WrappedConnection;
 use surrealdb::sql::Value as SurrealValue;
 use surrealdb::opt::Resource;
-use surrealdb::sql::Range;
+use surrealdb::sql::{parse, Field, Fields, Idiom, Limit, Order, Orders, Range, Statement};
 
 
 /// Performs a query on the database.
@@ -20,6 +25,169 @@ use surrealdb::sql::Range;
 /// # Returns
 /// * `Ok(Value)` - The result of the query
 pub async fn query(connection: WrappedConnection, sql: String, bindings: Option<Value>) -> Result<String, String> {
+	let output = execute(connection, sql, bindings).await?;
+	let json_value: Value = Value::Array(output);
+	Ok(json_value.to_string())
+}
+
+
+/// Performs a query on the database returning the rows of every statement as one flat list.
+/// 
+/// # Arguments
+/// * `connection` - The connection to perform the query on
+/// * `sql` - The SQL query to perform
+/// * `bindings` - The bindings to use for the query
+/// * `fields` - The fields to keep in each row, all fields are kept if `None`
+/// * `stable_order` - If true the select statements are also ordered by record ID, see `stabilise_order`
+/// * `extract` - The paths of the values to extract from each row, see `extract_paths`
+/// * `flatten` - How to flatten the nested objects of each row, rows are left nested if `None`
+/// 
+/// # Returns
+/// * `Ok(Value)` - The flattened rows of the query
+pub async fn query_rows(connection: WrappedConnection, sql: String, bindings: Option<Value>, fields: Option<Vec<String>>, stable_order: bool, extract: Option<Vec<String>>, flatten: Option<Flatten>) -> Result<String, String> {
+	let sql = match stable_order {
+		true => stabilise_order(&sql)?,
+		false => sql
+	};
+	let output = execute(connection, sql, bindings).await?;
+	let mut rows = shape_rows(output, fields.as_deref());
+	if let Some(paths) = extract {
+		rows = extract_paths(rows, &paths)?;
+	}
+	if let Some(flatten) = flatten {
+		rows = rows.into_iter().map(|row| flatten.row(row)).collect();
+	}
+	let json_value: Value = Value::Array(rows);
+	Ok(json_value.to_string())
+}
+
+
+/// Performs several queries in a single transaction so they all read the same snapshot of the database,
+/// rather than each seeing the writes made between them. The queries share the bindings and
+/// may not contain their own transaction statements.
+/// 
+/// # Arguments
+/// * `connection` - The connection to perform the queries on
+/// * `queries` - The SQL queries to perform
+/// * `bindings` - The bindings to use for the queries
+/// 
+/// # Returns
+/// * `Ok(Value)` - The result of the last statement of each query
+pub async fn query_snapshot(connection: WrappedConnection, queries: Vec<String>, bindings: Option<Value>) -> Result<String, String> {
+	let mut statements = Vec::with_capacity(queries.len());
+	let mut sql = vec!["BEGIN TRANSACTION;".to_string()];
+	for text in queries.iter() {
+		let mut parsed = parse(text).map_err(|e| e.to_string())?;
+		if parsed.0.0.is_empty() {
+			return Err("snapshot queries can not be empty".to_string())
+		}
+		for statement in parsed.0.0.iter_mut() {
+			match statement {
+				Statement::Begin(_) | Statement::Cancel(_) | Statement::Commit(_) => {
+					return Err("snapshot queries can not contain transaction statements".to_string())
+				},
+				// a RETURN inside a transaction ends it early so it is run as a plain expression instead
+				Statement::Output(output) if output.fetch.is_none() => {
+					*statement = Statement::Value(output.what.clone());
+				},
+				Statement::Output(_) => {
+					return Err("snapshot queries can not contain RETURN with FETCH".to_string())
+				},
+				_ => {}
+			}
+		}
+		statements.push(parsed.0.0.len());
+		sql.push(parsed.to_string());
+	}
+	sql.push("COMMIT TRANSACTION;".to_string());
+
+	let mut output = execute(connection, sql.join("\n"), bindings).await?.into_iter();
+	let results = statements.into_iter()
+		.map(|count| output.by_ref().take(count).last().unwrap_or(Value::Null))
+		.collect();
+	Ok(Value::Array(results).to_string())
+}
+
+
+/// Appends the record ID to the ordering of every select statement so rows with the same values
+/// for the `ORDER BY` fields always come back in the same order and pages never skip or repeat rows.
+/// The ID is added to the selected fields if it is not selected already. Statements with a `GROUP BY`,
+/// a random order or `SELECT VALUE` have no ID to order by and are left alone.
+/// 
+/// # Arguments
+/// * `sql` - The SQL query to rewrite
+/// 
+/// # Returns
+/// * `Ok(String)` - The rewritten query
+pub fn stabilise_order(sql: &str) -> Result<String, String> {
+	let mut query = parse(sql).map_err(|e| e.to_string())?;
+	let id = Idiom::from("id".to_string());
+	for statement in query.0.0.iter_mut() {
+		let select = match statement {
+			Statement::Select(select) if select.group.is_none() && !select.expr.1 => select,
+			_ => continue
+		};
+		let mut orders = select.order.take().map(|orders| orders.0).unwrap_or_default();
+		if !orders.iter().any(|order| order.random || order.order == id) {
+			orders.push(Order { order: id.clone(), random: false, collate: false, numeric: false, direction: true });
+		}
+		if !selects_id(&select.expr, &id) {
+			select.expr.0.push(Field::Single { expr: SurrealValue::Idiom(id.clone()), alias: None });
+		}
+		select.order = Some(Orders(orders));
+	}
+	Ok(query.to_string())
+}
+
+
+/// Adds a `LIMIT` to every select statement that has none so exploratory queries cannot scan a whole
+/// table on a shared database. Selects that already have a limit, select `ONLY` one record or only
+/// select record IDs are left alone, as are selects nested inside other statements.
+/// 
+/// # Arguments
+/// * `sql` - The SQL query to rewrite
+/// * `max_rows` - The limit to add to the select statements
+/// 
+/// # Returns
+/// * `Ok(String)` - The rewritten query
+pub fn limit_selects(sql: &str, max_rows: u64) -> Result<String, String> {
+	let mut query = parse(sql).map_err(|e| e.to_string())?;
+	for statement in query.0.0.iter_mut() {
+		let select = match statement {
+			Statement::Select(select) if select.limit.is_none() && !select.only => select,
+			_ => continue
+		};
+		if select.what.0.iter().all(|what| matches!(what, SurrealValue::Thing(_))) {
+			continue
+		}
+		select.limit = Some(Limit(SurrealValue::from(max_rows)));
+	}
+	Ok(query.to_string())
+}
+
+
+/// Checks if the record ID is one of the selected fields.
+fn selects_id(fields: &Fields, id: &Idiom) -> bool {
+	fields.0.iter().any(|field| match field {
+		Field::All => true,
+		Field::Single { expr, alias } => match alias {
+			Some(alias) => alias == id,
+			None => matches!(expr, SurrealValue::Idiom(idiom) if idiom == id)
+		}
+	})
+}
+
+
+/// Runs a query on the database and collects the result of each statement.
+/// 
+/// # Arguments
+/// * `connection` - The connection to perform the query on
+/// * `sql` - The SQL query to perform
+/// * `bindings` - The bindings to use for the query
+/// 
+/// # Returns
+/// * `Ok(Vec<Value>)` - The result of each statement, erroring if any of the statements failed
+pub async fn execute(connection: WrappedConnection, sql: String, bindings: Option<Value>) -> Result<Vec<Value>, String> {
 	let mut response = match bindings {
 		Some(bind) => {connection.connection.query(sql).bind(bind).await},
 		None => {connection.connection.query(sql).await}
@@ -34,10 +202,228 @@ pub async fn query(connection: WrappedConnection, sql: String, bindings: Option<
 		let value: SurrealValue = response.take(index).map_err(|x| x.to_string())?;
 		output.push(value.into_json());
 	}
-	let json_value: Value = Value::Array(output);
-	Ok(json_value.to_string())
+	Ok(output)
+}
+
+
+/// Checks that a field path only contains identifiers separated by `.` so it can be safely
+/// put into a statement.
+/// 
+/// # Arguments
+/// * `path` - The field path such as `name` or `name.first`
+/// 
+/// # Returns
+/// * `Ok(())` - The field path is valid
+pub fn validate_field_path(path: &str) -> Result<(), String> {
+	let valid = path.split('.').all(|part| {
+		!part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+	});
+	if !valid {
+		return Err(format!("invalid field path: {}", path))
+	}
+	Ok(())
+}
+
+
+/// Checks that a name such as a table is a single identifier so it can be safely put into a statement.
+/// 
+/// # Arguments
+/// * `name` - The name to be checked
+/// 
+/// # Returns
+/// * `Ok(())` - The name is valid
+pub fn validate_identifier(name: &str) -> Result<(), String> {
+	if name.contains('.') || validate_field_path(name).is_err() {
+		return Err(format!("invalid identifier: {}", name))
+	}
+	Ok(())
+}
+
+
+/// Flattens the results of multiple statements into a single list of rows.
+/// 
+/// # Arguments
+/// * `statements` - The result of each statement
+/// * `fields` - The fields to keep in each row, all fields are kept if `None`
+/// 
+/// # Returns
+/// * `Vec<Value>` - The rows of all the statements with statements returning nothing skipped
+pub fn shape_rows(statements: Vec<Value>, fields: Option<&[String]>) -> Vec<Value> {
+	let mut rows = Vec::new();
+	for statement in statements {
+		match statement {
+			Value::Array(items) => rows.extend(items),
+			Value::Null => {},
+			other => rows.push(other)
+		}
+	}
+	let fields = match fields {
+		Some(fields) => fields,
+		None => return rows
+	};
+	rows.into_iter().map(|row| match row {
+		Value::Object(mut map) => {
+			let projected = fields.iter()
+				.filter_map(|field| map.remove(field).map(|value| (field.clone(), value)))
+				.collect();
+			Value::Object(projected)
+		},
+		other => other
+	}).collect()
+}
+
+/// How lists are handled when flattening a row.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrayMode {
+	/// Lists are kept as the value of their key.
+	Keep,
+	/// Each item of a list gets its own key ending with its index.
+	Index,
+	/// Lists are turned into a JSON string.
+	Json,
+}
+
+impl ArrayMode {
+	/// Gets the mode from its name.
+	/// 
+	/// # Arguments
+	/// * `name` - The name of the mode, `keep`, `index` or `json`
+	/// 
+	/// # Returns
+	/// * `Ok(ArrayMode)` - The mode
+	pub fn from_name(name: &str) -> Result<ArrayMode, String> {
+		match name.to_lowercase().as_str() {
+			"keep" => Ok(ArrayMode::Keep),
+			"index" => Ok(ArrayMode::Index),
+			"json" => Ok(ArrayMode::Json),
+			other => Err(format!("unknown array mode: {}", other))
+		}
+	}
+}
+
+
+/// Flattens nested objects into a single level with the keys of each level joined by a separator,
+/// so `{"address": {"city": "London"}}` becomes `{"address.city": "London"}`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Flatten {
+	pub separator: String,
+	pub arrays: ArrayMode,
+}
+
+impl Flatten {
+	/// Flattens a row, values that are not objects are returned as they are.
+	/// 
+	/// # Arguments
+	/// * `row` - The row to flatten
+	/// 
+	/// # Returns
+	/// * `Value` - The flattened row
+	pub fn row(&self, row: Value) -> Value {
+		match row {
+			Value::Object(map) => {
+				let mut flat = Map::new();
+				for (key, value) in map {
+					self.insert(&mut flat, key, value);
+				}
+				Value::Object(flat)
+			},
+			other => other
+		}
+	}
+
+	/// Adds a value to a flattened row under a key, flattening the value if it is nested.
+	fn insert(&self, flat: &mut Map<String, Value>, key: String, value: Value) {
+		match value {
+			Value::Object(map) if !map.is_empty() => {
+				for (child, value) in map {
+					self.insert(flat, format!("{}{}{}", key, self.separator, child), value);
+				}
+			},
+			Value::Array(items) if self.arrays == ArrayMode::Index && !items.is_empty() => {
+				for (index, value) in items.into_iter().enumerate() {
+					self.insert(flat, format!("{}{}{}", key, self.separator, index), value);
+				}
+			},
+			Value::Array(items) if self.arrays == ArrayMode::Json => {
+				flat.insert(key, Value::String(Value::Array(items).to_string()));
+			},
+			value => {
+				flat.insert(key, value);
+			}
+		}
+	}
 }
 
+
+/// A step of a path used to extract values from a row.
+#[derive(Debug, PartialEq)]
+enum PathStep {
+	Field(String),
+	Index(usize),
+	All,
+}
+
+
+/// Replaces each row with an object holding the value found at each path. A path is made of field
+/// names separated by `.` that can be followed by `[n]` to take an item of a list or `[*]` to take
+/// every item. Paths with `[*]` give a list of every value found, other paths give the value or `null`
+/// if it was not found.
+/// 
+/// # Arguments
+/// * `rows` - The rows to extract the values from
+/// * `paths` - The paths of the values such as `name`, `orders[0].id` or `orders[*].total`
+/// 
+/// # Returns
+/// * `Ok(Vec<Value>)` - An object for each row with the paths as keys
+pub fn extract_paths(rows: Vec<Value>, paths: &[String]) -> Result<Vec<Value>, String> {
+	let parsed = paths.iter().map(|path| parse_path(path)).collect::<Result<Vec<Vec<PathStep>>, String>>()?;
+	Ok(rows.iter().map(|row| {
+		let extracted = paths.iter().zip(parsed.iter()).map(|(path, steps)| {
+			let mut found = vec![row];
+			for step in steps {
+				found = found.into_iter().flat_map(|value| match (step, value) {
+					(PathStep::Field(name), Value::Object(map)) => map.get(name).into_iter().collect(),
+					(PathStep::Index(index), Value::Array(items)) => items.get(*index).into_iter().collect(),
+					(PathStep::All, Value::Array(items)) => items.iter().collect(),
+					_ => vec![]
+				}).collect();
+			}
+			let value = match steps.contains(&PathStep::All) {
+				true => Value::Array(found.into_iter().cloned().collect()),
+				false => found.pop().cloned().unwrap_or(Value::Null)
+			};
+			(path.clone(), value)
+		}).collect();
+		Value::Object(extracted)
+	}).collect())
+}
+
+
+/// Splits a path such as `orders[*].total` into its steps.
+fn parse_path(path: &str) -> Result<Vec<PathStep>, String> {
+	let invalid = || format!("invalid path: {}", path);
+	let mut steps = vec![];
+	for part in path.split('.') {
+		let (name, mut rest) = part.split_at(part.find('[').unwrap_or(part.len()));
+		validate_field_path(name).map_err(|_| invalid())?;
+		steps.push(PathStep::Field(name.to_string()));
+		while !rest.is_empty() {
+			let end = rest.find(']').ok_or_else(invalid)?;
+			let index = rest.get(1..end).ok_or_else(invalid)?;
+			steps.push(match index {
+				"*" => PathStep::All,
+				index => PathStep::Index(index.parse().map_err(|_| invalid())?)
+			});
+			rest = &rest[end + 1..];
+			if !rest.is_empty() && !rest.starts_with('[') {
+				return Err(invalid())
+			}
+		}
+	}
+	Ok(steps)
+}
+
+
 /// Performs a select on the database.
 /// 
 /// # Arguments
@@ -157,4 +543,181 @@ mod tests {
 		assert_eq!(outcome["id"], "user:2");
 	}
 
+	#[test]
+	fn test_query_rows() {
+		let runtime = Runtime::new().unwrap();
+
+		let outcome = runtime.block_on(async {
+			let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+
+			query(connection.clone(), "CREATE user:1 SET name = 'Tobie', age = 1;".to_string(), None).await.unwrap();
+			query(connection.clone(), "CREATE user:2 SET name = 'Jaime', age = 2;".to_string(), None).await.unwrap();
+
+			let sql = "LET $age = 1; SELECT * FROM user WHERE age = $age; SELECT * FROM user:2;".to_string();
+			query_rows(connection, sql, None, Some(vec!["name".to_string()]), false, None, None).await.unwrap()
+		});
+
+		let outcome: Value = from_str(&outcome).unwrap();
+		assert_eq!(outcome.as_array().unwrap().len(), 2);
+		assert_eq!(outcome[0], serde_json::json!({"name": "Tobie"}));
+		assert_eq!(outcome[1], serde_json::json!({"name": "Jaime"}));
+	}
+
+	#[test]
+	fn test_query_rows_statement_error() {
+		let runtime = Runtime::new().unwrap();
+
+		let outcome = runtime.block_on(async {
+			let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+
+			let sql = "SELECT * FROM user; THROW 'failed';".to_string();
+			query_rows(connection, sql, None, None, false, None, None).await
+		});
+
+		assert!(outcome.is_err());
+	}
+
+	#[test]
+	fn test_stabilise_order() {
+		assert_eq!(
+			stabilise_order("SELECT * FROM user ORDER BY age DESC LIMIT 2 START 2;").unwrap(),
+			"SELECT * FROM user ORDER BY age DESC, id LIMIT 2 START 2;"
+		);
+		assert_eq!(
+			stabilise_order("SELECT name FROM user WHERE age > $age;").unwrap(),
+			"SELECT name, id FROM user WHERE age > $age ORDER BY id;"
+		);
+		assert_eq!(
+			stabilise_order("SELECT * FROM user ORDER BY id DESC;").unwrap(),
+			"SELECT * FROM user ORDER BY id DESC;"
+		);
+		assert_eq!(
+			stabilise_order("LET $a = 1; SELECT count() FROM user GROUP ALL; SELECT VALUE name FROM user;").unwrap(),
+			"LET $a = 1;\nSELECT count() FROM user GROUP ALL;\nSELECT VALUE name FROM user;"
+		);
+		assert!(stabilise_order("SELECT * FROM").is_err());
+	}
+
+	#[test]
+	fn test_limit_selects() {
+		assert_eq!(
+			limit_selects("SELECT * FROM user WHERE age > $age;", 100).unwrap(),
+			"SELECT * FROM user WHERE age > $age LIMIT 100;"
+		);
+		assert_eq!(
+			limit_selects("SELECT * FROM user LIMIT 5000; SELECT * FROM ONLY user:1; SELECT * FROM user:1, user:2;", 100).unwrap(),
+			"SELECT * FROM user LIMIT 5000;\nSELECT * FROM ONLY user:1;\nSELECT * FROM user:1, user:2;"
+		);
+		assert_eq!(
+			limit_selects("LET $a = 1; CREATE user:3; SELECT VALUE name FROM user, user:1;", 10).unwrap(),
+			"LET $a = 1;\nCREATE user:3;\nSELECT VALUE name FROM user, user:1 LIMIT 10;"
+		);
+		assert!(limit_selects("SELECT * FROM", 100).is_err());
+	}
+
+	#[test]
+	fn test_query_rows_stable_order() {
+		let runtime = Runtime::new().unwrap();
+
+		let pages = runtime.block_on(async {
+			let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+			query(connection.clone(), "FOR $i IN [1, 2, 3, 4, 5, 6] { CREATE type::thing('user', $i) SET age = 1 };".to_string(), None).await.unwrap();
+
+			let mut pages = vec![];
+			for start in [0, 2, 4] {
+				let sql = format!("SELECT age FROM user ORDER BY age LIMIT 2 START {};", start);
+				pages.push(query_rows(connection.clone(), sql, None, Some(vec!["id".to_string()]), true, None, None).await.unwrap());
+			}
+			pages
+		});
+
+		let ids: Vec<Value> = pages.iter().flat_map(|page| from_str::<Vec<Value>>(page).unwrap()).map(|row| row["id"].clone()).collect();
+		assert_eq!(ids, (1..=6).map(|i| Value::from(format!("user:{}", i))).collect::<Vec<Value>>());
+	}
+
+
+	#[test]
+	fn test_query_snapshot() {
+
+		let runtime = Runtime::new().unwrap();
+
+		let (outcome, nested, invalid) = runtime.block_on(async {
+			let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+
+			query(connection.clone(), "CREATE user:tobie SET age = 30; CREATE user:jaime SET age = 20;".to_string(), None).await.unwrap();
+
+			let outcome = query_snapshot(connection.clone(), vec![
+				"SELECT VALUE age FROM user WHERE age > $min;".to_string(),
+				"LET $total = math::sum((SELECT VALUE age FROM user)); RETURN $total;".to_string(),
+			], Some(serde_json::json!({"min": 25}))).await.unwrap();
+			let nested = query_snapshot(connection.clone(), vec!["BEGIN; SELECT * FROM user; COMMIT;".to_string()], None).await;
+			let invalid = query_snapshot(connection, vec!["SELEC * FROM user;".to_string()], None).await;
+			(outcome, nested, invalid)
+		});
+
+		let outcome: Value = from_str(&outcome).unwrap();
+		assert_eq!(outcome, serde_json::json!([[30], 50]));
+		assert!(nested.unwrap_err().contains("transaction statements"));
+		assert!(invalid.is_err());
+	}
+
+	#[test]
+	fn test_extract_paths() {
+		let rows = vec![
+			serde_json::json!({"name": "Tobie", "orders": [{"total": 10, "items": [{"sku": "a"}, {"sku": "b"}]}, {"total": 5, "items": []}]}),
+			serde_json::json!({"name": "Jaime"}),
+		];
+		let paths = vec!["name".to_string(), "orders[*].total".to_string(), "orders[0].items[*].sku".to_string(), "orders[1].total".to_string()];
+		assert_eq!(extract_paths(rows, &paths).unwrap(), vec![
+			serde_json::json!({"name": "Tobie", "orders[*].total": [10, 5], "orders[0].items[*].sku": ["a", "b"], "orders[1].total": 5}),
+			serde_json::json!({"name": "Jaime", "orders[*].total": [], "orders[0].items[*].sku": [], "orders[1].total": null}),
+		]);
+		for invalid in ["", "orders[", "orders[x]", "orders[*]total", "[0]", "a..b", "name; DELETE user"] {
+			assert!(extract_paths(vec![], &[invalid.to_string()]).is_err(), "{}", invalid);
+		}
+	}
+
+	#[test]
+	fn test_query_rows_extract() {
+
+		let runtime = Runtime::new().unwrap();
+
+		let outcome = runtime.block_on(async {
+			let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+
+			query(connection.clone(), "CREATE user:tobie SET orders = [{total: 10}, {total: 20}];".to_string(), None).await.unwrap();
+			let sql = "SELECT * FROM user;".to_string();
+			query_rows(connection, sql, None, None, false, Some(vec!["orders[*].total".to_string()]), None).await.unwrap()
+		});
+
+		let outcome: Value = from_str(&outcome).unwrap();
+		assert_eq!(outcome, serde_json::json!([{"orders[*].total": [10, 20]}]));
+	}
+
+	#[test]
+	fn test_flatten() {
+		let row = serde_json::json!({"id": "user:tobie", "address": {"city": "London", "geo": {"lat": 1}}, "tags": ["a", {"b": 1}], "meta": {}});
+		let keep = Flatten { separator: ".".to_string(), arrays: ArrayMode::Keep };
+		assert_eq!(keep.row(row.clone()), serde_json::json!({
+			"id": "user:tobie", "address.city": "London", "address.geo.lat": 1, "tags": ["a", {"b": 1}], "meta": {}
+		}));
+		let index = Flatten { separator: "__".to_string(), arrays: ArrayMode::Index };
+		assert_eq!(index.row(row.clone()), serde_json::json!({
+			"id": "user:tobie", "address__city": "London", "address__geo__lat": 1, "tags__0": "a", "tags__1__b": 1, "meta": {}
+		}));
+		let json = Flatten { separator: ".".to_string(), arrays: ArrayMode::Json };
+		assert_eq!(json.row(row)["tags"], serde_json::json!("[\"a\",{\"b\":1}]"));
+		assert_eq!(keep.row(serde_json::json!(5)), serde_json::json!(5));
+		assert!(ArrayMode::from_name("explode").is_err());
+	}
 }
@@ -4,7 +4,7 @@ use pyo3::types::PyAny;
 use serde_json::value::Value;
 
 use crate::connection::interface::WrappedConnection;
-use super::core::{query, select};
+use super::core::{limit_selects, query, query_rows, query_snapshot, select, ArrayMode, Flatten};
 use crate::py_future_wrapper;
 
 
@@ -31,6 +31,65 @@ pub fn rust_query_future<'a>(py: Python<'a>, connection: WrappedConnection, sql:
 }
 
 
+/// Performs a query returning the rows of every statement as one flat list in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `sql` - The SQL query to perform
+/// * `bindings` - The bindings to use for the query
+/// * `fields` - The fields to keep in each row, all fields are kept if not provided
+/// * `stable_order` - If true the select statements are also ordered by record ID (defaults to false)
+/// * `extract` - The paths of the values to extract from each row such as `orders[*].total`
+/// * `flatten_separator` - The separator to flatten nested objects with, rows are left nested if not provided
+/// * `flatten_arrays` - How lists are flattened, `keep`, `index` or `json` (defaults to `keep`)
+/// 
+/// # Returns
+/// * `Ok(String)` - The flattened rows of the query
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn rust_query_rows_future<'a>(py: Python<'a>, connection: WrappedConnection, sql: String, bindings: Option<&'a PyAny>, fields: Option<Vec<String>>, stable_order: Option<bool>, extract: Option<Vec<String>>, flatten_separator: Option<String>, flatten_arrays: Option<String>) -> Result<&'a PyAny, PyErr> {
+
+    let processed_bindings = match bindings {
+        Some(bindings) => {
+            let bindings: Value = serde_json::from_str(&bindings.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+            Some(bindings)
+        },
+        None => None
+    };
+    let flatten = match flatten_separator {
+        Some(separator) => {
+            let arrays = ArrayMode::from_name(flatten_arrays.as_deref().unwrap_or("keep")).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+            Some(Flatten { separator, arrays })
+        },
+        None => None
+    };
+    py_future_wrapper!(py, query_rows(connection, sql, processed_bindings, fields, stable_order.unwrap_or(false), extract, flatten))
+}
+
+
+/// Performs several queries that all read the same snapshot of the database in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `queries` - The SQL queries to perform
+/// * `bindings` - The bindings to use for the queries
+/// 
+/// # Returns
+/// * `Ok(String)` - The result of each query
+#[pyfunction]
+pub fn rust_query_snapshot_future<'a>(py: Python<'a>, connection: WrappedConnection, queries: Vec<String>, bindings: Option<&'a PyAny>) -> Result<&'a PyAny, PyErr> {
+
+    let processed_bindings = match bindings {
+        Some(bindings) => {
+            let bindings: Value = serde_json::from_str(&bindings.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+            Some(bindings)
+        },
+        None => None
+    };
+    py_future_wrapper!(py, query_snapshot(connection, queries, processed_bindings))
+}
+
+
 /// Performs a select on the database in an non-async manner.
 /// 
 /// # Arguments
@@ -43,3 +102,17 @@ pub fn rust_query_future<'a>(py: Python<'a>, connection: WrappedConnection, sql:
 pub fn rust_select_future(py: Python, connection: WrappedConnection, resource: String) -> Result<&PyAny, PyErr> {
     py_future_wrapper!(py, select(connection, resource))
 }
+
+
+/// Adds a limit to the select statements of a query that have none.
+/// 
+/// # Arguments
+/// * `sql` - The SQL query to rewrite
+/// * `max_rows` - The limit to add to the select statements
+/// 
+/// # Returns
+/// * `Ok(String)` - The rewritten query
+#[pyfunction]
+pub fn rust_limit_selects(sql: String, max_rows: u64) -> Result<String, PyErr> {
+    limit_selects(&sql, max_rows).map_err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>)
+}
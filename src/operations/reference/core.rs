@@ -0,0 +1,414 @@
+//! Defines the core functions for working with record links. Following the links of a result set one
+//! record at a time from Python needs a query per link, so the linked records are fetched here in
+//! batches with each record only fetched once. In this module we can do the following:
+//! 
+//! * Fetch the records linked from a set of rows and embed them in the rows or return them alongside
+//! * Find and fix links to records that no longer exist
+//! * Find and delete graph edges whose `in` or `out` record no longer exists
+use serde_json::value::Value;
+use serde_json::{from_str, json, Map};
+use std::collections::BTreeSet;
+use surrealdb::sql::{thing, Thing, Value as SurrealValue};
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::pagination::core::paginate;
+use crate::operations::query::core::{validate_field_path, validate_identifier};
+
+
+/// The number of linked records fetched per query.
+pub const FETCH_BATCH_SIZE: usize = 1000;
+
+
+/// What `check_references` does with the links to records that no longer exist.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DanglingFix {
+    /// The dangling links are removed, a field holding a single link is unset.
+    Unset,
+    /// The records holding dangling links are deleted.
+    Delete,
+}
+
+impl DanglingFix {
+    /// Gets the fix from its name.
+    /// 
+    /// # Arguments
+    /// * `name` - The name of the fix, `null` or `delete`
+    /// 
+    /// # Returns
+    /// * `Ok(DanglingFix)` - The fix
+    pub fn from_name(name: &str) -> Result<DanglingFix, String> {
+        match name.to_lowercase().as_str() {
+            "null" | "unset" => Ok(DanglingFix::Unset),
+            "delete" => Ok(DanglingFix::Delete),
+            other => Err(format!("unknown fix for dangling references: {}", other))
+        }
+    }
+}
+
+
+/// Fetches the records linked from fields of a set of rows. A field can hold a record ID or a list of
+/// record IDs. When embedding, the IDs in the rows are replaced by the records they link to and
+/// links to records that do not exist are left as IDs. Otherwise the rows are left as they are and
+/// the linked records are returned alongside them keyed by ID.
+/// 
+/// # Arguments
+/// * `connection` - The connection to fetch the records with
+/// * `rows` - The rows holding the links
+/// * `fields` - The paths of the fields holding the links such as `author` or `post.author`
+/// * `embed` - If true the linked records are embedded in the rows
+/// 
+/// # Returns
+/// * `Ok(String)` - An object with the `rows` and the `included` records
+pub async fn fetch_references(connection: WrappedConnection, rows: Value, fields: Vec<String>, embed: bool) -> Result<String, String> {
+    let mut rows = match rows {
+        Value::Array(rows) => rows,
+        _ => return Err("rows must be a list".to_string())
+    };
+    for field in fields.iter() {
+        validate_field_path(field)?;
+    }
+
+    let mut ids = BTreeSet::new();
+    for row in rows.iter() {
+        for field in fields.iter() {
+            for link in links(lookup(row, field)) {
+                ids.insert(link.to_string());
+            }
+        }
+    }
+
+    let mut included = Map::new();
+    let ids: Vec<Thing> = ids.iter().filter_map(|id| thing(id).ok()).collect();
+    for batch in ids.chunks(FETCH_BATCH_SIZE) {
+        let mut response = connection.connection.query("SELECT * FROM $ids;")
+            .bind(("ids", batch.to_vec()))
+            .await.map_err(|e| e.to_string())?;
+        let records: SurrealValue = response.take(0).map_err(|e| e.to_string())?;
+        if let Value::Array(records) = records.into_json() {
+            for record in records {
+                if let Some(id) = record["id"].as_str() {
+                    included.insert(id.to_string(), record.clone());
+                }
+            }
+        }
+    }
+
+    if !embed {
+        return Ok(json!({"rows": rows, "included": included}).to_string())
+    }
+    for row in rows.iter_mut() {
+        for field in fields.iter() {
+            if let Some(value) = lookup_mut(row, field) {
+                embed_links(value, &included);
+            }
+        }
+    }
+    Ok(json!({"rows": rows, "included": {}}).to_string())
+}
+
+
+/// Checks a batch of the records of a table for links to records that no longer exist, optionally
+/// fixing them. The table is read in record ID order a batch at a time so large tables can be checked
+/// with a call per batch, passing the returned token to the next call.
+/// 
+/// # Arguments
+/// * `connection` - The connection to check the records with
+/// * `table` - The table holding the links
+/// * `fields` - The paths of the fields holding a record ID or a list of them
+/// * `batch_size` - The number of records checked
+/// * `token` - The token returned by the previous batch, the first batch is checked if `None`
+/// * `fix` - What to do with the dangling links, they are only reported if `None`
+/// 
+/// # Returns
+/// * `Ok(String)` - An object with the `dangling` links of each record and field, the number of records
+///   `checked` and the `next` token
+pub async fn check_references(connection: WrappedConnection, table: String, fields: Vec<String>, batch_size: usize, token: Option<String>, fix: Option<DanglingFix>) -> Result<String, String> {
+    validate_identifier(&table)?;
+    for field in fields.iter() {
+        validate_field_path(field)?;
+    }
+    let page: Value = from_str(&paginate(connection.clone(), table, batch_size, token).await?).map_err(|e| e.to_string())?;
+    let rows = page["rows"].as_array().cloned().unwrap_or_default();
+
+    // the records are read again without going through JSON, where a record ID and a string that
+    // looks like one can not be told apart
+    let row_ids: Vec<Thing> = rows.iter().filter_map(|row| row["id"].as_str().and_then(|id| thing(id).ok())).collect();
+    let mut response = connection.connection.query("SELECT * FROM $ids;")
+        .bind(("ids", row_ids))
+        .await.map_err(|e| e.to_string())?;
+    let records = match response.take::<SurrealValue>(0).map_err(|e| e.to_string())? {
+        SurrealValue::Array(records) => records.0,
+        _ => vec![]
+    };
+
+    let mut ids = BTreeSet::new();
+    for record in records.iter() {
+        for field in fields.iter() {
+            ids.extend(record_links(lookup_value(record, field)).into_iter().map(|link| link.to_string()));
+        }
+    }
+    let ids: Vec<Thing> = ids.iter().filter_map(|id| thing(id).ok()).collect();
+    let mut existing = BTreeSet::new();
+    for batch in ids.chunks(FETCH_BATCH_SIZE) {
+        let mut response = connection.connection.query("SELECT VALUE id FROM $ids;")
+            .bind(("ids", batch.to_vec()))
+            .await.map_err(|e| e.to_string())?;
+        let found: SurrealValue = response.take(0).map_err(|e| e.to_string())?;
+        if let SurrealValue::Array(found) = found {
+            existing.extend(found.0.into_iter().filter_map(|id| match id {
+                SurrealValue::Thing(id) => Some(id.to_string()),
+                _ => None
+            }));
+        }
+    }
+
+    let mut dangling = vec![];
+    for record in records.iter() {
+        let id = match lookup_value(record, "id") {
+            Some(SurrealValue::Thing(id)) => id.clone(),
+            _ => continue
+        };
+        for field in fields.iter() {
+            let value = lookup_value(record, field);
+            let missing: Vec<Thing> = record_links(value).into_iter().filter(|link| !existing.contains(&link.to_string())).cloned().collect();
+            if missing.is_empty() {
+                continue
+            }
+            match fix {
+                Some(DanglingFix::Unset) if matches!(value, Some(SurrealValue::Array(_))) => {
+                    let sql = format!("UPDATE $id SET {} -= $missing RETURN NONE;", field);
+                    connection.connection.query(sql).bind(("id", id.clone())).bind(("missing", missing.clone())).await.map_err(|e| e.to_string())?
+                        .check().map_err(|e| e.to_string())?;
+                },
+                Some(DanglingFix::Unset) => {
+                    let sql = format!("UPDATE $id SET {} = NONE RETURN NONE;", field);
+                    connection.connection.query(sql).bind(("id", id.clone())).await.map_err(|e| e.to_string())?
+                        .check().map_err(|e| e.to_string())?;
+                },
+                Some(DanglingFix::Delete) => {
+                    connection.connection.query("DELETE $id;").bind(("id", id.clone())).await.map_err(|e| e.to_string())?
+                        .check().map_err(|e| e.to_string())?;
+                },
+                None => {}
+            }
+            let missing: Vec<String> = missing.iter().map(|link| link.to_string()).collect();
+            dangling.push(json!({"id": id.to_string(), "field": field, "missing": missing}));
+        }
+    }
+    Ok(json!({"dangling": dangling, "checked": rows.len(), "next": page["next"]}).to_string())
+}
+
+
+/// Checks a batch of the edges of a graph table for edges whose `in` or `out` record no longer exists,
+/// optionally deleting them. The edges are read a batch at a time like `check_references`.
+/// 
+/// # Arguments
+/// * `connection` - The connection to check the edges with
+/// * `table` - The edge table
+/// * `batch_size` - The number of edges checked
+/// * `token` - The token returned by the previous batch, the first batch is checked if `None`
+/// * `delete` - If true the orphaned edges are deleted
+/// 
+/// # Returns
+/// * `Ok(String)` - An object with the `orphans` and their `missing` records, the number of edges `checked`
+///   and the `next` token
+pub async fn orphaned_edges(connection: WrappedConnection, table: String, batch_size: usize, token: Option<String>, delete: bool) -> Result<String, String> {
+    let fields = vec!["in".to_string(), "out".to_string()];
+    let fix = if delete { Some(DanglingFix::Delete) } else { None };
+    let outcome: Value = from_str(&check_references(connection, table, fields, batch_size, token, fix).await?).map_err(|e| e.to_string())?;
+
+    // an edge missing both ends is reported once
+    let mut orphans: Vec<Value> = vec![];
+    for dangling in outcome["dangling"].as_array().into_iter().flatten() {
+        match orphans.last_mut() {
+            Some(orphan) if orphan["id"] == dangling["id"] => {
+                if let (Some(missing), Some(more)) = (orphan["missing"].as_array_mut(), dangling["missing"].as_array()) {
+                    missing.extend(more.iter().cloned());
+                }
+            },
+            _ => orphans.push(json!({"id": dangling["id"], "missing": dangling["missing"]}))
+        }
+    }
+    Ok(json!({"orphans": orphans, "checked": outcome["checked"], "next": outcome["next"]}).to_string())
+}
+
+
+/// Gets the value at a dotted field path of a row.
+fn lookup<'a>(row: &'a Value, field: &str) -> Option<&'a Value> {
+    field.split('.').try_fold(row, |value, part| value.get(part))
+}
+
+
+/// Gets the value at a dotted field path of a row to change it.
+fn lookup_mut<'a>(row: &'a mut Value, field: &str) -> Option<&'a mut Value> {
+    field.split('.').try_fold(row, |value, part| value.get_mut(part))
+}
+
+
+/// Gets the value at a dotted field path of a record read without going through JSON.
+fn lookup_value<'a>(record: &'a SurrealValue, field: &str) -> Option<&'a SurrealValue> {
+    field.split('.').try_fold(record, |value, part| match value {
+        SurrealValue::Object(object) => object.get(part),
+        _ => None
+    })
+}
+
+
+/// Gets the record links held by a field, which can be a single link or a list of them. Strings are
+/// never links, even if they look like a record ID.
+fn record_links(value: Option<&SurrealValue>) -> Vec<&Thing> {
+    match value {
+        Some(SurrealValue::Thing(id)) => vec![id],
+        Some(SurrealValue::Array(items)) => items.iter().flat_map(|item| record_links(Some(item))).collect(),
+        _ => vec![]
+    }
+}
+
+
+/// Gets the record IDs held by a field, which can be a single ID or a list of them.
+fn links(value: Option<&Value>) -> Vec<&str> {
+    match value {
+        Some(Value::String(id)) if thing(id).is_ok() => vec![id],
+        Some(Value::Array(items)) => items.iter().flat_map(|item| links(Some(item))).collect(),
+        _ => vec![]
+    }
+}
+
+
+/// Replaces the record IDs held by a field with the records they link to.
+fn embed_links(value: &mut Value, included: &Map<String, Value>) {
+    match value {
+        Value::String(id) => {
+            if let Some(record) = included.get(id.as_str()) {
+                *value = record.clone();
+            }
+        },
+        Value::Array(items) => items.iter_mut().for_each(|item| embed_links(item, included)),
+        _ => {}
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::core::make_connection;
+    use crate::operations::query::core::query;
+    use tokio::runtime::Runtime;
+
+    #[test]
+    fn test_fetch_references() {
+        let runtime = Runtime::new().unwrap();
+
+        let (embedded, sideloaded, invalid) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "CREATE user:tobie SET name = 'Tobie'; CREATE user:jaime SET name = 'Jaime';".to_string(), None).await.unwrap();
+
+            let rows = json!([
+                {"id": "post:1", "author": "user:tobie", "meta": {"editors": ["user:jaime", "user:gone"]}},
+                {"id": "post:2", "author": "user:tobie", "meta": {}},
+            ]);
+            let fields = vec!["author".to_string(), "meta.editors".to_string()];
+            let embedded = fetch_references(connection.clone(), rows.clone(), fields.clone(), true).await.unwrap();
+            let sideloaded = fetch_references(connection.clone(), rows, fields, false).await.unwrap();
+            let invalid = fetch_references(connection, json!([]), vec!["author; DELETE user".to_string()], true).await;
+            (embedded, sideloaded, invalid)
+        });
+
+        let tobie = json!({"id": "user:tobie", "name": "Tobie"});
+        let jaime = json!({"id": "user:jaime", "name": "Jaime"});
+        let embedded: Value = from_str(&embedded).unwrap();
+        assert_eq!(embedded["rows"], json!([
+            {"id": "post:1", "author": tobie, "meta": {"editors": [jaime, "user:gone"]}},
+            {"id": "post:2", "author": tobie, "meta": {}},
+        ]));
+        let sideloaded: Value = from_str(&sideloaded).unwrap();
+        assert_eq!(sideloaded["rows"][0]["author"], json!("user:tobie"));
+        assert_eq!(sideloaded["included"], json!({"user:tobie": tobie, "user:jaime": jaime}));
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_check_references() {
+        let runtime = Runtime::new().unwrap();
+
+        let (first, second, unset, deleted, remaining) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "
+                CREATE user:tobie;
+                CREATE post:1 SET author = user:tobie, editors = [user:tobie, user:gone];
+                CREATE post:2 SET author = user:jaime;
+                CREATE post:3 SET author = user:tobie;
+                CREATE post:4 SET author = <string> 'status:active', editors = [<string> 'v:1'];
+            ".to_string(), None).await.unwrap();
+            let fields = vec!["author".to_string(), "editors".to_string()];
+
+            let first: Value = from_str(&check_references(connection.clone(), "post".to_string(), fields.clone(), 2, None, None).await.unwrap()).unwrap();
+            let token = first["next"].as_str().map(|token| token.to_string());
+            let second: Value = from_str(&check_references(connection.clone(), "post".to_string(), fields.clone(), 2, token, None).await.unwrap()).unwrap();
+            let unset: Value = from_str(&check_references(connection.clone(), "post".to_string(), vec!["editors".to_string()], 10, None, Some(DanglingFix::Unset)).await.unwrap()).unwrap();
+            let deleted: Value = from_str(&check_references(connection.clone(), "post".to_string(), fields.clone(), 10, None, Some(DanglingFix::Delete)).await.unwrap()).unwrap();
+            let remaining: Value = from_str(&query(connection, "SELECT * FROM post;".to_string(), None).await.unwrap()).unwrap();
+            (first, second, unset, deleted, remaining)
+        });
+
+        assert_eq!(first["dangling"], json!([
+            {"id": "post:1", "field": "editors", "missing": ["user:gone"]},
+            {"id": "post:2", "field": "author", "missing": ["user:jaime"]},
+        ]));
+        assert_eq!(first["checked"], json!(2));
+        assert_eq!(second["dangling"], json!([]));
+        assert_eq!(second["checked"], json!(2));
+        assert_eq!(unset["dangling"], json!([{"id": "post:1", "field": "editors", "missing": ["user:gone"]}]));
+        assert_eq!(deleted["dangling"], json!([{"id": "post:2", "field": "author", "missing": ["user:jaime"]}]));
+        assert_eq!(remaining, json!([[
+            {"id": "post:1", "author": "user:tobie", "editors": ["user:tobie"]},
+            {"id": "post:3", "author": "user:tobie"},
+            {"id": "post:4", "author": "status:active", "editors": ["v:1"]},
+        ]]));
+    }
+
+    #[test]
+    fn test_dangling_fix_from_name() {
+        assert_eq!(DanglingFix::from_name("null").unwrap(), DanglingFix::Unset);
+        assert_eq!(DanglingFix::from_name("Delete").unwrap(), DanglingFix::Delete);
+        assert!(DanglingFix::from_name("ignore").is_err());
+    }
+
+    #[test]
+    fn test_orphaned_edges() {
+        let runtime = Runtime::new().unwrap();
+
+        let (found, deleted, remaining) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "
+                CREATE user:tobie, post:1;
+                RELATE user:tobie->wrote:1->post:1;
+                RELATE user:jaime->wrote:2->post:2;
+                RELATE user:tobie->wrote:3->post:2;
+            ".to_string(), None).await.unwrap();
+
+            let found: Value = from_str(&orphaned_edges(connection.clone(), "wrote".to_string(), 10, None, false).await.unwrap()).unwrap();
+            let deleted: Value = from_str(&orphaned_edges(connection.clone(), "wrote".to_string(), 10, None, true).await.unwrap()).unwrap();
+            let remaining: Value = from_str(&query(connection, "SELECT VALUE id FROM wrote;".to_string(), None).await.unwrap()).unwrap();
+            (found, deleted, remaining)
+        });
+
+        assert_eq!(found, json!({
+            "orphans": [
+                {"id": "wrote:2", "missing": ["user:jaime", "post:2"]},
+                {"id": "wrote:3", "missing": ["post:2"]},
+            ],
+            "checked": 3,
+            "next": null,
+        }));
+        assert_eq!(deleted["orphans"], found["orphans"]);
+        assert_eq!(remaining, json!([["wrote:1"]]));
+    }
+}
@@ -0,0 +1,3 @@
+//! Defines the operations for following and checking the links between records.
+pub mod core;
+pub mod python;
@@ -0,0 +1,61 @@
+//! Python entry points for working with record links.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use serde_json::value::Value;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{check_references, fetch_references, orphaned_edges, DanglingFix};
+use crate::py_future_wrapper;
+
+
+/// Fetches the records linked from fields of a set of rows in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `rows` - The rows holding the links
+/// * `fields` - The paths of the fields holding the links
+/// * `embed` - If true the linked records are embedded in the rows (defaults to true)
+/// 
+/// # Returns
+/// * `Ok(String)` - The rows and the linked records
+#[pyfunction]
+pub fn rust_fetch_references_future<'a>(py: Python<'a>, connection: WrappedConnection, rows: &'a PyAny, fields: Vec<String>, embed: Option<bool>) -> Result<&'a PyAny, PyErr> {
+    let rows: Value = serde_json::from_str(&rows.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    py_future_wrapper!(py, fetch_references(connection, rows, fields, embed.unwrap_or(true)))
+}
+
+
+/// Checks a batch of the records of a table for links to records that no longer exist in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table holding the links
+/// * `fields` - The paths of the fields holding the links
+/// * `batch_size` - The number of records checked
+/// * `token` - The token returned by the previous batch
+/// * `fix` - `null` to remove the dangling links or `delete` to delete the records holding them
+/// 
+/// # Returns
+/// * `Ok(String)` - The dangling links and the token for the next batch
+#[pyfunction]
+pub fn rust_check_references_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, fields: Vec<String>, batch_size: usize, token: Option<String>, fix: Option<String>) -> Result<&'a PyAny, PyErr> {
+    let fix = fix.as_deref().map(DanglingFix::from_name).transpose().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    py_future_wrapper!(py, check_references(connection, table, fields, batch_size, token, fix))
+}
+
+
+/// Checks a batch of the edges of a graph table for edges whose ends no longer exist in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The edge table
+/// * `batch_size` - The number of edges checked
+/// * `token` - The token returned by the previous batch
+/// * `delete` - If true the orphaned edges are deleted (defaults to false)
+/// 
+/// # Returns
+/// * `Ok(String)` - The orphaned edges, the number of edges checked and the token for the next batch
+#[pyfunction]
+pub fn rust_orphaned_edges_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, batch_size: usize, token: Option<String>, delete: Option<bool>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, orphaned_edges(connection, table, batch_size, token, delete.unwrap_or(false)))
+}
@@ -0,0 +1,364 @@
+//! Defines the core functions for suggesting a schema from a sample of records. The type of every
+//! field is inferred from the sampled values and the suggestion is returned as `DEFINE` statements
+//! to be reviewed before they are applied. In this module we can do the following:
+//! 
+//! * Infer the statements defining a table from a sample of records
+//! * Suggest a schema for a table from its stored records
+//! * Suggest a schema for a table from a file before it is imported
+//! * Describe the declared type and format of the fields of tables for display layers
+//! 
+//! # Inference
+//! * Fields missing or `null` in some records are `option<T>`
+//! * Fields with both ints and floats are `number` and fields with other mixed types are `any`
+//! * Arrays whose elements all have the same type are `array<T>`
+//! * Fields present in every record with a distinct value in each get a unique index
+use serde_json::value::Value;
+use serde_json::{json, Map};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use surrealdb::sql::{parse, Statement};
+use surrealdb::sql::statements::DefineStatement;
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::analysis::core::table_of;
+use crate::operations::ingest::core::read_file;
+use crate::operations::query::core::{execute, validate_identifier};
+
+/// The number of records sampled if no sample size is given.
+pub const DEFAULT_SAMPLE_SIZE: usize = 1000;
+
+
+/// What has been seen of a field across the sampled records.
+#[derive(Default)]
+struct FieldStats {
+    kinds: BTreeSet<&'static str>,
+    element_kinds: BTreeSet<&'static str>,
+    present: usize,
+    nulls: usize,
+    values: HashSet<String>,
+}
+
+
+/// Suggests a schema for a table from a sample of its records.
+/// 
+/// # Arguments
+/// * `connection` - The connection to sample the records with
+/// * `table` - The table to sample
+/// * `sample_size` - The maximum number of records sampled, defaults to `DEFAULT_SAMPLE_SIZE`
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - The suggested statements
+pub async fn suggest_schema(connection: WrappedConnection, table: String, sample_size: Option<usize>) -> Result<Vec<String>, String> {
+    validate_identifier(&table)?;
+    let sql = "SELECT * FROM type::table($table) LIMIT $limit;".to_string();
+    let bindings = json!({"table": table, "limit": sample_size.unwrap_or(DEFAULT_SAMPLE_SIZE)});
+    let records = match execute(connection, sql, Some(bindings)).await?.pop() {
+        Some(Value::Array(records)) => records,
+        _ => vec![]
+    };
+    Ok(infer_schema(&table, &records))
+}
+
+
+/// Suggests a schema for a table from the records of a file before it is imported.
+/// 
+/// # Arguments
+/// * `table` - The table the file will be imported into
+/// * `path` - The path of the file
+/// * `format` - The name of the format of the file
+/// * `mapping` - The field mapping that will be used for the import
+/// * `sample_size` - The maximum number of records sampled, defaults to `DEFAULT_SAMPLE_SIZE`
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - The suggested statements
+pub fn suggest_schema_from_file(table: String, path: String, format: String, mapping: Option<Value>, sample_size: Option<usize>) -> Result<Vec<String>, String> {
+    validate_identifier(&table)?;
+    let mut records = read_file(&path, &format, mapping)?;
+    records.truncate(sample_size.unwrap_or(DEFAULT_SAMPLE_SIZE));
+    Ok(infer_schema(&table, &records))
+}
+
+
+/// Describes the fields defined on tables so values can be formatted by their declared type rather
+/// than by guessing from the value. Each field has its `type`, the `format` its assertion checks for
+/// such as `email` for `string::is::email($value)`, its `default`, its `assert` and its `comment`,
+/// each `null` if the definition does not have one. Fields that are not defined are not described.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the table definitions with
+/// * `tables` - The tables to describe
+/// 
+/// # Returns
+/// * `Ok(String)` - An object of each table to an object of its field paths to their description
+pub async fn field_metadata(connection: WrappedConnection, tables: Vec<String>) -> Result<String, String> {
+    let mut metadata = Map::new();
+    for table in tables {
+        validate_identifier(&table)?;
+        let info = execute(connection.clone(), format!("INFO FOR TABLE {};", table), None).await?;
+        let fields = info.first()
+            .and_then(|info| info["fields"].as_object())
+            .map(|fields| fields.values().filter_map(Value::as_str).filter_map(describe_field).collect())
+            .unwrap_or_default();
+        metadata.insert(table, Value::Object(fields));
+    }
+    Ok(Value::Object(metadata).to_string())
+}
+
+
+/// Describes the fields of the tables a query selects from, see `field_metadata`.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the table definitions with
+/// * `sql` - The query whose select statements name the tables
+/// 
+/// # Returns
+/// * `Ok(String)` - An object of each table to an object of its field paths to their description
+pub async fn query_metadata(connection: WrappedConnection, sql: String) -> Result<String, String> {
+    let query = parse(&sql).map_err(|e| e.to_string())?;
+    let mut tables = BTreeSet::new();
+    for statement in query.0.0.iter() {
+        if let Statement::Select(select) = statement {
+            tables.extend(select.what.0.iter().filter_map(table_of));
+        }
+    }
+    field_metadata(connection, tables.into_iter().collect()).await
+}
+
+
+/// Infers the statements defining a table from a sample of records.
+/// 
+/// # Arguments
+/// * `table` - The name of the table
+/// * `records` - The sampled records
+/// 
+/// # Returns
+/// * `Vec<String>` - The `DEFINE TABLE`, `DEFINE FIELD` and `DEFINE INDEX` statements
+pub fn infer_schema(table: &str, records: &[Value]) -> Vec<String> {
+    let mut fields = BTreeMap::new();
+    for record in records {
+        if let Value::Object(record) = record {
+            for (key, value) in record {
+                if key != "id" {
+                    collect(&mut fields, escape(key), value);
+                }
+            }
+        }
+    }
+
+    let mut statements = vec![format!("DEFINE TABLE {} SCHEMAFULL;", table)];
+    let mut indexes = vec![];
+    for (path, stats) in fields.iter() {
+        statements.push(format!("DEFINE FIELD {} ON TABLE {} TYPE {};", path, table, field_type(stats, records.len())));
+        let scalar = stats.kinds.len() == 1 && (stats.kinds.contains("string") || stats.kinds.contains("int"));
+        if records.len() > 1 && scalar && stats.present == records.len() && stats.values.len() == records.len() {
+            indexes.push(format!(
+                "DEFINE INDEX {table}_{name}_unique ON TABLE {table} FIELDS {path} UNIQUE;",
+                table = table, name = path.replace(['.', '`'], "_"), path = path
+            ));
+        }
+    }
+    statements.extend(indexes);
+    statements
+}
+
+
+/// Adds a value and its nested fields to the stats of the fields.
+fn collect(fields: &mut BTreeMap<String, FieldStats>, path: String, value: &Value) {
+    let stats = fields.entry(path.clone()).or_default();
+    stats.present += 1;
+    match kind(value) {
+        Some(kind) => { stats.kinds.insert(kind); },
+        None => stats.nulls += 1
+    }
+    match value {
+        Value::String(_) | Value::Number(_) => { stats.values.insert(value.to_string()); },
+        Value::Array(elements) => stats.element_kinds.extend(elements.iter().filter_map(kind)),
+        Value::Object(object) => {
+            for (key, value) in object {
+                collect(fields, format!("{}.{}", path, escape(key)), value);
+            }
+        },
+        _ => {}
+    }
+}
+
+
+/// Gets the SurrealQL type of a field.
+fn field_type(stats: &FieldStats, records: usize) -> String {
+    let base = match merge_kinds(&stats.kinds) {
+        Some("array") => match merge_kinds(&stats.element_kinds) {
+            Some(element) if element != "any" => format!("array<{}>", element),
+            _ => "array".to_string()
+        },
+        Some(kind) => kind.to_string(),
+        None => "any".to_string()
+    };
+    if base != "any" && (stats.nulls > 0 || stats.present < records) {
+        return format!("option<{}>", base)
+    }
+    base
+}
+
+
+/// Merges the kinds seen for a field into a single type, `None` if no kind has been seen.
+fn merge_kinds(kinds: &BTreeSet<&'static str>) -> Option<&'static str> {
+    match kinds.len() {
+        0 => None,
+        1 => kinds.iter().next().copied(),
+        2 if kinds.contains("int") && kinds.contains("float") => Some("number"),
+        _ => Some("any")
+    }
+}
+
+
+/// Gets the kind of a value, `None` for `null`.
+fn kind(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::Null => None,
+        Value::Bool(_) => Some("bool"),
+        Value::Number(number) if number.is_f64() => Some("float"),
+        Value::Number(_) => Some("int"),
+        Value::String(_) => Some("string"),
+        Value::Array(_) => Some("array"),
+        Value::Object(_) => Some("object"),
+    }
+}
+
+
+/// Describes a field from its `DEFINE FIELD` statement, `None` if the statement can not be read.
+fn describe_field(definition: &str) -> Option<(String, Value)> {
+    let field = match parse(definition).ok()?.0.0.pop()? {
+        Statement::Define(DefineStatement::Field(field)) => field,
+        _ => return None
+    };
+    let assert = field.assert.as_ref().map(|assert| assert.to_string());
+    let format = assert.as_deref().and_then(format_of);
+    Some((field.name.to_string(), json!({
+        "type": field.kind.as_ref().map(|kind| kind.to_string()),
+        "format": format,
+        "default": field.default.as_ref().map(|default| default.to_string()),
+        "assert": assert,
+        "comment": field.comment.as_ref().map(|comment| comment.0.clone())
+    })))
+}
+
+
+/// Gets the format an assertion checks for, such as `email` for `string::is::email($value)`.
+fn format_of(assert: &str) -> Option<String> {
+    let start = assert.find("string::is::")? + "string::is::".len();
+    let format: String = assert[start..].chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+    Some(format).filter(|format| !format.is_empty())
+}
+
+
+/// Escapes a key that is not a plain identifier with backticks.
+fn escape(key: &str) -> String {
+    match validate_identifier(key) {
+        Ok(_) => key.to_string(),
+        Err(_) => format!("`{}`", key.replace('`', "\\`"))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::operations::query::core::query;
+    use crate::connection::core::make_connection;
+    use tokio::runtime::Runtime;
+
+
+    #[test]
+    fn test_infer_schema() {
+        let records = vec![
+            json!({"id": "user:1", "email": "a@b.c", "age": 1, "score": 1, "tags": ["a"], "name": {"first": "Tobie"}, "extra": "x", "first name": true}),
+            json!({"id": "user:2", "email": "b@b.c", "age": null, "score": 1.5, "tags": [], "name": {"first": "Jaime"}, "extra": 1, "first name": false}),
+        ];
+        assert_eq!(infer_schema("user", &records), vec![
+            "DEFINE TABLE user SCHEMAFULL;",
+            "DEFINE FIELD `first name` ON TABLE user TYPE bool;",
+            "DEFINE FIELD age ON TABLE user TYPE option<int>;",
+            "DEFINE FIELD email ON TABLE user TYPE string;",
+            "DEFINE FIELD extra ON TABLE user TYPE any;",
+            "DEFINE FIELD name ON TABLE user TYPE object;",
+            "DEFINE FIELD name.first ON TABLE user TYPE string;",
+            "DEFINE FIELD score ON TABLE user TYPE number;",
+            "DEFINE FIELD tags ON TABLE user TYPE array<string>;",
+            "DEFINE INDEX user_email_unique ON TABLE user FIELDS email UNIQUE;",
+            "DEFINE INDEX user_name_first_unique ON TABLE user FIELDS name.first UNIQUE;",
+        ]);
+    }
+
+    #[test]
+    fn test_suggest_schema() {
+        let runtime = Runtime::new().unwrap();
+
+        let statements = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "CREATE user:1 SET age = 1; CREATE user:2 SET age = 1, nickname = 'T';".to_string(), None).await.unwrap();
+            let statements = suggest_schema(connection.clone(), "user".to_string(), None).await.unwrap();
+
+            // the suggestion can be applied as it is
+            query(connection.clone(), statements.join(" "), None).await.unwrap();
+            statements
+        });
+
+        assert_eq!(statements, vec![
+            "DEFINE TABLE user SCHEMAFULL;",
+            "DEFINE FIELD age ON TABLE user TYPE int;",
+            "DEFINE FIELD nickname ON TABLE user TYPE option<string>;",
+        ]);
+    }
+
+    #[test]
+    fn test_suggest_schema_from_file() {
+        let path = std::env::temp_dir().join(format!("schema-{}.csv", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "id,age\n1,32\n2,32\n").unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let statements = suggest_schema_from_file(
+            "user".to_string(), path.clone(), "csv".to_string(), Some(json!({"id": "id", "age": {"type": "int"}})), Some(1)
+        ).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(statements, vec![
+            "DEFINE TABLE user SCHEMAFULL;",
+            "DEFINE FIELD age ON TABLE user TYPE int;",
+        ]);
+    }
+
+
+    #[test]
+    fn test_field_metadata() {
+        let runtime = Runtime::new().unwrap();
+
+        let (metadata, selected) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            query(connection.clone(), "
+                DEFINE TABLE order SCHEMAFULL;
+                DEFINE FIELD total ON TABLE order TYPE decimal DEFAULT 0dec COMMENT 'currency:EUR';
+                DEFINE FIELD email ON TABLE order TYPE string ASSERT string::is::email($value);
+                DEFINE FIELD placed ON TABLE order TYPE option<datetime>;
+                DEFINE FIELD lines ON TABLE order FLEXIBLE TYPE array<object>;
+            ".to_string(), None).await.unwrap();
+            let metadata = field_metadata(connection.clone(), vec!["order".to_string(), "user".to_string()]).await.unwrap();
+            let selected = query_metadata(connection, "SELECT * FROM order:1, user; CREATE post;".to_string()).await.unwrap();
+            (metadata, selected)
+        });
+        let metadata: Value = serde_json::from_str(&metadata).unwrap();
+        assert_eq!(metadata["order"]["total"], json!({
+            "type": "decimal", "format": null, "default": "0dec", "assert": null, "comment": "currency:EUR"
+        }));
+        assert_eq!(metadata["order"]["email"]["format"], "email");
+        assert_eq!(metadata["order"]["placed"]["type"], "option<datetime>");
+        assert_eq!(metadata["order"]["lines"]["type"], "array<object>");
+        assert_eq!(metadata["user"], json!({}));
+        assert_eq!(serde_json::from_str::<Value>(&selected).unwrap(), metadata);
+    }
+
+}
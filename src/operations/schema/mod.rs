@@ -0,0 +1,3 @@
+//! Defines the operations for suggesting a schema from sampled records.
+pub mod core;
+pub mod python;
@@ -0,0 +1,72 @@
+//! Python entry points for suggesting a schema from sampled records.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use serde_json::value::Value;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{field_metadata, query_metadata, suggest_schema, suggest_schema_from_file};
+use crate::py_future_wrapper;
+
+
+/// Suggests a schema for a table from a sample of its records in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to sample
+/// * `sample_size` - The maximum number of records sampled
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - The suggested statements
+#[pyfunction]
+pub fn rust_suggest_schema_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, sample_size: Option<usize>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, suggest_schema(connection, table, sample_size))
+}
+
+
+/// Suggests a schema for a table from the records of a file.
+/// 
+/// # Arguments
+/// * `table` - The table the file will be imported into
+/// * `path` - The path of the file
+/// * `format` - The format of the file, `ndjson` or `csv`
+/// * `mapping` - The JSON of the field mapping that will be used for the import
+/// * `sample_size` - The maximum number of records sampled
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - The suggested statements
+#[pyfunction]
+pub fn rust_suggest_schema_from_file(table: String, path: String, format: String, mapping: Option<String>, sample_size: Option<usize>) -> Result<Vec<String>, PyErr> {
+    let mapping: Option<Value> = match mapping {
+        Some(mapping) => Some(serde_json::from_str(&mapping).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?),
+        None => None
+    };
+    suggest_schema_from_file(table, path, format, mapping, sample_size).map_err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>)
+}
+
+
+/// Describes the declared type and format of the fields of tables in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `tables` - The tables to describe
+/// 
+/// # Returns
+/// * `Ok(String)` - The description of the fields of each table
+#[pyfunction]
+pub fn rust_field_metadata_future<'a>(py: Python<'a>, connection: WrappedConnection, tables: Vec<String>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, field_metadata(connection, tables))
+}
+
+
+/// Describes the fields of the tables a query selects from in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `sql` - The query whose select statements name the tables
+/// 
+/// # Returns
+/// * `Ok(String)` - The description of the fields of each table
+#[pyfunction]
+pub fn rust_query_metadata_future<'a>(py: Python<'a>, connection: WrappedConnection, sql: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, query_metadata(connection, sql))
+}
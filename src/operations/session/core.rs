@@ -0,0 +1,197 @@
+//! Defines the core functions for a session store. Each session is a record holding the session data,
+//! its time to live in seconds and the time it expires at. Expired sessions are never returned or
+//! extended and are deleted when they are accessed or purged. In this module we can do the following:
+//! 
+//! * Create a session with a time to live
+//! * Get the data of a session, optionally extending its expiry
+//! * Touch a session to extend its expiry
+//! * Delete a session
+//! * Purge all expired sessions of a table
+use serde_json::value::Value;
+use serde_json::json;
+use surrealdb::sql::thing;
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::execute;
+
+
+/// Creates a session that expires after the time to live.
+/// 
+/// # Arguments
+/// * `connection` - The connection to create the session with
+/// * `table` - The table to store the session in
+/// * `data` - The data of the session
+/// * `ttl` - The number of seconds the session lives for without being touched
+/// 
+/// # Returns
+/// * `Ok(String)` - The ID of the session
+pub async fn create_session(connection: WrappedConnection, table: String, data: Value, ttl: u64) -> Result<String, String> {
+    let sql = "CREATE type::table($table) SET data = $data, ttl = $ttl, expires_at = time::now() + duration::from::secs($ttl) RETURN VALUE id;";
+    let bindings = json!({"table": table, "data": data, "ttl": ttl});
+    let outcome = execute(connection, sql.to_string(), Some(bindings)).await?;
+    match single(outcome) {
+        Value::String(id) => Ok(id),
+        other => Err(format!("unexpected session ID: {}", other))
+    }
+}
+
+
+/// Gets the data of a session that has not expired. Deleting the session if it expired and reading
+/// or extending it run in one transaction, so a session cannot expire or be deleted in between.
+/// 
+/// # Arguments
+/// * `connection` - The connection to get the session with
+/// * `session_id` - The ID of the session
+/// * `sliding` - Whether the expiry of the session should be extended by its time to live
+/// 
+/// # Returns
+/// * `Ok(String)` - The data of the session or `null` if the session does not exist or has expired
+pub async fn get_session(connection: WrappedConnection, session_id: String, sliding: bool) -> Result<String, String> {
+    let record = thing(&session_id).map_err(|e| e.to_string())?;
+    let read = match sliding {
+        true => format!("UPDATE (SELECT VALUE id FROM {} WHERE expires_at > time::now()) SET expires_at = time::now() + duration::from::secs(ttl) RETURN VALUE data;", record),
+        false => format!("SELECT VALUE data FROM {} WHERE expires_at > time::now();", record)
+    };
+    let sql = format!(
+        "BEGIN TRANSACTION; DELETE {record} WHERE expires_at <= time::now(); {read} COMMIT TRANSACTION;",
+        record = record,
+        read = read
+    );
+    let outcome = execute(connection, sql, None).await?;
+    Ok(single(outcome).to_string())
+}
+
+
+/// Extends the expiry of a session that has not expired by its time to live.
+/// 
+/// # Arguments
+/// * `connection` - The connection to touch the session with
+/// * `session_id` - The ID of the session
+/// 
+/// # Returns
+/// * `Ok(bool)` - True if the session exists and was extended
+pub async fn touch_session(connection: WrappedConnection, session_id: String) -> Result<bool, String> {
+    let record = thing(&session_id).map_err(|e| e.to_string())?;
+    let sql = format!(
+        "UPDATE (SELECT VALUE id FROM {record} WHERE expires_at > time::now()) SET expires_at = time::now() + duration::from::secs(ttl) RETURN VALUE id;",
+        record = record
+    );
+    let outcome = execute(connection, sql, None).await?;
+    Ok(!single(outcome).is_null())
+}
+
+
+/// Deletes a session.
+/// 
+/// # Arguments
+/// * `connection` - The connection to delete the session with
+/// * `session_id` - The ID of the session
+/// 
+/// # Returns
+/// * `Ok(())` - The session no longer exists
+pub async fn delete_session(connection: WrappedConnection, session_id: String) -> Result<(), String> {
+    let record = thing(&session_id).map_err(|e| e.to_string())?;
+    execute(connection, format!("DELETE {};", record), None).await?;
+    Ok(())
+}
+
+
+/// Deletes all the expired sessions of a table.
+/// 
+/// # Arguments
+/// * `connection` - The connection to purge the sessions with
+/// * `table` - The table the sessions are stored in
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of sessions that were deleted
+pub async fn purge_sessions(connection: WrappedConnection, table: String) -> Result<usize, String> {
+    let sql = "DELETE type::table($table) WHERE expires_at <= time::now() RETURN BEFORE;";
+    let mut outcome = execute(connection, sql.to_string(), Some(json!({"table": table}))).await?;
+    match outcome.pop() {
+        Some(Value::Array(deleted)) => Ok(deleted.len()),
+        _ => Ok(0)
+    }
+}
+
+
+/// Takes the first value returned by the last statement.
+/// 
+/// # Arguments
+/// * `outcome` - The results of the statements
+/// 
+/// # Returns
+/// * `Value` - The first value of the last statement or `null` if there is none
+fn single(mut outcome: Vec<Value>) -> Value {
+    match outcome.pop() {
+        Some(Value::Array(mut values)) if !values.is_empty() => values.swap_remove(0),
+        Some(Value::Array(_)) | None => Value::Null,
+        Some(other) => other
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::operations::query::core::query;
+    use crate::connection::core::make_connection;
+    use tokio::runtime::Runtime;
+    use serde_json::from_str;
+
+
+    #[test]
+    fn test_session_lifecycle() {
+        let runtime = Runtime::new().unwrap();
+
+        let (data, touched, deleted, extended, stored) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            let id = create_session(connection.clone(), "session".to_string(), json!({"user": "tobie"}), 60).await.unwrap();
+            let data = get_session(connection.clone(), id.clone(), true).await.unwrap();
+            let touched = touch_session(connection.clone(), id.clone()).await.unwrap();
+            delete_session(connection.clone(), id.clone()).await.unwrap();
+            let deleted = get_session(connection.clone(), id.clone(), false).await.unwrap();
+            let extended = get_session(connection.clone(), id, true).await.unwrap();
+            let stored = query(connection, "SELECT * FROM session;".to_string(), None).await.unwrap();
+            (data, touched, deleted, extended, stored)
+        });
+
+        let data: Value = from_str(&data).unwrap();
+        assert_eq!(data, json!({"user": "tobie"}));
+        assert!(touched);
+        assert_eq!(deleted, "null");
+        assert_eq!(extended, "null");
+        assert_eq!(stored, "[[]]");
+    }
+
+    #[test]
+    fn test_expired_session() {
+        let runtime = Runtime::new().unwrap();
+
+        let (data, touched, purged, remaining) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            let id = create_session(connection.clone(), "session".to_string(), json!({"user": "tobie"}), 0).await.unwrap();
+            create_session(connection.clone(), "session".to_string(), json!({"user": "jaime"}), 0).await.unwrap();
+            create_session(connection.clone(), "session".to_string(), json!({"user": "dave"}), 60).await.unwrap();
+
+            let data = get_session(connection.clone(), id.clone(), true).await.unwrap();
+            let touched = touch_session(connection.clone(), id).await.unwrap();
+            let purged = purge_sessions(connection.clone(), "session".to_string()).await.unwrap();
+            let remaining = query(connection, "SELECT VALUE data.user FROM session;".to_string(), None).await.unwrap();
+            (data, touched, purged, remaining)
+        });
+
+        assert_eq!(data, "null");
+        assert!(!touched);
+        assert_eq!(purged, 1);
+        let remaining: Value = from_str(&remaining).unwrap();
+        assert_eq!(remaining, json!([["dave"]]));
+    }
+
+}
@@ -0,0 +1,3 @@
+//! Defines the operations for storing web framework sessions with a time to live in the database.
+pub mod core;
+pub mod python;
@@ -0,0 +1,88 @@
+//! Python entry points for the session store.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use serde_json::value::Value;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{
+    create_session,
+    get_session,
+    touch_session,
+    delete_session,
+    purge_sessions
+};
+use crate::py_future_wrapper;
+
+
+/// Creates a session that expires after the time to live in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to store the session in
+/// * `data` - The data of the session
+/// * `ttl` - The number of seconds the session lives for without being touched
+/// 
+/// # Returns
+/// * `Ok(String)` - The ID of the session
+#[pyfunction]
+pub fn rust_create_session_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, data: &'a PyAny, ttl: u64) -> Result<&'a PyAny, PyErr> {
+    let data: Value = serde_json::from_str(&data.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    py_future_wrapper!(py, create_session(connection, table, data, ttl))
+}
+
+
+/// Gets the data of a session that has not expired in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `session_id` - The ID of the session
+/// * `sliding` - Whether the expiry of the session should be extended by its time to live
+/// 
+/// # Returns
+/// * `Ok(String)` - The data of the session or `null` if it does not exist or has expired
+#[pyfunction]
+pub fn rust_get_session_future<'a>(py: Python<'a>, connection: WrappedConnection, session_id: String, sliding: bool) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, get_session(connection, session_id, sliding))
+}
+
+
+/// Extends the expiry of a session in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `session_id` - The ID of the session
+/// 
+/// # Returns
+/// * `Ok(bool)` - True if the session exists and was extended
+#[pyfunction]
+pub fn rust_touch_session_future<'a>(py: Python<'a>, connection: WrappedConnection, session_id: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, touch_session(connection, session_id))
+}
+
+
+/// Deletes a session in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `session_id` - The ID of the session
+/// 
+/// # Returns
+/// * `Ok(())` - The session no longer exists
+#[pyfunction]
+pub fn rust_delete_session_future<'a>(py: Python<'a>, connection: WrappedConnection, session_id: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, delete_session(connection, session_id))
+}
+
+
+/// Deletes all the expired sessions of a table in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table the sessions are stored in
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of sessions that were deleted
+#[pyfunction]
+pub fn rust_purge_sessions_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, purge_sessions(connection, table))
+}
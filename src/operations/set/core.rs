@@ -31,3 +31,52 @@ pub async fn unset(connection: WrappedConnection, key: String) -> Result<(), Str
     connection.connection.unset(key).await.map_err(|e| e.to_string())?;
     Ok(())
 }
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::operations::query::core::query;
+    use crate::connection::core::make_connection;
+    use tokio::runtime::Runtime;
+    use serde_json::{from_str, json};
+
+
+    #[test]
+    fn test_set_persists_between_queries() {
+        let runtime = Runtime::new().unwrap();
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            set(connection.clone(), "name".to_string(), json!({"first": "Tobie"})).await.unwrap();
+            query(connection.clone(), "CREATE user:1 SET name = $name;".to_string(), None).await.unwrap();
+            query(connection, "RETURN $name.first;".to_string(), None).await.unwrap()
+        });
+
+        let outcome: Value = from_str(&outcome).unwrap();
+        assert_eq!(outcome, json!(["Tobie"]));
+    }
+
+    #[test]
+    fn test_unset() {
+        let runtime = Runtime::new().unwrap();
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            set(connection.clone(), "name".to_string(), json!("Tobie")).await.unwrap();
+            unset(connection.clone(), "name".to_string()).await.unwrap();
+            query(connection, "RETURN $name;".to_string(), None).await.unwrap()
+        });
+
+        let outcome: Value = from_str(&outcome).unwrap();
+        assert_eq!(outcome, json!([null]));
+    }
+
+}
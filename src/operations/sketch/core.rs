@@ -0,0 +1,331 @@
+//! Defines the core functions for approximating the distinct count and the percentiles of a field with
+//! streaming sketches. The table is read a page at a time in record ID order and only the field is
+//! selected, so neither the database nor the client has to hold the whole table at once. Distinct
+//! counts use a HyperLogLog with a standard error of about 0.8% and percentiles use a t-digest, which
+//! is most accurate towards the tails. In this module we can do the following:
+//! 
+//! * Approximate the number of distinct values of a field
+//! * Approximate the percentiles of a numeric field
+use serde_json::value::Value;
+use serde_json::{json, Map};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::Bound;
+use surrealdb::sql::{thing, Range};
+
+use crate::connection::interface::WrappedConnection;
+use crate::operations::query::core::{execute, validate_field_path, validate_identifier};
+
+/// The number of records read per page if no page size is given.
+pub const DEFAULT_PAGE_SIZE: usize = 1000;
+
+/// The compression of the t-digest if none is given, higher is more accurate and uses more memory.
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+
+/// The number of bits of the hash that pick the register of the HyperLogLog.
+const PRECISION: u32 = 14;
+
+/// The number of values the t-digest buffers before merging them into its centroids.
+const BUFFER_SIZE: usize = 500;
+
+
+/// Counts distinct values approximately in a fixed amount of memory.
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Creates an empty sketch.
+    pub fn new() -> Self {
+        HyperLogLog { registers: vec![0; 1 << PRECISION] }
+    }
+
+    /// Adds a value to the sketch.
+    /// 
+    /// # Arguments
+    /// * `value` - The value to add
+    pub fn add(&mut self, value: &Value) {
+        let mut hasher = DefaultHasher::new();
+        value.to_string().hash(&mut hasher);
+        let hash = hasher.finish();
+        let index = (hash >> (64 - PRECISION)) as usize;
+        let rank = ((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    /// Estimates the number of distinct values added.
+    /// 
+    /// # Returns
+    /// * `u64` - The estimate
+    pub fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let sum: f64 = self.registers.iter().map(|register| 2f64.powi(-(*register as i32))).sum();
+        let estimate = 0.7213 / (1.0 + 1.079 / m) * m * m / sum;
+        let zeros = self.registers.iter().filter(|register| **register == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            return (m * (m / zeros as f64).ln()).round() as u64
+        }
+        estimate.round() as u64
+    }
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// Approximates the distribution of numbers with a merging t-digest.
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<(f64, f64)>,
+    buffer: Vec<f64>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Creates an empty digest.
+    /// 
+    /// # Arguments
+    /// * `compression` - The compression of the digest, about the number of centroids it keeps
+    pub fn new(compression: f64) -> Self {
+        TDigest { compression, centroids: vec![], buffer: vec![], count: 0.0, min: f64::INFINITY, max: f64::NEG_INFINITY }
+    }
+
+    /// Adds a number to the digest.
+    /// 
+    /// # Arguments
+    /// * `value` - The number to add
+    pub fn add(&mut self, value: f64) {
+        self.buffer.push(value);
+        self.count += 1.0;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        if self.buffer.len() >= BUFFER_SIZE {
+            self.merge();
+        }
+    }
+
+    /// Merges the buffered numbers into the centroids. Neighbouring centroids are combined while the
+    /// combined weight stays under a limit that shrinks towards the tails of the distribution.
+    fn merge(&mut self) {
+        let mut points: Vec<(f64, f64)> = self.buffer.drain(..).map(|value| (value, 1.0)).collect();
+        points.append(&mut self.centroids);
+        points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        let mut merged: Vec<(f64, f64)> = Vec::with_capacity(points.len());
+        let mut before = 0.0;
+        for (mean, weight) in points {
+            if let Some(last) = merged.last_mut() {
+                let combined = last.1 + weight;
+                let quantile = (before + combined / 2.0) / self.count;
+                if combined <= 4.0 * self.count * quantile * (1.0 - quantile) / self.compression {
+                    last.0 += (mean - last.0) * weight / combined;
+                    last.1 = combined;
+                    continue
+                }
+                before += last.1;
+            }
+            merged.push((mean, weight));
+        }
+        self.centroids = merged;
+    }
+
+    /// Estimates the value at a quantile by interpolating between the centres of the centroids.
+    /// 
+    /// # Arguments
+    /// * `quantile` - The quantile between 0 and 1
+    /// 
+    /// # Returns
+    /// * `Option<f64>` - The estimate, `None` if no numbers were added
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        self.merge();
+        if self.centroids.is_empty() {
+            return None
+        }
+        let target = quantile * self.count;
+        let mut previous = (self.min, 0.0);
+        let mut seen = 0.0;
+        for (mean, weight) in self.centroids.iter() {
+            let centre = seen + weight / 2.0;
+            if target < centre {
+                let span = centre - previous.1;
+                let fraction = if span > 0.0 { (target - previous.1) / span } else { 0.0 };
+                return Some(previous.0 + (mean - previous.0) * fraction)
+            }
+            previous = (*mean, centre);
+            seen += weight;
+        }
+        let span = self.count - previous.1;
+        let fraction = if span > 0.0 { (target - previous.1) / span } else { 1.0 };
+        Some(previous.0 + (self.max - previous.0) * fraction)
+    }
+}
+
+
+/// Approximates the number of distinct values of a field of a table, `null` and missing values are
+/// not counted.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the table with
+/// * `table` - The table to read
+/// * `field` - The dotted path of the field
+/// * `page_size` - The number of records read per page, defaults to `DEFAULT_PAGE_SIZE`
+/// 
+/// # Returns
+/// * `Ok(String)` - An object with the `count` of values read and the approximate number of `distinct` values
+pub async fn approx_distinct(connection: WrappedConnection, table: String, field: String, page_size: Option<usize>) -> Result<String, String> {
+    let mut sketch = HyperLogLog::new();
+    let mut count = 0;
+    stream_field(connection, table, field, page_size, |value| {
+        sketch.add(value);
+        count += 1;
+    }).await?;
+    Ok(json!({"count": count, "distinct": sketch.estimate()}).to_string())
+}
+
+
+/// Approximates percentiles of a numeric field of a table, values that are not numbers are skipped.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the table with
+/// * `table` - The table to read
+/// * `field` - The dotted path of the field
+/// * `percentiles` - The percentiles to estimate as fractions such as `0.5` and `0.99`
+/// * `page_size` - The number of records read per page, defaults to `DEFAULT_PAGE_SIZE`
+/// * `compression` - The compression of the t-digest, defaults to `DEFAULT_COMPRESSION`
+/// 
+/// # Returns
+/// * `Ok(String)` - An object with the `count` of numbers read, their exact `min` and `max` and the
+///   estimated `percentiles` keyed by the requested fraction, the values are `null` if there were no numbers
+pub async fn approx_percentiles(connection: WrappedConnection, table: String, field: String, percentiles: Vec<f64>, page_size: Option<usize>, compression: Option<f64>) -> Result<String, String> {
+    if let Some(invalid) = percentiles.iter().find(|percentile| !(0.0..=1.0).contains(*percentile)) {
+        return Err(format!("percentile {} is not between 0 and 1", invalid))
+    }
+    let compression = compression.unwrap_or(DEFAULT_COMPRESSION);
+    if compression <= 0.0 {
+        return Err("compression must be greater than zero".to_string())
+    }
+    let mut digest = TDigest::new(compression);
+    stream_field(connection, table, field, page_size, |value| {
+        if let Some(number) = value.as_f64() {
+            digest.add(number);
+        }
+    }).await?;
+
+    let mut estimates = Map::new();
+    for percentile in percentiles {
+        estimates.insert(percentile.to_string(), json!(digest.quantile(percentile)));
+    }
+    let seen = digest.count > 0.0;
+    Ok(json!({
+        "count": digest.count as u64,
+        "min": if seen { Some(digest.min) } else { None },
+        "max": if seen { Some(digest.max) } else { None },
+        "percentiles": estimates,
+    }).to_string())
+}
+
+
+/// Reads the values of a field of every record of a table a page at a time in record ID order.
+/// 
+/// # Arguments
+/// * `connection` - The connection to read the table with
+/// * `table` - The table to read
+/// * `field` - The dotted path of the field
+/// * `page_size` - The number of records read per page, defaults to `DEFAULT_PAGE_SIZE`
+/// * `visit` - Called with every value that is not `null`
+/// 
+/// # Returns
+/// * `Ok(())` - The whole table was read
+async fn stream_field<F: FnMut(&Value)>(connection: WrappedConnection, table: String, field: String, page_size: Option<usize>, mut visit: F) -> Result<(), String> {
+    validate_identifier(&table)?;
+    validate_field_path(&field)?;
+    let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    if page_size == 0 {
+        return Err("page size must be greater than zero".to_string())
+    }
+    let mut source = table.clone();
+    loop {
+        let sql = format!("SELECT id, {} AS value FROM {} LIMIT $limit;", field, source);
+        let rows = match execute(connection.clone(), sql, Some(json!({"limit": page_size}))).await?.pop() {
+            Some(Value::Array(rows)) => rows,
+            _ => vec![]
+        };
+        for row in rows.iter() {
+            if !row["value"].is_null() {
+                visit(&row["value"]);
+            }
+        }
+        let last = match rows.last() {
+            Some(last) if rows.len() == page_size => last["id"].as_str().ok_or_else(|| "record without an ID".to_string())?,
+            _ => return Ok(())
+        };
+        let last = thing(last).map_err(|e| e.to_string())?;
+        source = Range::new(table.clone(), Bound::Excluded(last.id), Bound::Unbounded).to_string();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::connection::core::make_connection;
+    use crate::operations::query::core::query;
+    use serde_json::from_str;
+    use tokio::runtime::Runtime;
+
+    #[test]
+    fn test_hyper_log_log() {
+        let mut sketch = HyperLogLog::new();
+        assert_eq!(sketch.estimate(), 0);
+        for value in 0..50000 {
+            sketch.add(&json!(value % 20000));
+        }
+        let estimate = sketch.estimate() as f64;
+        assert!((estimate - 20000.0).abs() / 20000.0 < 0.03, "estimate {}", estimate);
+    }
+
+    #[test]
+    fn test_t_digest() {
+        let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+        assert_eq!(digest.quantile(0.5), None);
+        for value in (1..=10000).rev() {
+            digest.add(value as f64);
+        }
+        for (quantile, expected) in [(0.5, 5000.0), (0.9, 9000.0), (0.99, 9900.0)].iter() {
+            let estimate = digest.quantile(*quantile).unwrap();
+            assert!((estimate - expected).abs() / expected < 0.01, "{} gave {}", quantile, estimate);
+        }
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(1.0), Some(10000.0));
+    }
+
+    #[test]
+    fn test_approx_distinct_and_percentiles() {
+        let runtime = Runtime::new().unwrap();
+
+        let (distinct, percentiles, empty, invalid) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+            let mut sql: String = (1..=10)
+                .map(|i| format!("CREATE order:{} SET total = {}, customer = {{ name: 'c{}' }};", i, i * 10, i % 3))
+                .collect();
+            sql.push_str("CREATE order:11 SET total = 'n/a';");
+            query(connection.clone(), sql, None).await.unwrap();
+            let distinct = approx_distinct(connection.clone(), "order".to_string(), "customer.name".to_string(), Some(3)).await.unwrap();
+            let percentiles = approx_percentiles(connection.clone(), "order".to_string(), "total".to_string(), vec![0.5], Some(4), None).await.unwrap();
+            let empty = approx_percentiles(connection.clone(), "order".to_string(), "missing".to_string(), vec![0.5], None, None).await.unwrap();
+            let invalid = approx_percentiles(connection, "order".to_string(), "total".to_string(), vec![50.0], None, None).await;
+            (distinct, percentiles, empty, invalid)
+        });
+        assert_eq!(from_str::<Value>(&distinct).unwrap(), json!({"count": 10, "distinct": 3}));
+        assert_eq!(from_str::<Value>(&percentiles).unwrap(), json!({"count": 10, "min": 10.0, "max": 100.0, "percentiles": {"0.5": 55.0}}));
+        assert_eq!(from_str::<Value>(&empty).unwrap(), json!({"count": 0, "min": null, "max": null, "percentiles": {"0.5": null}}));
+        assert!(invalid.is_err());
+    }
+}
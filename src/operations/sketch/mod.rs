@@ -0,0 +1,3 @@
+//! Defines the operations for approximating statistics of tables too big to compute exactly.
+pub mod core;
+pub mod python;
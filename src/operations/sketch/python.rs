@@ -0,0 +1,41 @@
+//! Python entry points for approximating statistics of tables too big to compute exactly.
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+
+use crate::connection::interface::WrappedConnection;
+use super::core::{approx_distinct, approx_percentiles};
+use crate::py_future_wrapper;
+
+
+/// Approximates the number of distinct values of a field in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to read
+/// * `field` - The dotted path of the field
+/// * `page_size` - The number of records read per page
+/// 
+/// # Returns
+/// * `Ok(String)` - The number of values read and the approximate number of distinct values
+#[pyfunction]
+pub fn rust_approx_distinct_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, field: String, page_size: Option<usize>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, approx_distinct(connection, table, field, page_size))
+}
+
+
+/// Approximates percentiles of a numeric field in an non-async manner.
+/// 
+/// # Arguments
+/// * `connection` - The database connection being used for the operation
+/// * `table` - The table to read
+/// * `field` - The dotted path of the field
+/// * `percentiles` - The percentiles to estimate as fractions
+/// * `page_size` - The number of records read per page
+/// * `compression` - The compression of the t-digest
+/// 
+/// # Returns
+/// * `Ok(String)` - The number of numbers read, their minimum and maximum and the estimated percentiles
+#[pyfunction]
+pub fn rust_approx_percentiles_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, field: String, percentiles: Vec<f64>, page_size: Option<usize>, compression: Option<f64>) -> Result<&'a PyAny, PyErr> {
+    py_future_wrapper!(py, approx_percentiles(connection, table, field, percentiles, page_size, compression))
+}
@@ -4,10 +4,45 @@ use surrealdb::sql::Range;
 use surrealdb::opt::Resource;
 use surrealdb::opt::PatchOp;
 use crate::connection::interface::WrappedConnection;
-use serde::Deserialize;
-use std::collections::VecDeque;
-use serde_json::from_str;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map};
+use surrealdb::sql::thing;
 use std::fmt;
+use crate::operations::query::core::{execute, validate_identifier};
+use crate::operations::history::core::diff;
+use surrealdb::sql::Value as SurrealValue;
+
+/// The number of records updated per statement by `update_where` if no batch size is given.
+pub const DEFAULT_UPDATE_BATCH_SIZE: usize = 1000;
+
+/// The number of times `merge_three_way` reads the record again if it changes while being merged.
+const MERGE_ATTEMPTS: usize = 5;
+
+/// How `merge_three_way` resolves a field that was changed differently by both sides.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConflictPolicy {
+    Ours,
+    Theirs,
+    Fail,
+}
+
+impl ConflictPolicy {
+    /// Gets the policy from its name.
+    /// 
+    /// # Arguments
+    /// * `name` - The name of the policy, `ours`, `theirs` or `fail`
+    /// 
+    /// # Returns
+    /// * `Ok(ConflictPolicy)` - The policy
+    pub fn from_name(name: &str) -> Result<ConflictPolicy, String> {
+        match name.to_lowercase().as_str() {
+            "ours" => Ok(ConflictPolicy::Ours),
+            "theirs" => Ok(ConflictPolicy::Theirs),
+            "fail" => Ok(ConflictPolicy::Fail),
+            other => Err(format!("unknown conflict policy: {}", other))
+        }
+    }
+}
 
 #[derive(Clone, PartialEq)]
 pub struct Diff {
@@ -73,8 +108,9 @@ pub async fn merge(connection: WrappedConnection, resource: String, data: Value)
 /// 
 /// # Arguments
 /// * `connection` - The connection to perform the patch with
-/// * `resource` - The resource to patch (can be a table or a range)
-/// * `data` - The data to patch the resource with
+/// * `resource` - The resource to patch (can be a table, a record or a range)
+/// * `data` - The RFC 6902 patch operations to apply to the resource
+/// * `return_diff` - If true the diff of each patched record is returned instead of the record
 /// 
 /// # Data Examples
 /// For instance, if you wanted to update the last name of the user for all users in the `users` table,
@@ -88,58 +124,229 @@ pub async fn merge(connection: WrappedConnection, resource: String, data: Value)
 /// ```
 /// # Returns
 /// an array of the results of the patch for each row that was updated with the patch operation.
-pub async fn patch(connection: WrappedConnection, resource: String, data: Value) -> Result<String, String> {
-    let patch = match resource.parse::<Range>() {
+pub async fn patch(connection: WrappedConnection, resource: String, data: Value, return_diff: bool) -> Result<String, String> {
+    let patches = validate_patches(data)?;
+
+    // the client builder only supports add, remove and replace so anything else goes through a statement
+    let operations: Option<Vec<PatchOp>> = patches.iter().cloned().map(Patch::into_patch_op).collect();
+    let operations = match operations {
+        Some(operations) if !return_diff => operations,
+        _ => return patch_statement(connection, resource, patches, return_diff).await
+    };
+
+    let update = match resource.parse::<Range>() {
         Ok(range) => connection.connection.update(Resource::from(range.tb)).range((range.beg, range.end)),
         Err(_) => connection.connection.update(Resource::from(resource))
     };
-    let data_str = serde_json::to_string(&data).map_err(|e| e.to_string())?;
-
-    let mut patches: VecDeque<Patch> = from_str(&data_str).map_err(|e| e.to_string())?;
-    let mut patch = match patches.pop_front() {
-        Some(p) => patch.patch(match p {
-            Patch::Add {
-                path,
-                value,
-            } => PatchOp::add(&path, value),
-            Patch::Remove {
-                path,
-            } => PatchOp::remove(&path),
-            Patch::Replace {
-                path,
-                value,
-            } => PatchOp::replace(&path, value),
-            // Patch::Change {
-            //     path,
-            //     diff,
-            // } => PatchOp::change(&path, diff),
-        }),
-        None => {
-            let response = patch.await.map_err(|e| e.to_string())?;
-            return Ok(response.into_json().to_string())
+    let mut operations = operations.into_iter();
+    let response = match operations.next() {
+        Some(first) => {
+            let mut patch = update.patch(first);
+            for operation in operations {
+                patch = patch.patch(operation);
+            }
+            patch.await
+        },
+        None => update.await
+    }.map_err(|e| e.to_string())?;
+    Ok(response.into_json().to_string())
+}
+
+
+/// Updates all the records in a table that match a condition in batches on the server.
+/// 
+/// # Arguments
+/// * `connection` - The connection to perform the update with
+/// * `table` - The table to update
+/// * `condition` - The SurrealQL condition that the records have to match
+/// * `data` - An object is merged into the records, a list is applied as patch operations
+/// * `batch_size` - The maximum number of records updated per statement, defaults to `DEFAULT_UPDATE_BATCH_SIZE`
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of records that were updated
+pub async fn update_where(connection: WrappedConnection, table: String, condition: String, data: Value, batch_size: Option<usize>) -> Result<usize, String> {
+    validate_identifier(&table)?;
+    let batch_size = batch_size.unwrap_or(DEFAULT_UPDATE_BATCH_SIZE);
+    if batch_size == 0 {
+        return Err("batch size must be greater than zero".to_string())
+    }
+    let (operation, data) = match data {
+        Value::Object(_) => ("MERGE", data),
+        Value::Array(_) => ("PATCH", serde_json::to_value(validate_patches(data)?).map_err(|e| e.to_string())?),
+        _ => return Err("update data must be an object to merge or a list of patch operations".to_string())
+    };
+
+    // records are walked in id order so updated records that still match are not picked up twice
+    let sql = format!(
+        "LET $ids = (SELECT VALUE id FROM {table} WHERE ({condition}) AND ($last = NONE OR id > <record> $last) ORDER BY id LIMIT {batch_size});
+        UPDATE $ids {operation} $data RETURN NONE;
+        RETURN {{ count: array::len($ids), last: array::last($ids) }};"
+    );
+    let mut last = Value::Null;
+    let mut total = 0;
+    loop {
+        let bindings = json!({"data": data, "last": last});
+        let outcome = execute(connection.clone(), sql.clone(), Some(bindings)).await?
+            .pop()
+            .unwrap_or(Value::Null);
+        let count = outcome["count"].as_u64().unwrap_or(0) as usize;
+        total += count;
+        if count < batch_size {
+            return Ok(total)
         }
+        last = outcome["last"].clone();
+    }
+}
+
+
+/// Applies the patches with an `UPDATE ... PATCH` statement.
+/// 
+/// # Arguments
+/// * `connection` - The connection to perform the patch with
+/// * `resource` - The resource to patch (can be a table, a record or a range)
+/// * `patches` - The validated patch operations
+/// * `return_diff` - If true the diff of each patched record is returned instead of the record
+/// 
+/// # Returns
+/// * `Ok(String)` - The patched records (or diffs), a single record returns a single item
+async fn patch_statement(connection: WrappedConnection, resource: String, patches: Vec<Patch>, return_diff: bool) -> Result<String, String> {
+    let (target, single) = if let Ok(range) = resource.parse::<Range>() {
+        (range.to_string(), false)
+    } else if let Ok(record) = thing(&resource) {
+        (record.to_string(), true)
+    } else {
+        validate_identifier(&resource)?;
+        (resource, false)
     };
-    for p in patches {
-        patch = patch.patch(match p {
-            Patch::Add {
-                path,
-                value,
-            } => PatchOp::add(&path, value),
-            Patch::Remove {
-                path,
-            } => PatchOp::remove(&path),
-            Patch::Replace {
-                path,
-                value,
-            } => PatchOp::replace(&path, value),
-            // Patch::Change {
-            //     path,
-            //     diff,
-            // } => PatchOp::change(&path, diff),
-        });
+    let output = if return_diff { "DIFF" } else { "AFTER" };
+    let sql = format!("UPDATE {} PATCH $patches RETURN {};", target, output);
+    let bindings = json!({"patches": serde_json::to_value(&patches).map_err(|e| e.to_string())?});
+
+    let outcome = execute(connection, sql, Some(bindings)).await?
+        .pop()
+        .unwrap_or(Value::Null);
+    let outcome = match (single, outcome) {
+        (true, Value::Array(mut rows)) if rows.len() == 1 => rows.remove(0),
+        (_, outcome) => outcome
+    };
+    Ok(outcome.to_string())
+}
+
+
+/// Merges an edit of a record with the changes other clients made since the edit started. Fields are
+/// compared between the `base` the edit started from, the current state of the record and `ours`, so
+/// changes made by only one side are kept and fields changed differently by both sides are resolved
+/// with the conflict policy. The merged record is only written if the record has not changed again
+/// since it was read, otherwise the merge is retried against the new state.
+/// 
+/// # Arguments
+/// * `connection` - The connection to perform the merge with
+/// * `resource` - The ID of the record to merge into
+/// * `base` - The record as it was when the edit started
+/// * `ours` - The record with the edit applied
+/// * `policy` - How to resolve fields changed by both sides
+/// 
+/// # Returns
+/// * `Ok(String)` - An object with the merged `record` and the JSON pointers of the `conflicts`
+pub async fn merge_three_way(connection: WrappedConnection, resource: String, base: Value, ours: Value, policy: ConflictPolicy) -> Result<String, String> {
+    let id = thing(&resource).map_err(|_| format!("invalid record ID: {}", resource))?;
+    if !base.is_object() || !ours.is_object() {
+        return Err("base and ours must be objects".to_string())
     }
-    let response = patch.await.map_err(|e| e.to_string())?;
-    Ok(response.into_json().to_string())
+    for _ in 0..MERGE_ATTEMPTS {
+        let mut response = connection.connection.query("SELECT * FROM ONLY $id;")
+            .bind(("id", id.clone()))
+            .await.map_err(|e| e.to_string())?;
+        let current: SurrealValue = response.take(0).map_err(|e| e.to_string())?;
+        if current.is_none_or_null() {
+            return Err(format!("record {} does not exist", resource))
+        }
+
+        let mut conflicts = vec![];
+        let theirs = current.clone().into_json();
+        let merged = merge_values(Some(&without_id(&base)), Some(&without_id(&theirs)), Some(&without_id(&ours)), "", policy, &mut conflicts);
+        if policy == ConflictPolicy::Fail && !conflicts.is_empty() {
+            return Err(format!("merge conflicts at {}", conflicts.join(", ")))
+        }
+
+        // only the fields that differ from theirs are patched so the untouched fields keep their
+        // types, such as record links and datetimes, that do not survive the trip through JSON
+        let patches = diff(&without_id(&theirs), &merged.unwrap_or(json!({})));
+
+        // the record is only updated if it still matches the state the merge was worked out from
+        let mut response = connection.connection.query("UPDATE $id PATCH $patches WHERE $this = $current RETURN AFTER;")
+            .bind(("id", id.clone()))
+            .bind(("patches", Value::Array(patches)))
+            .bind(("current", current))
+            .await.map_err(|e| e.to_string())?;
+        let updated: SurrealValue = response.take(0).map_err(|e| e.to_string())?;
+        if let Some(record) = updated.into_json().as_array().and_then(|records| records.first()) {
+            return Ok(json!({"record": record, "conflicts": conflicts}).to_string())
+        }
+    }
+    Err(format!("record {} kept changing while it was being merged", resource))
+}
+
+
+/// Merges the values of a field changed by two sides since a common base, `None` meaning the field is absent.
+fn merge_values(base: Option<&Value>, theirs: Option<&Value>, ours: Option<&Value>, path: &str, policy: ConflictPolicy, conflicts: &mut Vec<String>) -> Option<Value> {
+    if ours == base || theirs == ours {
+        return theirs.cloned()
+    }
+    if theirs == base {
+        return ours.cloned()
+    }
+    if let (Some(Value::Object(theirs)), Some(Value::Object(ours))) = (theirs, ours) {
+        let empty = Map::new();
+        let base = base.and_then(|base| base.as_object()).unwrap_or(&empty);
+        let mut merged = Map::new();
+        let keys = theirs.keys().chain(ours.keys().filter(|key| !theirs.contains_key(*key)));
+        for key in keys {
+            let pointer = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+            if let Some(value) = merge_values(base.get(key), theirs.get(key), ours.get(key), &pointer, policy, conflicts) {
+                merged.insert(key.clone(), value);
+            }
+        }
+        return Some(Value::Object(merged))
+    }
+    conflicts.push(path.to_string());
+    match policy {
+        ConflictPolicy::Ours => ours.cloned(),
+        _ => theirs.cloned()
+    }
+}
+
+
+/// Copies a record without its ID so it can be compared and written as content.
+fn without_id(record: &Value) -> Value {
+    let mut record = record.clone();
+    if let Some(fields) = record.as_object_mut() {
+        fields.remove("id");
+    }
+    record
+}
+
+
+/// Checks that the data is a list of valid RFC 6902 patch operations before anything is sent.
+/// 
+/// # Arguments
+/// * `data` - The patch operations to validate
+/// 
+/// # Returns
+/// * `Ok(Vec<Patch>)` - The parsed patch operations
+pub fn validate_patches(data: Value) -> Result<Vec<Patch>, String> {
+    if !data.is_array() {
+        return Err("patch data must be a list of patch operations".to_string())
+    }
+    let patches: Vec<Patch> = serde_json::from_value(data).map_err(|e| format!("invalid patch operation: {}", e))?;
+    for patch in patches.iter() {
+        for pointer in patch.pointers() {
+            if !pointer.is_empty() && !pointer.starts_with('/') {
+                return Err(format!("invalid patch path '{}': paths must be JSON pointers starting with '/'", pointer))
+            }
+        }
+    }
+    Ok(patches)
 }
 
 
@@ -150,7 +357,7 @@ struct DiffDef {
 	text: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, Serialize)]
 #[serde(tag = "op")]
 #[serde(rename_all = "lowercase")]
 pub enum Patch {
@@ -165,6 +372,18 @@ pub enum Patch {
 		path: String,
 		value: Value,
 	},
+	Copy {
+		from: String,
+		path: String,
+	},
+	Move {
+		from: String,
+		path: String,
+	},
+	Test {
+		path: String,
+		value: Value,
+	},
 	// Change {
 	// 	path: String,
 	// 	// #[serde(with = "DiffDef")]
@@ -172,6 +391,26 @@ pub enum Patch {
 	// },
 }
 
+impl Patch {
+	/// The JSON pointers that the operation refers to.
+	fn pointers(&self) -> Vec<&str> {
+		match self {
+			Patch::Add { path, .. } | Patch::Remove { path } | Patch::Replace { path, .. } | Patch::Test { path, .. } => vec![path],
+			Patch::Copy { from, path } | Patch::Move { from, path } => vec![from, path],
+		}
+	}
+
+	/// Converts the operation for the client patch builder, `None` if the builder does not support it.
+	fn into_patch_op(self) -> Option<PatchOp> {
+		match self {
+			Patch::Add { path, value } => Some(PatchOp::add(&path, value)),
+			Patch::Remove { path } => Some(PatchOp::remove(&path)),
+			Patch::Replace { path, value } => Some(PatchOp::replace(&path, value)),
+			_ => None,
+		}
+	}
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -180,7 +419,7 @@ mod tests {
     use crate::operations::query::core::query;
     use crate::connection::core::make_connection;
 	use tokio::runtime::Runtime;
-    use serde_json::{from_str, Value};
+    use serde_json::{from_str, json, Value};
 
 
     async fn prime_database(connection: WrappedConnection) {
@@ -371,7 +610,7 @@ mod tests {
 			connection.connection.use_db("test_database").await.unwrap();
 
             prime_merge_database(connection.clone()).await;
-            let outcome = patch(connection.clone(), "user".to_string(), json_value).await.unwrap();
+            let outcome = patch(connection.clone(), "user".to_string(), json_value, false).await.unwrap();
             println!("{:?}", outcome);
             query(connection.clone(), "SELECT * FROM user;".to_string(), None).await.unwrap()
         });
@@ -382,4 +621,156 @@ mod tests {
         }
     }
 
+
+    #[test]
+    fn test_patch_return_diff() {
+        let json_value: Value = from_str(r#"[{"op": "replace", "path": "/age", "value": 5}]"#).unwrap();
+        let runtime = Runtime::new().unwrap();
+
+        let (diff, records) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+
+            prime_merge_database(connection.clone()).await;
+            let diff = patch(connection.clone(), "user:1".to_string(), json_value, true).await.unwrap();
+            let records = query(connection.clone(), "SELECT * FROM user:1;".to_string(), None).await.unwrap();
+            (diff, records)
+        });
+
+        let diff: Value = from_str(&diff).unwrap();
+        assert_eq!(diff, from_str::<Value>(r#"[{"op": "replace", "path": "/age", "value": 5}]"#).unwrap());
+        let records: Value = from_str(&records).unwrap();
+        assert_eq!(records[0][0]["age"], 5);
+    }
+
+    #[test]
+    fn test_patch_copy_move_and_test() {
+        let json_value: Value = from_str(r#"[
+            {"op": "test", "path": "/age", "value": 2},
+            {"op": "copy", "from": "/name/first", "path": "/nickname"},
+            {"op": "move", "from": "/name/last", "path": "/surname"}
+        ]"#).unwrap();
+        let runtime = Runtime::new().unwrap();
+
+        let outcome = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+
+            prime_merge_database(connection.clone()).await;
+            let outcome = patch(connection.clone(), "user:3".to_string(), json_value.clone(), false).await.unwrap();
+            let failed = patch(connection.clone(), "user:1".to_string(), json_value, false).await;
+            assert!(failed.is_err());
+            outcome
+        });
+
+        let outcome: Value = from_str(&outcome).unwrap();
+        assert_eq!(outcome["nickname"], "Dave".to_string());
+        assert_eq!(outcome["surname"], "three".to_string());
+        assert_eq!(outcome["name"].get("last"), None);
+    }
+
+    #[test]
+    fn test_validate_patches() {
+        assert!(validate_patches(from_str(r#"[{"op": "remove", "path": "/name"}]"#).unwrap()).is_ok());
+        assert!(validate_patches(from_str(r#"{"op": "remove", "path": "/name"}"#).unwrap()).is_err());
+        assert!(validate_patches(from_str(r#"[{"op": "delete", "path": "/name"}]"#).unwrap()).is_err());
+        assert!(validate_patches(from_str(r#"[{"op": "remove", "path": "name"}]"#).unwrap()).is_err());
+        assert!(validate_patches(from_str(r#"[{"op": "move", "from": "name", "path": "/other"}]"#).unwrap()).is_err());
+    }
+
+
+    #[test]
+    fn test_update_where() {
+        let runtime = Runtime::new().unwrap();
+
+        let (merged, patched, records) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+
+            prime_merge_database(connection.clone()).await;
+            let merged = update_where(
+                connection.clone(), "user".to_string(), "age = 2".to_string(), generate_merge_json(), Some(1)
+            ).await.unwrap();
+            let patches: Value = from_str(r#"[{"op": "replace", "path": "/name/first", "value": "Young"}]"#).unwrap();
+            let patched = update_where(
+                connection.clone(), "user".to_string(), "age < 2".to_string(), patches, None
+            ).await.unwrap();
+            let records = query(connection.clone(), "SELECT * FROM user ORDER BY id;".to_string(), None).await.unwrap();
+            (merged, patched, records)
+        });
+
+        assert_eq!(merged, 2);
+        assert_eq!(patched, 2);
+        let records: Value = from_str(&records).unwrap();
+        let records = records[0].as_array().unwrap();
+        assert_eq!(records[0]["name"]["first"], "Young".to_string());
+        assert_eq!(records[1]["name"]["first"], "Young".to_string());
+        assert_eq!(records[1]["name"]["last"], "two".to_string());
+        assert_eq!(records[2]["name"]["last"], "Doe".to_string());
+        assert_eq!(records[3]["name"]["last"], "Doe".to_string());
+        assert_eq!(records[3]["name"]["first"], "Tom".to_string());
+    }
+
+    #[test]
+    fn test_update_where_invalid_data() {
+        let runtime = Runtime::new().unwrap();
+
+        runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+			connection.connection.use_ns("test_namespace").await.unwrap();
+			connection.connection.use_db("test_database").await.unwrap();
+
+            let json_value = generate_merge_json();
+            assert!(update_where(connection.clone(), "user".to_string(), "true".to_string(), Value::from(1), None).await.is_err());
+            assert!(update_where(connection.clone(), "user;".to_string(), "true".to_string(), json_value.clone(), None).await.is_err());
+            assert!(update_where(connection.clone(), "user".to_string(), "true".to_string(), json_value, Some(0)).await.is_err());
+        });
+    }
+
+
+    #[test]
+    fn test_merge_three_way() {
+        let runtime = Runtime::new().unwrap();
+
+        let (merged, ours, failed, missing, types) = runtime.block_on(async {
+            let connection = make_connection("memory".to_string()).await.unwrap();
+            connection.connection.use_ns("test_namespace").await.unwrap();
+            connection.connection.use_db("test_database").await.unwrap();
+
+            let base = json!({"id": "user:tobie", "name": "Tobie", "age": 30, "address": {"city": "London", "street": "A"}, "tags": ["a"]});
+            query(connection.clone(), "CREATE user:tobie CONTENT $base;".to_string(), Some(json!({"base": without_id(&base)}))).await.unwrap();
+            query(connection.clone(), "UPDATE user:tobie SET age = 31, address.city = 'Paris', tags = ['b'];".to_string(), None).await.unwrap();
+
+            let ours = json!({"name": "Tobias", "age": 30, "address": {"city": "London", "street": "B"}, "tags": ["c"], "email": "t@x.com"});
+            let merged = merge_three_way(connection.clone(), "user:tobie".to_string(), base.clone(), ours.clone(), ConflictPolicy::Theirs).await.unwrap();
+            let ours_wins = merge_three_way(connection.clone(), "user:tobie".to_string(), json!({"age": 31}), json!({"age": 40}), ConflictPolicy::Ours).await.unwrap();
+            let failed = merge_three_way(connection.clone(), "user:tobie".to_string(), json!({"age": 31}), json!({"age": 50}), ConflictPolicy::Fail).await;
+            query(connection.clone(), "UPDATE user:tobie SET author = user:jaime, joined = <datetime> '2024-01-02T03:04:05Z';".to_string(), None).await.unwrap();
+            merge_three_way(connection.clone(), "user:tobie".to_string(), json!({"age": 40}), json!({"age": 41}), ConflictPolicy::Ours).await.unwrap();
+            let types: Value = from_str(&query(connection.clone(), "SELECT type::is::record(author) AS author, type::is::datetime(joined) AS joined, age FROM ONLY user:tobie;".to_string(), None).await.unwrap()).unwrap();
+            let missing = merge_three_way(connection, "user:jaime".to_string(), json!({}), json!({"age": 1}), ConflictPolicy::Ours).await;
+            (merged, ours_wins, failed, missing, types)
+        });
+
+        let merged: Value = from_str(&merged).unwrap();
+        assert_eq!(merged["record"], json!({
+            "id": "user:tobie", "name": "Tobias", "age": 31, "address": {"city": "Paris", "street": "B"}, "tags": ["b"], "email": "t@x.com"
+        }));
+        assert_eq!(merged["conflicts"], json!(["/tags"]));
+        let ours: Value = from_str(&ours).unwrap();
+        assert_eq!(ours["record"]["age"], json!(40));
+        assert!(failed.unwrap_err().contains("/age"));
+        assert!(missing.unwrap_err().contains("does not exist"));
+        assert_eq!(types[0], json!({"author": true, "joined": true, "age": 41}));
+    }
+
+    #[test]
+    fn test_conflict_policy_from_name() {
+        assert_eq!(ConflictPolicy::from_name("Ours").unwrap(), ConflictPolicy::Ours);
+        assert_eq!(ConflictPolicy::from_name("fail").unwrap(), ConflictPolicy::Fail);
+        assert!(ConflictPolicy::from_name("newest").is_err());
+    }
 }
@@ -7,7 +7,10 @@ use crate::connection::interface::WrappedConnection;
 use super::core::{
     update,
     merge,
-    patch
+    patch,
+    update_where,
+    merge_three_way,
+    ConflictPolicy
 };
 use crate::py_future_wrapper;
 
@@ -54,11 +57,50 @@ pub fn rust_merge_future<'a>(py: Python<'a>, connection: WrappedConnection, reso
 /// * `connection` - The connection to be used for the patch
 /// * `resource` - The resource to be patched
 /// * `data` - The data to be used for the patch
+/// * `return_diff` - If true the diff of each patched record is returned (defaults to false)
 /// 
 /// # Returns
 /// * `Ok(String)` - The outcome of the patch operation
 #[pyfunction]
-pub fn rust_patch_future<'a>(py: Python<'a>, connection: WrappedConnection, resource: String, data: &'a PyAny) -> Result<&'a PyAny, PyErr> {
+pub fn rust_patch_future<'a>(py: Python<'a>, connection: WrappedConnection, resource: String, data: &'a PyAny, return_diff: Option<bool>) -> Result<&'a PyAny, PyErr> {
     let data: Value = serde_json::from_str(&data.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-    py_future_wrapper!(py, patch(connection, resource, data))
+    py_future_wrapper!(py, patch(connection, resource, data, return_diff.unwrap_or(false)))
+}
+
+
+/// Updates all the records in a table that match a condition in a blocking manner.
+/// 
+/// # Arguments
+/// * `connection` - The connection to be used for the update
+/// * `table` - The table to be updated
+/// * `condition` - The SurrealQL condition that the records have to match
+/// * `data` - An object to merge into the records or a list of patch operations
+/// * `batch_size` - The maximum number of records updated per statement
+/// 
+/// # Returns
+/// * `Ok(usize)` - The number of records that were updated
+#[pyfunction]
+pub fn rust_update_where_future<'a>(py: Python<'a>, connection: WrappedConnection, table: String, condition: String, data: &'a PyAny, batch_size: Option<usize>) -> Result<&'a PyAny, PyErr> {
+    let data: Value = serde_json::from_str(&data.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    py_future_wrapper!(py, update_where(connection, table, condition, data, batch_size))
+}
+
+
+/// Merges an edit of a record with the changes made since the edit started in a blocking manner.
+/// 
+/// # Arguments
+/// * `connection` - The connection to be used for the merge
+/// * `resource` - The ID of the record to merge into
+/// * `base` - The record as it was when the edit started
+/// * `ours` - The record with the edit applied
+/// * `policy` - How to resolve fields changed by both sides, `ours`, `theirs` or `fail` (defaults to `fail`)
+/// 
+/// # Returns
+/// * `Ok(String)` - The merged record and the paths of the conflicts
+#[pyfunction]
+pub fn rust_merge_three_way_future<'a>(py: Python<'a>, connection: WrappedConnection, resource: String, base: &'a PyAny, ours: &'a PyAny, policy: Option<String>) -> Result<&'a PyAny, PyErr> {
+    let base: Value = serde_json::from_str(&base.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    let ours: Value = serde_json::from_str(&ours.to_string()).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    let policy = ConflictPolicy::from_name(policy.as_deref().unwrap_or("fail")).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    py_future_wrapper!(py, merge_three_way(connection, resource, base, ours, policy))
 }
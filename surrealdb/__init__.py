@@ -1,4 +1,5 @@
 from surrealdb.async_connection_interface import AsyncSurrealDB
 from surrealdb.connection_interface import SurrealDB
+from surrealdb.diagnostics import blocking_diagnostics, diagnostics
 
-__all__ = ("SurrealDB", "AsyncSurrealDB")
+__all__ = ("SurrealDB", "AsyncSurrealDB", "blocking_diagnostics", "diagnostics")
@@ -18,15 +18,34 @@ connection = SurrealDB(
 ```
 """
 
+import atexit
 import uuid
 from typing import Optional
 
 from surrealdb.async_execution_mixins.auth import AsyncSignInMixin
 
 # import the mixins for operations for the connection
+from surrealdb.async_execution_mixins.analysis import AsyncAnalysisMixin
+from surrealdb.async_execution_mixins.blob import AsyncBlobMixin
+from surrealdb.async_execution_mixins.constraint import AsyncConstraintMixin
+from surrealdb.async_execution_mixins.counter import AsyncCounterMixin
 from surrealdb.async_execution_mixins.create import AsyncCreateMixin
+from surrealdb.async_execution_mixins.encryption import AsyncEncryptionMixin
+from surrealdb.async_execution_mixins.error_history import AsyncErrorHistoryMixin
+from surrealdb.async_execution_mixins.function import AsyncFunctionMixin
+from surrealdb.async_execution_mixins.guard import AsyncGuardMixin
+from surrealdb.async_execution_mixins.history import AsyncHistoryMixin
+from surrealdb.async_execution_mixins.ingest import AsyncIngestMixin
+from surrealdb.async_execution_mixins.limit import AsyncLimitMixin
+from surrealdb.async_execution_mixins.pagination import AsyncPaginationMixin
 from surrealdb.async_execution_mixins.query import AsyncQueryMixin
+from surrealdb.async_execution_mixins.quota import AsyncQuotaMixin
+from surrealdb.async_execution_mixins.reference import AsyncReferenceMixin
+from surrealdb.async_execution_mixins.schema import AsyncSchemaMixin
+from surrealdb.async_execution_mixins.session import AsyncSessionMixin
 from surrealdb.async_execution_mixins.set import AsyncSetMixin
+from surrealdb.async_execution_mixins.sketch import AsyncSketchMixin
+from surrealdb.async_execution_mixins.units import AsyncUnitMixin
 from surrealdb.async_execution_mixins.update import AsyncUpdateMixin
 from surrealdb.rust_surrealdb import (
     rust_make_connection_future,
@@ -49,6 +68,17 @@ class ConnectionController(type):
         """
         del cls.instances[connection_id]
 
+    def close_all(cls) -> None:
+        """
+        Closes all the connections that are being kept by the controller including the main connection.
+
+        :return: None
+        """
+        for instance in list(cls.instances.values()):
+            instance.close()
+        if cls.main_connection is not None:
+            cls.main_connection.close()
+
     def __call__(cls, *args, **kwargs):  # noqa: D102
         # establish the main connection
         if kwargs.get("main_connection", False) is True:
@@ -87,6 +117,24 @@ class AsyncSurrealDB(
     AsyncSetMixin,
     AsyncQueryMixin,
     AsyncUpdateMixin,
+    AsyncFunctionMixin,
+    AsyncCounterMixin,
+    AsyncSessionMixin,
+    AsyncConstraintMixin,
+    AsyncEncryptionMixin,
+    AsyncBlobMixin,
+    AsyncIngestMixin,
+    AsyncSchemaMixin,
+    AsyncPaginationMixin,
+    AsyncHistoryMixin,
+    AsyncReferenceMixin,
+    AsyncAnalysisMixin,
+    AsyncQuotaMixin,
+    AsyncLimitMixin,
+    AsyncGuardMixin,
+    AsyncUnitMixin,
+    AsyncSketchMixin,
+    AsyncErrorHistoryMixin,
     metaclass=ConnectionController,
 ):
     """This class is responsible for managing the async connection to SurrealDB and managing operations on the connection."""
@@ -147,3 +195,27 @@ class AsyncSurrealDB(
         :return: None
         """
         await rust_use_database_future(self._connection, database)
+
+    def close(self) -> None:
+        """
+        Closes the connection and removes it from the connection controller if it is being kept.
+
+        :return: None
+        """
+        self._connection = None
+        if self.id in type(self).instances:
+            type(self).remove_connection(self.id)
+        if type(self).main_connection is self:
+            type(self).main_connection = None
+
+    async def __aenter__(self) -> "AsyncSurrealDB":
+        """Connects to SurrealDB when entering the context manager."""
+        await self.connect()
+        return self
+
+    async def __aexit__(self, exc_type, exc_value, traceback) -> None:
+        """Closes the connection when exiting the context manager."""
+        self.close()
+
+
+atexit.register(AsyncSurrealDB.close_all)
@@ -0,0 +1,138 @@
+"""This file defines the interface between python and the Rust SurrealDB library for analysing the database."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, List, Optional
+
+from surrealdb.rust_surrealdb import (
+    rust_profile_query_future,
+    rust_profile_table_future,
+    rust_sample_table_future,
+    rust_storage_report_future,
+    rust_usage_report_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class AsyncAnalysisMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for analysis."""
+
+    async def usage_report(self: SurrealDB, queries: List[str]) -> dict:
+        """
+        Reports the tables and indexes that a log of queries never used so unused parts of the
+        schema can be pruned. Selects in the log are planned with EXPLAIN to find the indexes
+        they use, no other statement is sent to the database. Selects whose sources, condition,
+        limit or start hold a subquery or a function call are not planned, as planning would
+        run them.
+
+        :param queries: the queries run against the database over the period being analysed
+        :return: a dict with the "unused_tables", the "unused_indexes" as "table.index" and the
+            number of "skipped" queries that could not be parsed or were not planned
+        """
+        try:
+            return json.loads(
+                await rust_usage_report_future(self._connection, queries)
+            )
+        except Exception as e:
+            raise self._record_error("usage_report", e) from None
+
+    async def storage_report(
+        self: SurrealDB, sample_size: Optional[int] = None
+    ) -> List[dict]:
+        """
+        Estimates the number of records and the storage size of every table for capacity
+        reports. The size of a table is estimated from the average serialized size of a
+        sample of its records, so it is exact for tables no bigger than the sample.
+
+        :param sample_size: the number of records sampled per table, 1000 if None
+        :return: a dict for each table with the "table", the number of "records", the number
+            "sampled", the "average_bytes" of a record, the "estimated_bytes" of the table and
+            if the estimate is "exact"
+        """
+        try:
+            return json.loads(
+                await rust_storage_report_future(self._connection, sample_size)
+            )
+        except Exception as e:
+            raise self._record_error("storage_report", e) from None
+
+    async def profile(
+        self: SurrealDB,
+        name: str,
+        sample_size: Optional[int] = None,
+        top: Optional[int] = None,
+    ) -> dict:
+        """
+        Computes statistics for every field of a sample of the records of a table for quick
+        exploration without loading the table into Python. Nested fields are profiled by their
+        dotted path such as "address.city".
+
+        :param name: the name of the table to profile
+        :param sample_size: the maximum number of records sampled, 1000 if None
+        :param top: the number of most common values reported per field, 5 if None
+        :return: a dict with the number of rows "sampled" and the statistics of the "fields",
+            each with its "count", "nulls", "null_rate", "distinct", "min", "max" and "top"
+            values with their "count"
+        """
+        try:
+            return json.loads(
+                await rust_profile_table_future(
+                    self._connection, name, sample_size, top
+                )
+            )
+        except Exception as e:
+            raise self._record_error("profile", e) from None
+
+    async def profile_query(
+        self: SurrealDB,
+        query: str,
+        bindings: Optional[dict] = None,
+        sample_size: Optional[int] = None,
+        top: Optional[int] = None,
+    ) -> dict:
+        """
+        Computes statistics for every field of the rows of a query, see profile. Selects
+        without a LIMIT are limited to the sample size.
+
+        :param query: the query whose rows are profiled
+        :param bindings: the variables to bind to the query
+        :param sample_size: the maximum number of rows profiled, 1000 if None
+        :param top: the number of most common values reported per field, 5 if None
+        :return: a dict with the number of rows "sampled" and the statistics of the "fields"
+        """
+        try:
+            return json.loads(
+                await rust_profile_query_future(
+                    self._connection,
+                    query,
+                    None if bindings is None else json.dumps(bindings),
+                    sample_size,
+                    top,
+                )
+            )
+        except Exception as e:
+            raise self._record_error("profile_query", e) from None
+
+    async def sample(
+        self: SurrealDB, name: str, size: int, method: str = "random"
+    ) -> List[dict]:
+        """
+        Samples the records of a table so notebooks can work on a representative subset. Only
+        the record IDs are read to pick the sample, the rest of the table never leaves the
+        database.
+
+        :param name: the name of the table to sample
+        :param size: the number of records in the sample, the whole table if it is not bigger
+        :param method: "random" gives every record the same chance, "systematic" picks every
+            k-th record in ID order from a random start
+        :return: the sampled records in ID order
+        """
+        try:
+            return json.loads(
+                await rust_sample_table_future(self._connection, name, size, method)
+            )
+        except Exception as e:
+            raise self._record_error("sample", e) from None
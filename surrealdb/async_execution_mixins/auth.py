@@ -2,11 +2,14 @@
 
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Dict, Optional
+import json
+from typing import TYPE_CHECKING, Any, Dict, Optional
 
 from surrealdb.errors import SurrealDbError
 from surrealdb.rust_surrealdb import (
+    rust_auth_info_future,
     rust_authenticate_future,
+    rust_invalidate_future,
     rust_sign_in_future,
     rust_sign_up_future,
 )
@@ -18,14 +21,14 @@ if TYPE_CHECKING:
 class AsyncSignInMixin:
     """This class is responsible for the interface between python and the Rust SurrealDB library for logging in."""
 
-    async def signin(self: SurrealDB, data: Optional[Dict[str, str]] = None) -> None:
+    async def signin(self: SurrealDB, data: Optional[Dict[str, str]] = None) -> str:
         """
         Signs in to the database.
 
         :param password: the password to sign in with
         :param username: the username to sign in with
 
-        :return: None
+        :return: the JWT for the session
         """
         if data is None:
             data = {}
@@ -33,13 +36,18 @@ class AsyncSignInMixin:
 
         password: str = data.get("password", data.get("pass", data.get("p", "root")))
         username: str = data.get("username", data.get("user", data.get("u", "root")))
-        await rust_sign_in_future(self._connection, password, username)
+        try:
+            return await rust_sign_in_future(self._connection, username, password)
+        except Exception as e:
+            self._record("signin", e)
+            raise
 
     async def signup(
         self: SurrealDB,
         namespace: str,
         database: str,
         data: Optional[Dict[str, str]] = None,
+        scope: Optional[str] = None,
     ) -> str:
         """
         Signs up to an auth scope within a namespace and database.
@@ -47,13 +55,29 @@ class AsyncSignInMixin:
         :param namespace: the namespace the auth scope is associated with
         :param database: the database the auth scope is associated with
         :param data: the data to sign up with
+        :param scope: the auth scope to sign up to, taken from the "scope" or "sc" key of the data if not provided
         :return: an JWT for that auth scope
         """
-        return await rust_sign_up_future(self._connection, data, namespace, database)
+        if data is None:
+            data = {}
+        if scope is None:
+            scope = data.get("scope", data.get("sc"))
+        if scope is None:
+            raise self._record_error(
+                "signup", SurrealDbError("a scope is required to sign up")
+            )
+        try:
+            return await rust_sign_up_future(
+                self._connection, json.dumps(data), namespace, database, scope
+            )
+        except Exception as e:
+            self._record("signup", e)
+            raise
 
-    async def authenticate(self: SurrealDB, jwt: str) -> bool:
+    async def authenticate(self: SurrealDB, jwt: str) -> None:
         """
-        Authenticates a JWT.
+        Authenticates a JWT. SurrealDB 1.x has no refresh tokens, to keep a session alive sign in
+        again or authenticate with a new JWT before the current one expires.
 
         :param jwt: the JWT to authenticate
         :return: None
@@ -61,4 +85,26 @@ class AsyncSignInMixin:
         try:
             return await rust_authenticate_future(self._connection, jwt)
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("authenticate", e) from None
+
+    async def invalidate(self: SurrealDB) -> None:
+        """
+        Invalidates the authentication of the current session.
+
+        :return: None
+        """
+        try:
+            await rust_invalidate_future(self._connection)
+        except Exception as e:
+            raise self._record_error("invalidate", e) from None
+
+    async def auth_info(self: SurrealDB) -> Dict[str, Any]:
+        """
+        Gets the authentication information of the current session.
+
+        :return: the authenticated record, scope, token claims and session of the connection
+        """
+        try:
+            return json.loads(await rust_auth_info_future(self._connection))
+        except Exception as e:
+            raise self._record_error("auth_info", e) from None
@@ -0,0 +1,89 @@
+"""This file defines the interface between python and the Rust SurrealDB library for storing blobs by their content hash."""
+
+from __future__ import annotations
+
+import base64
+from typing import TYPE_CHECKING, Optional
+
+from surrealdb.rust_surrealdb import (
+    rust_gc_blobs_future,
+    rust_get_blob_future,
+    rust_link_blob_future,
+    rust_store_blob_future,
+    rust_unlink_blob_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class AsyncBlobMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for blobs."""
+
+    async def store_blob(self: SurrealDB, content: bytes) -> str:
+        """
+        Stores a blob such as an uploaded file, the same content is only stored once.
+
+        :param content: the content of the blob
+
+        :return: the SHA-256 hash of the content used to get and link the blob
+        """
+        try:
+            return await rust_store_blob_future(self._connection, content)
+        except Exception as e:
+            raise self._record_error("store_blob", e) from None
+
+    async def get_blob(self: SurrealDB, blob_hash: str) -> Optional[bytes]:
+        """
+        Gets the content of a blob.
+
+        :param blob_hash: the hash of the blob
+
+        :return: the content of the blob or None if it is not stored
+        """
+        try:
+            content = await rust_get_blob_future(self._connection, blob_hash)
+            return None if content is None else base64.b64decode(content)
+        except Exception as e:
+            raise self._record_error("get_blob", e) from None
+
+    async def link_blob(self: SurrealDB, record: str, blob_hash: str) -> None:
+        """
+        Links a record to a blob so the blob is not garbage collected.
+
+        :param record: the ID of the record such as "user:tobie"
+        :param blob_hash: the hash of the blob
+
+        :return: None
+        """
+        try:
+            await rust_link_blob_future(self._connection, record, blob_hash)
+        except Exception as e:
+            raise self._record_error("link_blob", e) from None
+
+    async def unlink_blob(self: SurrealDB, record: str, blob_hash: str) -> None:
+        """
+        Removes the link between a record and a blob.
+
+        :param record: the ID of the record
+        :param blob_hash: the hash of the blob
+
+        :return: None
+        """
+        try:
+            await rust_unlink_blob_future(self._connection, record, blob_hash)
+        except Exception as e:
+            raise self._record_error("unlink_blob", e) from None
+
+    async def gc_blobs(self: SurrealDB, min_age: int = 3600) -> int:
+        """
+        Deletes the blobs that are not linked to any record, this is meant to be run on a schedule.
+
+        :param min_age: blobs stored less than this many seconds ago are kept so they can still be linked
+
+        :return: the number of blobs deleted
+        """
+        try:
+            return await rust_gc_blobs_future(self._connection, min_age)
+        except Exception as e:
+            raise self._record_error("gc_blobs", e) from None
@@ -0,0 +1,63 @@
+"""This file defines the interface between python and the Rust SurrealDB library for atomic counters and sequences."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Union
+
+from surrealdb.rust_surrealdb import rust_increment_future, rust_next_value_future
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class AsyncCounterMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for counters."""
+
+    async def increment(
+        self: SurrealDB, record: str, field: str, amount: Union[int, float] = 1
+    ) -> Union[int, float]:
+        """
+        Atomically adds an amount to a numeric field of a record.
+
+        :param record: the ID of the record holding the counter such as "counter:visits"
+        :param field: the field of the counter
+        :param amount: the amount to add to the counter
+
+        :return: the value of the counter after the update
+        """
+        try:
+            return json.loads(
+                await rust_increment_future(
+                    self._connection, record, field, json.dumps(amount)
+                )
+            )
+        except Exception as e:
+            raise self._record_error("increment", e) from None
+
+    async def decrement(
+        self: SurrealDB, record: str, field: str, amount: Union[int, float] = 1
+    ) -> Union[int, float]:
+        """
+        Atomically subtracts an amount from a numeric field of a record.
+
+        :param record: the ID of the record holding the counter such as "counter:visits"
+        :param field: the field of the counter
+        :param amount: the amount to subtract from the counter
+
+        :return: the value of the counter after the update
+        """
+        return await self.increment(record, field, -amount)
+
+    async def next_value(self: SurrealDB, name: str) -> int:
+        """
+        Gets the next value of a named sequence starting at 1.
+
+        :param name: the name of the sequence
+
+        :return: the next value of the sequence
+        """
+        try:
+            return json.loads(await rust_next_value_future(self._connection, name))
+        except Exception as e:
+            raise self._record_error("next_value", e) from None
@@ -3,13 +3,16 @@
 from __future__ import annotations
 
 import json
-from typing import TYPE_CHECKING, List, Union
+from typing import TYPE_CHECKING, List, Optional, Union
 
-from surrealdb.errors import SurrealDbError
+from surrealdb.errors import InsertError
 from surrealdb.rust_surrealdb import (
     rust_create_future,
     rust_delete_future,
+    rust_insert_future,
 )
+from surrealdb.execution_mixins.create import insert_error
+from surrealdb.execution_mixins.quota import write_size
 
 if TYPE_CHECKING:
     from surrealdb.connection_interface import SurrealDB
@@ -27,12 +30,18 @@ class AsyncCreateMixin:
 
         :return: None
         """
+        with self._recording("create"):
+            data = self._to_units(name, data)
+            records, size = write_size(data)
+            await self._check_quota(records, size)
         try:
-            return json.loads(
-                await rust_create_future(self._connection, name, json.dumps(data))
+            outcome = await rust_create_future(
+                self._connection, name, self._encrypt(name, json.dumps(data))
             )
+            self._count_quota(records, size)
+            return self._from_units(name, json.loads(self._decrypt(name, outcome)))
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("create", e) from None
 
     async def delete(self: SurrealDB, name: str) -> Union[List[dict], dict]:
         """
@@ -45,4 +54,43 @@ class AsyncCreateMixin:
         try:
             return await rust_delete_future(self._connection, name)
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("delete", e) from None
+
+    async def insert(
+        self: SurrealDB,
+        name: str,
+        data: List[dict],
+        chunk_size: Optional[int] = None,
+    ) -> List[str]:
+        """
+        Inserts a list of documents into a table in chunks.
+
+        :param name: the name of the table to insert the documents into
+        :param data: the documents to insert
+        :param chunk_size: the maximum number of documents sent to the database at once
+
+        :return: the IDs of the inserted documents
+
+        :raises InsertError: if a chunk fails, the chunks before it stay inserted and their IDs are on
+            the "inserted" attribute of the error so only the remaining documents need to be retried
+        """
+        with self._recording("insert"):
+            data = self._to_units(name, data)
+            records, size = write_size(data)
+            await self._check_quota(records, size)
+        try:
+            inserted = json.loads(
+                await rust_insert_future(
+                    self._connection,
+                    name,
+                    self._encrypt(name, json.dumps(data)),
+                    chunk_size,
+                )
+            )
+            self._count_quota(records, size)
+            return inserted
+        except Exception as e:
+            error = insert_error(e)
+            if isinstance(error, InsertError):
+                self._count_quota(*write_size(data[: len(error.inserted)]))
+            raise self._record_error("insert", error) from None
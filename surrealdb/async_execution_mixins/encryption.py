@@ -0,0 +1,7 @@
+"""This file defines the interface between python and the Rust SurrealDB library for client-side field encryption."""
+
+from surrealdb.execution_mixins.encryption import EncryptionMixin
+
+
+class AsyncEncryptionMixin(EncryptionMixin):
+    """This class is responsible for encrypting fields before they are sent to the database and decrypting them on the way back."""
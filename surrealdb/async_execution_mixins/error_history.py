@@ -0,0 +1,7 @@
+"""This file defines the history of the errors raised by the operations of a connection."""
+
+from surrealdb.execution_mixins.error_history import ErrorHistoryMixin
+
+
+class AsyncErrorHistoryMixin(ErrorHistoryMixin):
+    """This class is responsible for keeping the most recent errors of a connection."""
@@ -0,0 +1,35 @@
+"""This file defines the interface between python and the Rust SurrealDB library for running functions and ML models."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Any, List, Optional
+
+from surrealdb.rust_surrealdb import rust_run_function_future
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class AsyncFunctionMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for running functions."""
+
+    async def run_function(
+        self: SurrealDB, name: str, args: Optional[List[Any]] = None
+    ) -> Any:
+        """
+        Runs a custom function or ML model on the database.
+
+        :param name: the name of the function such as "fn::greet", "greet" or "ml::model<1.0.0>"
+        :param args: the arguments to pass to the function
+
+        :return: the result of the function
+        """
+        try:
+            return json.loads(
+                await rust_run_function_future(
+                    self._connection, name, json.dumps(args or [])
+                )
+            )
+        except Exception as e:
+            raise self._record_error("run_function", e) from None
@@ -0,0 +1,7 @@
+"""This file defines the opt-in guard against queries with user data formatted into them."""
+
+from surrealdb.execution_mixins.guard import GuardMixin
+
+
+class AsyncGuardMixin(GuardMixin):
+    """This class is responsible for rejecting queries that look like they were built from user data."""
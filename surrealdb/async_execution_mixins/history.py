@@ -0,0 +1,64 @@
+"""This file defines the interface between python and the Rust SurrealDB library for reading past versions of records."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, List, Optional
+
+from surrealdb.rust_surrealdb import (
+    rust_read_as_of_future,
+    rust_record_history_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class AsyncHistoryMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for record history."""
+
+    async def read_as_of(
+        self: SurrealDB,
+        name: str,
+        versionstamp: Optional[int] = None,
+        timestamp: Optional[str] = None,
+    ) -> List[dict]:
+        """
+        Reads a table as it was at a versionstamp or a point in time by replaying its change feed.
+        The table must be defined with a CHANGEFEED that still holds the changes being read.
+
+        :param name: the name of the table to read
+        :param versionstamp: the versionstamp of the last change to include
+        :param timestamp: the time to read the table at such as "2024-01-01T00:00:00Z"
+
+        :return: the records of the table at that version
+        """
+        try:
+            return json.loads(
+                await rust_read_as_of_future(
+                    self._connection, name, versionstamp, timestamp
+                )
+            )
+        except Exception as e:
+            raise self._record_error("read_as_of", e) from None
+
+    async def record_history(
+        self: SurrealDB, record_id: str, since: Optional[int] = None
+    ) -> List[dict]:
+        """
+        Gets the timeline of a record from the change feed of its table. Each entry has the
+        "versionstamp" of the change, the "action" taken ("create", "update" or "delete"), the
+        "state" of the record after it (None once deleted) and the "diff" from the previous state
+        as JSON Patch operations.
+
+        :param record_id: the ID of the record such as "user:tobie"
+        :param since: the versionstamp of the first change to include, all changes if None
+
+        :return: the entries of the timeline, oldest first
+        """
+        try:
+            return json.loads(
+                await rust_record_history_future(self._connection, record_id, since)
+            )
+        except Exception as e:
+            raise self._record_error("record_history", e) from None
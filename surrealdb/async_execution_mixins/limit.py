@@ -0,0 +1,7 @@
+"""This file defines the limit added to select statements that would otherwise return a whole table."""
+
+from surrealdb.execution_mixins.limit import LimitMixin
+
+
+class AsyncLimitMixin(LimitMixin):
+    """This class is responsible for capping the rows returned by select statements without a limit."""
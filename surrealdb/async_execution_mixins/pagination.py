@@ -0,0 +1,122 @@
+"""This file defines the interface between python and the Rust SurrealDB library for paginating over tables."""
+
+from __future__ import annotations
+
+import json
+import asyncio
+from typing import (
+    TYPE_CHECKING,
+    AsyncIterator,
+    Awaitable,
+    Callable,
+    List,
+    Optional,
+    Tuple,
+)
+
+from surrealdb.rust_surrealdb import (
+    rust_paginate_future,
+    rust_partition_table_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class AsyncPaginationMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for pagination."""
+
+    async def paginate(
+        self: SurrealDB, name: str, page_size: int = 1000, token: Optional[str] = None
+    ) -> Tuple[List[dict], Optional[str]]:
+        """
+        Gets a page of records from a table or record range in record ID order.
+
+        :param name: the name of the table or a record range from partition_table to read
+        :param page_size: the maximum number of records in the page
+        :param token: the continuation token returned with the previous page, None for the first page
+
+        :return: the records of the page and the token for the next page, the token is None after the last page
+        """
+        try:
+            page = json.loads(
+                await rust_paginate_future(self._connection, name, page_size, token)
+            )
+            return page["rows"], page["next"]
+        except Exception as e:
+            raise self._record_error("paginate", e) from None
+
+    async def scan(
+        self: SurrealDB, name: str, page_size: int = 1000
+    ) -> AsyncIterator[dict]:
+        """
+        Iterates over all the records of a table or record range, reading them a page at a time.
+
+        :param name: the name of the table or a record range from partition_table to read
+        :param page_size: the number of records read from the database at once
+
+        :return: an async iterator over the records
+        """
+        rows, token = await self.paginate(name, page_size)
+        for row in rows:
+            yield row
+        while token is not None:
+            rows, token = await self.paginate(name, page_size, token)
+            for row in rows:
+                yield row
+
+    async def partition_table(
+        self: SurrealDB, name: str, partitions: int = 4
+    ) -> List[dict]:
+        """
+        Splits a table into record ranges with about the same number of records in each. Each range
+        can be read by a separate worker with scan or paginate.
+
+        :param name: the name of the table to split
+        :param partitions: the number of ranges to split the table into, fewer are returned for small tables
+
+        :return: a list of dicts with the "resource" to read and the "count" of records it had
+        """
+        try:
+            return json.loads(
+                await rust_partition_table_future(self._connection, name, partitions)
+            )
+        except Exception as e:
+            raise self._record_error("partition_table", e) from None
+
+    async def process_partitions(
+        self: SurrealDB,
+        name: str,
+        handler: Callable[[dict], Awaitable[None]],
+        partitions: int = 4,
+        page_size: int = 1000,
+        progress: Optional[Callable[[int, int], None]] = None,
+    ) -> int:
+        """
+        Splits a table into record ranges and passes every record to the handler, with the ranges
+        being scanned concurrently.
+
+        :param name: the name of the table to process
+        :param handler: the coroutine function called with each record
+        :param partitions: the number of ranges to split the table into
+        :param page_size: the number of records read from the database at once
+        :param progress: called with the number of records processed so far and the estimated total
+
+        :return: the number of records processed
+        """
+        ranges = await self.partition_table(name, partitions)
+        total = sum(partition["count"] for partition in ranges)
+        processed = 0
+
+        async def _process(resource):
+            nonlocal processed
+            async for row in self.scan(resource, page_size):
+                await handler(row)
+                processed += 1
+                if progress is not None:
+                    progress(processed, total)
+
+        await asyncio.gather(
+            *(_process(partition["resource"]) for partition in ranges)
+        )
+        return processed
@@ -7,11 +7,13 @@ https://github.com/surrealdb/surrealdb/blob/main/lib/tests/fetch.rs.
 from __future__ import annotations
 
 import json
-from typing import TYPE_CHECKING, List, Union
+from typing import TYPE_CHECKING, Any, Dict, List, Optional, Tuple, Union
 
-from surrealdb.errors import SurrealDbError
 from surrealdb.rust_surrealdb import (
     rust_query_future,
+    rust_query_metadata_future,
+    rust_query_rows_future,
+    rust_query_snapshot_future,
     rust_select_future,
 )
 
@@ -22,18 +24,116 @@ if TYPE_CHECKING:
 class AsyncQueryMixin:
     """This class is responsible for the interface between python and the Rust SurrealDB library for creating a document."""
 
-    async def query(self: SurrealDB, query: str) -> List[dict]:
+    async def query(
+        self: SurrealDB, query: str, unlimited: bool = False
+    ) -> List[dict]:
         """
         queries the database.
 
         :param query: the query to run on the database
+        :param unlimited: if True the limit policy of the connection is not applied to the query
 
         :return: None
         """
+        with self._recording("query"):
+            self._guard(query)
         try:
+            query = self._limit(query, unlimited)
             return json.loads(await rust_query_future(self._connection, query))[0]
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("query", e) from None
+
+    async def query_rows(
+        self: SurrealDB,
+        query: str,
+        bindings: Optional[dict] = None,
+        fields: Optional[List[str]] = None,
+        stable_order: bool = False,
+        extract: Optional[List[str]] = None,
+        flatten: bool = False,
+        separator: str = ".",
+        arrays: str = "keep",
+        unlimited: bool = False,
+        with_metadata: bool = False,
+    ) -> Union[List[dict], Tuple[List[dict], Dict[str, Dict[str, dict]]]]:
+        """
+        queries the database returning the rows of every statement as one flat list.
+
+        :param query: the query to run on the database
+        :param bindings: the variables to bind to the query
+        :param fields: the fields to keep in each row, all fields are kept if None
+        :param stable_order: if True the record ID is added to the ORDER BY of every select so
+            pages of rows with equal sort values never skip or repeat rows
+        :param extract: paths such as "orders[*].total" to extract, each row is replaced by a dict
+            of the paths to the values found, paths with [*] give a list of every value found
+        :param flatten: if True nested dicts are flattened into keys joined by the separator
+        :param separator: the separator used to join the keys of nested dicts when flattening
+        :param arrays: how lists are flattened, "keep" leaves them as they are, "index" gives each
+            item its own key ending with its index and "json" turns them into a JSON string
+        :param unlimited: if True the limit policy of the connection is not applied to the query
+        :param with_metadata: if True the field_metadata of the tables selected from is returned
+            with the rows
+
+        :return: the rows of the query, with the field metadata if with_metadata is True
+        """
+        with self._recording("query_rows"):
+            self._guard(query)
+        try:
+            query = self._limit(query, unlimited)
+            rows = json.loads(
+                await rust_query_rows_future(
+                    self._connection,
+                    query,
+                    None if bindings is None else json.dumps(bindings),
+                    fields,
+                    stable_order,
+                    extract,
+                    separator if flatten else None,
+                    arrays,
+                )
+            )
+            rows = self._rows_from_units(rows, separator if flatten else None)
+            if not with_metadata:
+                return rows
+            return rows, json.loads(
+                await rust_query_metadata_future(self._connection, query)
+            )
+        except Exception as e:
+            raise self._record_error("query_rows", e) from None
+
+    async def query_snapshot(
+        self: SurrealDB,
+        queries: Union[List[str], Dict[str, str]],
+        bindings: Optional[dict] = None,
+        unlimited: bool = False,
+    ) -> Union[List[Any], Dict[str, Any]]:
+        """
+        queries the database with several queries that all read the same snapshot, so the results
+        of a report are consistent with each other even while other clients are writing.
+
+        :param queries: the queries to run, either a list or a dict of names to queries
+        :param bindings: the variables to bind to all the queries
+        :param unlimited: if True the limit policy of the connection is not applied to the query
+
+        :return: the result of the last statement of each query, in a dict if queries was a dict
+        """
+        names = list(queries) if isinstance(queries, dict) else None
+        sql = [queries[name] for name in names] if names is not None else queries
+        with self._recording("query_snapshot"):
+            for query in sql:
+                self._guard(query)
+        try:
+            sql = [self._limit(query, unlimited) for query in sql]
+            results = json.loads(
+                await rust_query_snapshot_future(
+                    self._connection,
+                    sql,
+                    None if bindings is None else json.dumps(bindings),
+                )
+            )
+        except Exception as e:
+            raise self._record_error("query_snapshot", e) from None
+        return results if names is None else dict(zip(names, results))
 
     async def select(self: SurrealDB, resource: str) -> Union[List[dict], dict]:
         """
@@ -43,4 +143,10 @@ class AsyncQueryMixin:
 
         :return: the result of the select
         """
-        return await rust_select_future(self._connection, resource)
+        try:
+            outcome = await rust_select_future(self._connection, resource)
+            return self._from_units(
+                resource, json.loads(self._decrypt(resource, outcome))
+            )
+        except Exception as e:
+            raise self._record_error("select", e) from None
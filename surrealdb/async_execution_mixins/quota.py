@@ -0,0 +1,33 @@
+"""This file defines the client-side quotas on the records and storage of the database."""
+
+from __future__ import annotations
+
+import time
+from typing import TYPE_CHECKING
+
+from surrealdb.execution_mixins.quota import QuotaMixin, check_quota, count_quota
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class AsyncQuotaMixin(QuotaMixin):
+    """This class is responsible for stopping writes that would take the database over a quota."""
+
+    async def _check_quota(self: SurrealDB, records: int, size: int) -> None:
+        """
+        Checks that a write keeps the database within the quota, it is only counted once it succeeds.
+
+        :param records: the number of records the write adds
+        :param size: the estimated number of bytes the write adds
+
+        :return: None
+        """
+        quota = getattr(self, "_quota", None)
+        if quota is None:
+            return
+        refreshed_at = quota["refreshed_at"]
+        stale = time.monotonic() - (refreshed_at or 0) > quota["refresh_seconds"]
+        if refreshed_at is None or stale:
+            count_quota(quota, await self.storage_report())
+        check_quota(quota, records, size)
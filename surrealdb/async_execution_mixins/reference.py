@@ -0,0 +1,126 @@
+"""This file defines the interface between python and the Rust SurrealDB library for following record links."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Callable, Dict, List, Optional, Tuple
+
+from surrealdb.rust_surrealdb import (
+    rust_check_references_future,
+    rust_fetch_references_future,
+    rust_orphaned_edges_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class AsyncReferenceMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for record links."""
+
+    async def _fetch_references(
+        self: SurrealDB, rows: List[dict], fields: List[str], embed: bool
+    ) -> dict:
+        try:
+            return json.loads(
+                await rust_fetch_references_future(
+                    self._connection, json.dumps(rows), fields, embed
+                )
+            )
+        except Exception as e:
+            raise self._record_error("fetch_references", e) from None
+
+    async def fetch_references(
+        self: SurrealDB, rows: List[dict], fields: List[str]
+    ) -> List[dict]:
+        """
+        Replaces the record IDs in fields of the rows with the records they link to. The linked
+        records are fetched in batches with each record only fetched once, links to records that
+        do not exist are left as IDs.
+
+        :param rows: the rows holding the links such as the result of a query
+        :param fields: the fields holding a record ID or a list of them such as "author"
+        :return: the rows with the linked records embedded
+        """
+        return (await self._fetch_references(rows, fields, True))["rows"]
+
+    async def sideload_references(
+        self: SurrealDB, rows: List[dict], fields: List[str]
+    ) -> Dict[str, dict]:
+        """
+        Fetches the records linked from fields of the rows without changing the rows.
+
+        :param rows: the rows holding the links such as the result of a query
+        :param fields: the fields holding a record ID or a list of them such as "author"
+        :return: the linked records keyed by record ID
+        """
+        return (await self._fetch_references(rows, fields, False))["included"]
+
+    async def check_references(
+        self: SurrealDB,
+        name: str,
+        fields: List[str],
+        batch_size: int = 1000,
+        token: Optional[str] = None,
+        fix: Optional[str] = None,
+    ) -> Tuple[List[dict], Optional[str]]:
+        """
+        Checks a batch of the records of a table for links to records that no longer exist. Pass
+        the returned token back in to check the next batch, it is None after the last batch.
+
+        :param name: the name of the table holding the links
+        :param fields: the fields holding a record ID or a list of them such as "author"
+        :param batch_size: the number of records checked
+        :param token: the token returned with the previous batch, None for the first batch
+        :param fix: "null" to remove the dangling links or "delete" to delete the records
+            holding them, the links are only reported if None
+        :return: a dict for each record and field with dangling links giving the "id" of the
+            record, the "field" and the "missing" record IDs, and the token for the next batch
+        """
+        try:
+            outcome = json.loads(
+                await rust_check_references_future(
+                    self._connection, name, fields, batch_size, token, fix
+                )
+            )
+            return outcome["dangling"], outcome["next"]
+        except Exception as e:
+            raise self._record_error("check_references", e) from None
+
+    async def clean_orphaned_edges(
+        self: SurrealDB,
+        name: str,
+        batch_size: int = 1000,
+        delete: bool = False,
+        progress: Optional[Callable[[int, int], None]] = None,
+    ) -> List[dict]:
+        """
+        Goes through all the edges of a graph table in batches finding the edges whose "in" or
+        "out" record no longer exists, optionally deleting them.
+
+        :param name: the name of the edge table
+        :param batch_size: the number of edges checked per batch
+        :param delete: if True the orphaned edges are deleted, otherwise they are only reported
+        :param progress: called after each batch with the number of edges checked and orphans
+            found so far
+        :return: a dict for each orphaned edge with its "id" and the "missing" record IDs
+        """
+        orphans: List[dict] = []
+        checked = 0
+        token = None
+        while True:
+            try:
+                batch = json.loads(
+                    await rust_orphaned_edges_future(
+                        self._connection, name, batch_size, token, delete
+                    )
+                )
+            except Exception as e:
+                raise self._record_error("clean_orphaned_edges", e) from None
+            orphans.extend(batch["orphans"])
+            checked += batch["checked"]
+            if progress is not None:
+                progress(checked, len(orphans))
+            token = batch["next"]
+            if token is None:
+                return orphans
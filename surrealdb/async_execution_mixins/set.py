@@ -5,8 +5,7 @@ from __future__ import annotations
 import json
 from typing import TYPE_CHECKING
 
-from surrealdb.errors import SurrealDbError
-from surrealdb.rust_surrealdb import rust_set_future
+from surrealdb.rust_surrealdb import rust_set_future, rust_unset_future
 
 if TYPE_CHECKING:
     from surrealdb.connection_interface import SurrealDB
@@ -29,10 +28,23 @@ class AsyncSetMixin:
             json_str = json.dumps(value)
         except json.JSONEncodeError as e:
             print(f"cannot serialize value {type(value)} to json")
-            raise SurrealDbError(e) from None
+            raise self._record_error("set", e) from None
 
         if json_str is not None:
             try:
                 _ = await rust_set_future(self._connection, key, json.dumps(value))
             except Exception as e:
-                raise SurrealDbError(e) from None
+                raise self._record_error("set", e) from None
+
+    async def unset(self: SurrealDB, key: str) -> None:
+        """
+        Removes a variable that was set on the connection.
+
+        :param key: the name of the variable to remove
+
+        :return: None
+        """
+        try:
+            await rust_unset_future(self._connection, key)
+        except Exception as e:
+            raise self._record_error("unset", e) from None
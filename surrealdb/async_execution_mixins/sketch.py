@@ -0,0 +1,75 @@
+"""This file defines the interface between python and the Rust SurrealDB library for approximate statistics."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, List, Optional
+
+from surrealdb.rust_surrealdb import (
+    rust_approx_distinct_future,
+    rust_approx_percentiles_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class AsyncSketchMixin:
+    """This class is responsible for approximating statistics of tables too big to compute exactly."""
+
+    async def approx_distinct(
+        self: SurrealDB, name: str, field: str, page_size: Optional[int] = None
+    ) -> dict:
+        """
+        Approximates the number of distinct values of a field with a HyperLogLog, which has a
+        standard error of about 0.8%. The table is read a page at a time and only the field is
+        selected, null and missing values are not counted.
+
+        :param name: the name of the table to read
+        :param field: the dotted path of the field such as "address.city"
+        :param page_size: the number of records read per page, 1000 if None
+        :return: a dict with the "count" of values read and the approximate number of "distinct" values
+        """
+        try:
+            return json.loads(
+                await rust_approx_distinct_future(
+                    self._connection, name, field, page_size
+                )
+            )
+        except Exception as e:
+            raise self._record_error("approx_distinct", e) from None
+
+    async def approx_percentiles(
+        self: SurrealDB,
+        name: str,
+        field: str,
+        percentiles: Optional[List[float]] = None,
+        page_size: Optional[int] = None,
+        compression: Optional[float] = None,
+    ) -> dict:
+        """
+        Approximates percentiles of a numeric field with a t-digest, which is most accurate towards
+        the tails. The table is read a page at a time and only the field is selected, values that
+        are not numbers are skipped.
+
+        :param name: the name of the table to read
+        :param field: the dotted path of the field such as "order.total"
+        :param percentiles: the percentiles as fractions, [0.5, 0.9, 0.99] if None
+        :param page_size: the number of records read per page, 1000 if None
+        :param compression: the compression of the t-digest, higher is more accurate, 100 if None
+        :return: a dict with the "count" of numbers read, their exact "min" and "max" and the
+            estimated "percentiles" as a dict of each fraction to its value
+        """
+        percentiles = [0.5, 0.9, 0.99] if percentiles is None else percentiles
+        try:
+            outcome = json.loads(
+                await rust_approx_percentiles_future(
+                    self._connection, name, field, percentiles, page_size, compression
+                )
+            )
+        except Exception as e:
+            raise self._record_error("approx_percentiles", e) from None
+        outcome["percentiles"] = {
+            float(key): value for key, value in outcome["percentiles"].items()
+        }
+        return outcome
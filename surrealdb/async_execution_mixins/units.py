@@ -0,0 +1,7 @@
+"""This file defines the conversion between the units stored in the database and the Python types of fields."""
+
+from surrealdb.execution_mixins.units import UnitMixin
+
+
+class AsyncUnitMixin(UnitMixin):
+    """This class is responsible for converting fields between the units stored in the database and Python types."""
@@ -18,6 +18,7 @@ connection = SurrealDB(
 ```
 """
 
+import atexit
 import uuid
 from typing import Optional
 
@@ -25,9 +26,27 @@ from surrealdb.asyncio_runtime import AsyncioRuntime
 from surrealdb.execution_mixins.auth import SignInMixin
 
 # import the mixins for operations for the connection
+from surrealdb.execution_mixins.analysis import AnalysisMixin
+from surrealdb.execution_mixins.blob import BlobMixin
+from surrealdb.execution_mixins.constraint import ConstraintMixin
+from surrealdb.execution_mixins.counter import CounterMixin
 from surrealdb.execution_mixins.create import CreateMixin
+from surrealdb.execution_mixins.encryption import EncryptionMixin
+from surrealdb.execution_mixins.error_history import ErrorHistoryMixin
+from surrealdb.execution_mixins.function import FunctionMixin
+from surrealdb.execution_mixins.guard import GuardMixin
+from surrealdb.execution_mixins.history import HistoryMixin
+from surrealdb.execution_mixins.ingest import IngestMixin
+from surrealdb.execution_mixins.limit import LimitMixin
+from surrealdb.execution_mixins.pagination import PaginationMixin
 from surrealdb.execution_mixins.query import QueryMixin
+from surrealdb.execution_mixins.quota import QuotaMixin
+from surrealdb.execution_mixins.reference import ReferenceMixin
+from surrealdb.execution_mixins.schema import SchemaMixin
+from surrealdb.execution_mixins.session import SessionMixin
 from surrealdb.execution_mixins.set import SetMixin
+from surrealdb.execution_mixins.sketch import SketchMixin
+from surrealdb.execution_mixins.units import UnitMixin
 from surrealdb.execution_mixins.update import UpdateMixin
 from surrealdb.rust_surrealdb import (
     rust_make_connection_future,
@@ -50,6 +69,17 @@ class ConnectionController(type):
         """
         del cls.instances[connection_id]
 
+    def close_all(cls) -> None:
+        """
+        Closes all the connections that are being kept by the controller including the main connection.
+
+        :return: None
+        """
+        for instance in list(cls.instances.values()):
+            instance.close()
+        if cls.main_connection is not None:
+            cls.main_connection.close()
+
     def __call__(cls, *args, **kwargs):  # noqa: D102
         # establish the main connection
         if kwargs.get("main_connection", False) is True:
@@ -88,6 +118,24 @@ class SurrealDB(
     SetMixin,
     QueryMixin,
     UpdateMixin,
+    FunctionMixin,
+    CounterMixin,
+    SessionMixin,
+    ConstraintMixin,
+    EncryptionMixin,
+    BlobMixin,
+    IngestMixin,
+    SchemaMixin,
+    PaginationMixin,
+    HistoryMixin,
+    ReferenceMixin,
+    AnalysisMixin,
+    QuotaMixin,
+    LimitMixin,
+    GuardMixin,
+    UnitMixin,
+    SketchMixin,
+    ErrorHistoryMixin,
     metaclass=ConnectionController,
 ):
     """This class is responsible for managing the connection to SurrealDB and managing operations on the connection."""
@@ -158,3 +206,26 @@ class SurrealDB(
 
         loop_manager = AsyncioRuntime()
         loop_manager.loop.run_until_complete(async_use_database(database))
+
+    def close(self) -> None:
+        """
+        Closes the connection and removes it from the connection controller if it is being kept.
+
+        :return: None
+        """
+        self._connection = None
+        if self.id in type(self).instances:
+            type(self).remove_connection(self.id)
+        if type(self).main_connection is self:
+            type(self).main_connection = None
+
+    def __enter__(self) -> "SurrealDB":
+        """Returns the connection when entering the context manager."""
+        return self
+
+    def __exit__(self, exc_type, exc_value, traceback) -> None:
+        """Closes the connection when exiting the context manager."""
+        self.close()
+
+
+atexit.register(SurrealDB.close_all)
@@ -0,0 +1,53 @@
+"""
+This file defines the diagnostics that check the extension and a connection work end to end. The
+report can be attached to bug reports.
+
+# Usage
+The diagnostics can be run by the following code:
+```python
+from surrealdb import blocking_diagnostics
+
+report = blocking_diagnostics(url="ws://localhost:8000/rpc")
+```
+"""
+
+import json
+from typing import Optional
+
+from surrealdb.asyncio_runtime import AsyncioRuntime
+from surrealdb.errors import SurrealDbError
+from surrealdb.rust_surrealdb import rust_diagnostics_future
+
+
+async def diagnostics(url: Optional[str] = None) -> dict:
+    """
+    Runs the diagnostics in an async manner. A failing step does not stop the others, it is
+    recorded in the report instead.
+
+    :param url: the url to make a test connection to, an in-memory database is used if None
+    :return: a dict with whether everything is "ok", the "version" of the extension, the "connection"
+        report with the "server_version" or "error", and the "serialization" report with what was
+        "expected" and "received" for every supported type
+    """
+    try:
+        return json.loads(await rust_diagnostics_future(url))
+    except Exception as e:
+        raise SurrealDbError(e) from None
+
+
+def blocking_diagnostics(url: Optional[str] = None) -> dict:
+    """
+    Runs the diagnostics in a blocking manner, see `diagnostics`.
+
+    :param url: the url to make a test connection to, an in-memory database is used if None
+    :return: the report of the connection and serialization checks
+    """
+
+    async def _diagnostics(url):
+        return await rust_diagnostics_future(url)
+
+    try:
+        loop_manager = AsyncioRuntime()
+        return json.loads(loop_manager.loop.run_until_complete(_diagnostics(url)))
+    except Exception as e:
+        raise SurrealDbError(e) from None
@@ -1,2 +1,18 @@
 class SurrealDbError(Exception):
     """Base class for exceptions in this module."""
+
+
+class QuotaExceeded(SurrealDbError):
+    """Raised when a write would take the database over the quota set for the connection."""
+
+
+class InjectionSuspected(SurrealDbError):
+    """Raised when the injection guard finds a query that looks like user data was formatted into it."""
+
+
+class InsertError(SurrealDbError):
+    """Raised when an insert fails part way, the chunks inserted before the failure are not rolled back."""
+
+    def __init__(self, message: str, inserted: list):
+        super().__init__(message)
+        self.inserted = inserted
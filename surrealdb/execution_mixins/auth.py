@@ -2,12 +2,15 @@
 
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Dict, Optional
+import json
+from typing import TYPE_CHECKING, Any, Dict, Optional
 
 from surrealdb.asyncio_runtime import AsyncioRuntime
 from surrealdb.errors import SurrealDbError
 from surrealdb.rust_surrealdb import (
+    rust_auth_info_future,
     rust_authenticate_future,
+    rust_invalidate_future,
     rust_sign_in_future,
     rust_sign_up_future,
 )
@@ -19,18 +22,18 @@ if TYPE_CHECKING:
 class SignInMixin:
     """This class is responsible for the interface between python and the Rust SurrealDB library for logging in."""
 
-    def signin(self: SurrealDB, data: Optional[Dict[str, str]] = None) -> None:
+    def signin(self: SurrealDB, data: Optional[Dict[str, str]] = None) -> str:
         """
         Signs in to the database.
 
         :param password: the password to sign in with
         :param username: the username to sign in with
 
-        :return: None
+        :return: the JWT for the session
         """
 
-        async def _signin(connection, password, username):
-            return await rust_sign_in_future(connection, password, username)
+        async def _signin(connection, username, password):
+            return await rust_sign_in_future(connection, username, password)
 
         if data is None:
             data = {}
@@ -41,17 +44,18 @@ class SignInMixin:
 
         try:
             loop_manager = AsyncioRuntime()
-            loop_manager.loop.run_until_complete(
-                _signin(self._connection, password, username)
+            return loop_manager.loop.run_until_complete(
+                _signin(self._connection, username, password)
             )
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("signin", e) from None
 
     def signup(
         self: SurrealDB,
         namespace: str,
         database: str,
         data: Optional[Dict[str, str]] = None,
+        scope: Optional[str] = None,
     ) -> str:
         """
         Signs up to an auth scope within a namespace and database.
@@ -59,23 +63,36 @@ class SignInMixin:
         :param namespace: the namespace the auth scope is associated with
         :param database: the database the auth scope is associated with
         :param data: the data to sign up with
+        :param scope: the auth scope to sign up to, taken from the "scope" or "sc" key of the data if not provided
         :return: an JWT for that auth scope
         """
 
-        async def _signup(connection, data, namespace, database):
-            return await rust_sign_up_future(connection, data, namespace, database)
+        async def _signup(connection, data, namespace, database, scope):
+            return await rust_sign_up_future(
+                connection, data, namespace, database, scope
+            )
+
+        if data is None:
+            data = {}
+        if scope is None:
+            scope = data.get("scope", data.get("sc"))
+        if scope is None:
+            raise self._record_error(
+                "signup", SurrealDbError("a scope is required to sign up")
+            )
 
         try:
             loop_manager = AsyncioRuntime()
             return loop_manager.loop.run_until_complete(
-                _signup(self._connection, data, namespace, database)
+                _signup(self._connection, json.dumps(data), namespace, database, scope)
             )
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("signup", e) from None
 
-    def authenticate(self: SurrealDB, jwt: str) -> bool:
+    def authenticate(self: SurrealDB, jwt: str) -> None:
         """
-        Authenticates a JWT.
+        Authenticates a JWT. SurrealDB 1.x has no refresh tokens, to keep a session alive sign in
+        again or authenticate with a new JWT before the current one expires.
 
         :param jwt: the JWT to authenticate
         :return: None
@@ -90,4 +107,38 @@ class SignInMixin:
                 _authenticate(self._connection, jwt)
             )
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("authenticate", e) from None
+
+    def invalidate(self: SurrealDB) -> None:
+        """
+        Invalidates the authentication of the current session.
+
+        :return: None
+        """
+
+        async def _invalidate(connection):
+            return await rust_invalidate_future(connection)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            loop_manager.loop.run_until_complete(_invalidate(self._connection))
+        except Exception as e:
+            raise self._record_error("invalidate", e) from None
+
+    def auth_info(self: SurrealDB) -> Dict[str, Any]:
+        """
+        Gets the authentication information of the current session.
+
+        :return: the authenticated record, scope, token claims and session of the connection
+        """
+
+        async def _auth_info(connection):
+            return await rust_auth_info_future(connection)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return json.loads(
+                loop_manager.loop.run_until_complete(_auth_info(self._connection))
+            )
+        except Exception as e:
+            raise self._record_error("auth_info", e) from None
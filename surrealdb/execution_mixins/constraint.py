@@ -0,0 +1,64 @@
+"""This file defines the interface between python and the Rust SurrealDB library for table constraints."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, List
+
+from surrealdb.asyncio_runtime import AsyncioRuntime
+from surrealdb.rust_surrealdb import (
+    rust_define_ci_unique_future,
+    rust_find_by_ci_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class ConstraintMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for constraints."""
+
+    def define_ci_unique(self: SurrealDB, table: str, field: str) -> None:
+        """
+        Defines a case-insensitive unique constraint on a field such as an email address.
+
+        :param table: the table the field belongs to
+        :param field: the field to be unique regardless of case
+
+        :return: None
+        """
+
+        async def _define_ci_unique(connection, table, field):
+            return await rust_define_ci_unique_future(connection, table, field)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            loop_manager.loop.run_until_complete(
+                _define_ci_unique(self._connection, table, field)
+            )
+        except Exception as e:
+            raise self._record_error("define_ci_unique", e) from None
+
+    def find_by_ci(self: SurrealDB, table: str, field: str, value: str) -> List[dict]:
+        """
+        Finds the records whose field matches the value regardless of case and surrounding whitespace.
+
+        :param table: the table to search
+        :param field: the field constrained with define_ci_unique
+        :param value: the value to match
+
+        :return: the matching records
+        """
+
+        async def _find_by_ci(connection, table, field, value):
+            return await rust_find_by_ci_future(connection, table, field, value)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return json.loads(
+                loop_manager.loop.run_until_complete(
+                    _find_by_ci(self._connection, table, field, value)
+                )
+            )
+        except Exception as e:
+            raise self._record_error("find_by_ci", e) from None
@@ -0,0 +1,78 @@
+"""This file defines the interface between python and the Rust SurrealDB library for atomic counters and sequences."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Union
+
+from surrealdb.asyncio_runtime import AsyncioRuntime
+from surrealdb.rust_surrealdb import rust_increment_future, rust_next_value_future
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class CounterMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for counters."""
+
+    def increment(
+        self: SurrealDB, record: str, field: str, amount: Union[int, float] = 1
+    ) -> Union[int, float]:
+        """
+        Atomically adds an amount to a numeric field of a record.
+
+        :param record: the ID of the record holding the counter such as "counter:visits"
+        :param field: the field of the counter
+        :param amount: the amount to add to the counter
+
+        :return: the value of the counter after the update
+        """
+
+        async def _increment(connection, record, field, amount):
+            return await rust_increment_future(connection, record, field, amount)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return json.loads(
+                loop_manager.loop.run_until_complete(
+                    _increment(self._connection, record, field, json.dumps(amount))
+                )
+            )
+        except Exception as e:
+            raise self._record_error("increment", e) from None
+
+    def decrement(
+        self: SurrealDB, record: str, field: str, amount: Union[int, float] = 1
+    ) -> Union[int, float]:
+        """
+        Atomically subtracts an amount from a numeric field of a record.
+
+        :param record: the ID of the record holding the counter such as "counter:visits"
+        :param field: the field of the counter
+        :param amount: the amount to subtract from the counter
+
+        :return: the value of the counter after the update
+        """
+        return self.increment(record, field, -amount)
+
+    def next_value(self: SurrealDB, name: str) -> int:
+        """
+        Gets the next value of a named sequence starting at 1.
+
+        :param name: the name of the sequence
+
+        :return: the next value of the sequence
+        """
+
+        async def _next_value(connection, name):
+            return await rust_next_value_future(connection, name)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return json.loads(
+                loop_manager.loop.run_until_complete(
+                    _next_value(self._connection, name)
+                )
+            )
+        except Exception as e:
+            raise self._record_error("next_value", e) from None
@@ -3,16 +3,36 @@
 from __future__ import annotations
 
 import json
-from typing import TYPE_CHECKING, List, Union
+from typing import TYPE_CHECKING, List, Optional, Union
 
 from surrealdb.asyncio_runtime import AsyncioRuntime
-from surrealdb.errors import SurrealDbError
-from surrealdb.rust_surrealdb import rust_create_future, rust_delete_future
+from surrealdb.errors import InsertError
+from surrealdb.execution_mixins.quota import write_size
+from surrealdb.rust_surrealdb import (
+    rust_create_future,
+    rust_delete_future,
+    rust_insert_future,
+)
 
 if TYPE_CHECKING:
     from surrealdb.connection_interface import SurrealDB
 
 
+def insert_error(error: Exception) -> Exception:
+    """
+    Converts an error raised by the extension for an insert that failed part way into an InsertError.
+
+    :param error: the error raised by the extension
+
+    :return: an InsertError holding the IDs inserted before the failure, or the error if it did not
+        come from the insert
+    """
+    if isinstance(error, RuntimeError) and len(error.args) == 2:
+        message, inserted = error.args
+        return InsertError(message, json.loads(inserted))
+    return error
+
+
 class CreateMixin:
     """This class is responsible for the interface between python and the Rust SurrealDB library for creating a document."""
 
@@ -27,17 +47,21 @@ class CreateMixin:
         """
 
         async def _create(connection, name, data):
-            return await rust_create_future(connection, name, json.dumps(data))
+            return await rust_create_future(connection, name, data)
 
+        with self._recording("create"):
+            data = self._to_units(name, data)
+            records, size = write_size(data)
+            self._check_quota(records, size)
         try:
             loop_manager = AsyncioRuntime()
-            return json.loads(
-                loop_manager.loop.run_until_complete(
-                    _create(self._connection, name, data)
-                )
+            outcome = loop_manager.loop.run_until_complete(
+                _create(self._connection, name, self._encrypt(name, json.dumps(data)))
             )
+            self._count_quota(records, size)
+            return self._from_units(name, json.loads(self._decrypt(name, outcome)))
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("create", e) from None
 
     def delete(self: SurrealDB, name: str) -> Union[List[dict], dict]:
         """
@@ -55,4 +79,50 @@ class CreateMixin:
             loop_manager = AsyncioRuntime()
             return loop_manager.loop.run_until_complete(_delete(self._connection, name))
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("delete", e) from None
+
+    def insert(
+        self: SurrealDB,
+        name: str,
+        data: List[dict],
+        chunk_size: Optional[int] = None,
+    ) -> List[str]:
+        """
+        Inserts a list of documents into a table in chunks.
+
+        :param name: the name of the table to insert the documents into
+        :param data: the documents to insert
+        :param chunk_size: the maximum number of documents sent to the database at once
+
+        :return: the IDs of the inserted documents
+
+        :raises InsertError: if a chunk fails, the chunks before it stay inserted and their IDs are on
+            the "inserted" attribute of the error so only the remaining documents need to be retried
+        """
+
+        async def _insert(connection, name, data, chunk_size):
+            return await rust_insert_future(connection, name, data, chunk_size)
+
+        with self._recording("insert"):
+            data = self._to_units(name, data)
+            records, size = write_size(data)
+            self._check_quota(records, size)
+        try:
+            loop_manager = AsyncioRuntime()
+            inserted = json.loads(
+                loop_manager.loop.run_until_complete(
+                    _insert(
+                        self._connection,
+                        name,
+                        self._encrypt(name, json.dumps(data)),
+                        chunk_size,
+                    )
+                )
+            )
+            self._count_quota(records, size)
+            return inserted
+        except Exception as e:
+            error = insert_error(e)
+            if isinstance(error, InsertError):
+                self._count_quota(*write_size(data[: len(error.inserted)]))
+            raise self._record_error("insert", error) from None
@@ -0,0 +1,124 @@
+"""This file defines the interface between python and the Rust SurrealDB library for client-side field encryption."""
+
+from __future__ import annotations
+
+import base64
+import json
+from typing import TYPE_CHECKING, Any, Dict, List
+
+from surrealdb.rust_surrealdb import (
+    rust_decrypt_fields,
+    rust_encrypt_fields,
+    rust_tokenize_fields,
+    rust_tokenize_value,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class EncryptionMixin:
+    """This class is responsible for encrypting fields before they are sent to the database and decrypting them on the way back."""
+
+    def set_encryption_policy(
+        self: SurrealDB, key: bytes, fields: Dict[str, List[str]]
+    ) -> None:
+        """
+        Sets the fields that are encrypted (AES-256-GCM) for this connection. The fields are encrypted by
+        create, insert, update and merge and decrypted by select and in the records those return. Fields
+        changed with patch or queries are not encrypted. Each value is bound to its table and field, so
+        it fails to decrypt if it is moved to another table or field. It is not bound to its record as
+        the record ID is not known when writing to a whole table, so a value copied from one record to
+        another of the same table still decrypts.
+
+        :param key: the 32 byte key used to encrypt the fields
+        :param fields: the fields to encrypt per table such as {"user": ["ssn", "card.number"]}
+
+        :return: None
+        """
+        if len(key) != 32:
+            raise ValueError("encryption key must be 32 bytes")
+        self._encryption_policy = (base64.b64encode(key).decode(), fields)
+
+    def clear_encryption_policy(self: SurrealDB) -> None:
+        """
+        Stops encrypting and decrypting fields for this connection.
+
+        :return: None
+        """
+        self._encryption_policy = None
+
+    def set_tokenization_policy(
+        self: SurrealDB, key: bytes, fields: Dict[str, List[str]]
+    ) -> None:
+        """
+        Sets the fields that are replaced by a deterministic token (HMAC-SHA256) for this connection. The same
+        value always gets the same token so tokenized fields can still be joined and grouped on, but the raw
+        value never reaches the database. Fields are tokenized by create, insert, update and merge.
+
+        :param key: the secret key of the HMAC
+        :param fields: the fields to tokenize per table such as {"user": ["email"]}
+
+        :return: None
+        """
+        if not key:
+            raise ValueError("tokenization key cannot be empty")
+        self._tokenization_policy = (base64.b64encode(key).decode(), fields)
+
+    def clear_tokenization_policy(self: SurrealDB) -> None:
+        """
+        Stops tokenizing fields for this connection.
+
+        :return: None
+        """
+        self._tokenization_policy = None
+
+    def tokenize(self: SurrealDB, value: Any) -> str:
+        """
+        Gets the token of a value with the key of the tokenization policy to look up records by a tokenized field.
+
+        :param value: the raw value such as an email address
+
+        :return: the token stored in place of the value
+        """
+        policy = getattr(self, "_tokenization_policy", None)
+        if policy is None:
+            raise ValueError("no tokenization policy has been set")
+        return rust_tokenize_value(json.dumps(value), policy[0])
+
+    def _encrypt(self: SurrealDB, resource: str, data: str) -> str:
+        """
+        Tokenizes and encrypts the fields of the JSON data going to a resource if there are policies for its table.
+
+        :param resource: the resource the data is for such as "user" or "user:1"
+        :param data: the JSON of the record or list of records
+
+        :return: the JSON with the fields tokenized and encrypted
+        """
+        policy = getattr(self, "_tokenization_policy", None)
+        fields = policy[1].get(resource.split(":")[0]) if policy else None
+        if fields:
+            data = rust_tokenize_fields(data, fields, policy[0])
+
+        table = resource.split(":")[0]
+        policy = getattr(self, "_encryption_policy", None)
+        fields = policy[1].get(table) if policy else None
+        if not fields:
+            return data
+        return rust_encrypt_fields(data, fields, policy[0], table)
+
+    def _decrypt(self: SurrealDB, resource: str, data: str) -> str:
+        """
+        Decrypts the fields of the JSON data coming from a resource if there is a policy for its table.
+
+        :param resource: the resource the data is from such as "user" or "user:1"
+        :param data: the JSON of the record or list of records
+
+        :return: the JSON with the fields decrypted
+        """
+        table = resource.split(":")[0]
+        policy = getattr(self, "_encryption_policy", None)
+        fields = policy[1].get(table) if policy else None
+        if not fields:
+            return data
+        return rust_decrypt_fields(data, fields, policy[0], table)
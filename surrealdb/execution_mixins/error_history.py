@@ -0,0 +1,156 @@
+"""This file defines the history of the errors raised by the operations of a connection."""
+
+from __future__ import annotations
+
+from collections import deque
+from contextlib import contextmanager
+from datetime import datetime, timezone
+from typing import TYPE_CHECKING, Iterator, List, Optional
+
+from surrealdb.errors import InjectionSuspected, QuotaExceeded, SurrealDbError
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+DEFAULT_HISTORY_SIZE = 50
+
+# The codes of the errors raised by the extension, found by the start of the message of the
+# SurrealDB error they come from
+ERROR_CODES = (
+    ("Parse error", "ParseError"),
+    ("The SQL query was not parsed fully", "ParseError"),
+    ("There was a problem with authentication", "AuthenticationFailed"),
+    ("The session has expired", "SessionExpired"),
+    ("You don't have permission", "PermissionDenied"),
+    ("Specify a namespace to use", "NamespaceNotSelected"),
+    ("Specify a database to use", "DatabaseNotSelected"),
+    ("Database record", "RecordExists"),
+    ("Database index", "IndexViolation"),
+    ("Found ", "FieldCheckFailed"),
+    ("Expected a ", "TypeMismatch"),
+    ("The query was not executed due to a failed transaction", "TransactionFailed"),
+    ("Connection uninitialised", "NotConnected"),
+    ("There was an error processing a remote", "ConnectionFailed"),
+    ("Unsupported protocol or storage engine", "UnsupportedProtocol"),
+)
+
+
+def error_code(error: Exception) -> str:
+    """
+    Gets the code of an error for the error history.
+
+    :param error: the error raised by the extension or by the checks made before calling it
+
+    :return: the code of the SurrealDB error for errors raised by the extension, "NotFound" if
+        something the operation needs does not exist and "ExtensionError" if the error is not
+        known, otherwise the name of the exception such as "QuotaExceeded" or "ValueError"
+    """
+    if not isinstance(error, RuntimeError):
+        return type(error).__name__
+    message = str(error).lstrip('"')
+    for prefix, code in ERROR_CODES:
+        if message.startswith(prefix):
+            return code
+    if "does not exist" in message:
+        return "NotFound"
+    return "ExtensionError"
+
+
+class ErrorHistoryMixin:
+    """This class is responsible for keeping the most recent errors of a connection."""
+
+    def set_error_history_size(self: SurrealDB, size: int) -> None:
+        """
+        Sets how many of the most recent errors are kept for this connection, the oldest errors are
+        dropped first. The errors already kept are carried over up to the new size.
+
+        :param size: the number of errors to keep
+
+        :return: None
+        """
+        if size < 1:
+            raise ValueError("size must be at least 1")
+        self._error_history = deque(self._errors(), maxlen=size)
+
+    def error_history(self: SurrealDB, limit: Optional[int] = None) -> List[dict]:
+        """
+        Gets the most recent errors raised by the operations of this connection, so a long-running
+        service can report why a connection failed without scraping logs.
+
+        :param limit: the number of errors to return, all errors that are kept if None
+
+        :return: the errors from oldest to newest, each a dict with the "route" that is the name of
+            the operation that failed, the "timestamp" as a timezone aware datetime in UTC, the
+            "code" of the error such as "ParseError" or "QuotaExceeded" and the "message"
+        """
+        errors = list(self._errors())
+        if limit is not None:
+            errors = errors[-limit:] if limit > 0 else []
+        return [dict(error) for error in errors]
+
+    def clear_error_history(self: SurrealDB) -> None:
+        """
+        Forgets the errors kept for this connection.
+
+        :return: None
+        """
+        self._errors().clear()
+
+    def _errors(self: SurrealDB) -> deque:
+        """
+        Gets the errors kept for this connection, starting an empty history if there is none.
+
+        :return: the errors from oldest to newest
+        """
+        errors = getattr(self, "_error_history", None)
+        if errors is None:
+            errors = self._error_history = deque(maxlen=DEFAULT_HISTORY_SIZE)
+        return errors
+
+    def _record_error(self: SurrealDB, route: str, error: Exception) -> SurrealDbError:
+        """
+        Adds an error to the history of this connection.
+
+        :param route: the name of the operation that failed
+        :param error: the error raised by the extension
+
+        :return: the SurrealDbError to raise for the error, the error itself if it already is one
+        """
+        self._record(route, error)
+        if isinstance(error, SurrealDbError):
+            return error
+        return SurrealDbError(error)
+
+    @contextmanager
+    def _recording(self: SurrealDB, route: str) -> Iterator[None]:
+        """
+        Adds the errors raised by the checks made before an operation is sent to the extension to the
+        history of this connection, the errors are raised as they are.
+
+        :param route: the name of the operation being checked
+
+        :return: None
+        """
+        try:
+            yield
+        except (QuotaExceeded, InjectionSuspected, ValueError) as e:
+            self._record(route, e)
+            raise
+
+    def _record(self: SurrealDB, route: str, error: Exception) -> None:
+        """
+        Adds an error to the history of this connection.
+
+        :param route: the name of the operation that failed
+        :param error: the error that was raised
+
+        :return: None
+        """
+        self._errors().append(
+            {
+                "route": route,
+                "timestamp": datetime.now(timezone.utc),
+                "code": error_code(error),
+                "message": str(error),
+            }
+        )
@@ -0,0 +1,54 @@
+"""This file defines the opt-in guard against queries with user data formatted into them."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, List, Optional
+
+from surrealdb.errors import InjectionSuspected
+from surrealdb.rust_surrealdb import rust_inspect_query
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class GuardMixin:
+    """This class is responsible for rejecting queries that look like they were built from user data."""
+
+    def set_injection_guard(self: SurrealDB, allow: Optional[List[str]] = None) -> None:
+        """
+        Makes query, query_rows and query_snapshot inspect every query before it is run and raise
+        InjectionSuspected if it contains a comment, compares a field to a string literal or
+        compares two literals such as 1 = 1 in a WHERE clause. These are the patterns left behind
+        when user data is formatted into a query, pass the data as bindings instead.
+
+        :param allow: queries that are known to be safe and are run without being inspected
+
+        :return: None
+        """
+        self._injection_allowlist = {query.strip() for query in allow or []}
+
+    def clear_injection_guard(self: SurrealDB) -> None:
+        """
+        Stops inspecting the queries of this connection.
+
+        :return: None
+        """
+        self._injection_allowlist = None
+
+    def _guard(self: SurrealDB, query: str) -> None:
+        """
+        Inspects a query if the injection guard is on, raising InjectionSuspected if it is suspicious.
+
+        :param query: the query to inspect
+
+        :return: None
+        """
+        allowlist = getattr(self, "_injection_allowlist", None)
+        if allowlist is None or query.strip() in allowlist:
+            return
+        try:
+            findings = rust_inspect_query(query)
+        except Exception as e:
+            raise self._record_error("inspect_query", e) from None
+        if findings:
+            raise InjectionSuspected("; ".join(findings))
@@ -0,0 +1,111 @@
+"""This file defines the interface between python and the Rust SurrealDB library for importing records from files."""
+
+from __future__ import annotations
+
+import json
+import os
+from typing import TYPE_CHECKING, Any, Dict, Optional
+
+from surrealdb.asyncio_runtime import AsyncioRuntime
+from surrealdb.rust_surrealdb import rust_import_file_future, rust_materialize_future
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class IngestMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for importing files."""
+
+    def import_file(
+        self: SurrealDB,
+        name: str,
+        path: str,
+        file_format: Optional[str] = None,
+        mapping: Optional[Dict[str, Any]] = None,
+        chunk_size: Optional[int] = None,
+    ) -> int:
+        """
+        Imports the rows of an NDJSON or CSV file into a table, the file is read and mapped in Rust.
+
+        :param name: the name of the table to import the rows into
+        :param path: the path of the file
+        :param file_format: "ndjson" or "csv", taken from the file extension if not given
+        :param mapping: the target field for each source field such as {"id": "user_id", "age": {"from": "age", "type": "int"}}
+        :param chunk_size: the maximum number of records sent to the database at once
+
+        :return: the number of records imported
+        """
+        file_format = file_format or os.path.splitext(path)[1].lstrip(".")
+
+        async def _import_file(connection, name, path, file_format, mapping, chunk_size):
+            return await rust_import_file_future(
+                connection, name, path, file_format, mapping, chunk_size
+            )
+
+        with self._recording("import_file"):
+            size = os.path.getsize(path) if os.path.isfile(path) else 0
+            self._check_quota(0, size)
+        try:
+            loop_manager = AsyncioRuntime()
+            imported = loop_manager.loop.run_until_complete(
+                _import_file(
+                    self._connection,
+                    name,
+                    path,
+                    file_format,
+                    None if mapping is None else json.dumps(mapping),
+                    chunk_size,
+                )
+            )
+            self._count_quota(imported, size)
+            return imported
+        except Exception as e:
+            raise self._record_error("import_file", e) from None
+
+    def materialize(
+        self: SurrealDB,
+        query: str,
+        name: str,
+        mode: str = "create",
+        bindings: Optional[dict] = None,
+        keep_ids: bool = True,
+    ) -> int:
+        """
+        Writes the rows of the select statements of a query into a table. The rows are copied
+        by the database in one transaction, so they are not loaded into Python, keep their
+        types such as record links and datetimes, and a failure leaves the table as it was.
+
+        :param query: the select statements whose rows are written
+        :param name: the name of the table to write the rows into
+        :param mode: "create" fails if the table exists, "replace" deletes its records first and
+            "append" adds to them
+        :param bindings: the variables to bind to the query
+        :param keep_ids: if True rows keep the key of their record ID so user:1 is written as
+            name:1, otherwise the records get new IDs
+
+        :return: the number of records written
+        """
+
+        async def _materialize(connection, query, name, bindings, mode, keep_ids):
+            return await rust_materialize_future(
+                connection, query, name, bindings, mode, keep_ids
+            )
+
+        with self._recording("materialize"):
+            self._check_quota(0, 0)
+        try:
+            loop_manager = AsyncioRuntime()
+            written = loop_manager.loop.run_until_complete(
+                _materialize(
+                    self._connection,
+                    query,
+                    name,
+                    None if bindings is None else json.dumps(bindings),
+                    mode,
+                    keep_ids,
+                )
+            )
+            self._count_quota(written)
+            return written
+        except Exception as e:
+            raise self._record_error("materialize", e) from None
@@ -0,0 +1,51 @@
+"""This file defines the limit added to select statements that would otherwise return a whole table."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from surrealdb.rust_surrealdb import rust_limit_selects
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class LimitMixin:
+    """This class is responsible for capping the rows returned by select statements without a limit."""
+
+    def set_limit_policy(self: SurrealDB, max_rows: int) -> None:
+        """
+        Adds a LIMIT of max_rows to every select statement without a limit that is run by query,
+        query_rows and query_snapshot on this connection, so exploratory code cannot accidentally
+        scan a whole table on a shared database. Pass unlimited=True to those methods to run a
+        query as it is written.
+
+        :param max_rows: the limit to add to select statements without a limit
+
+        :return: None
+        """
+        if max_rows < 1:
+            raise ValueError("max_rows must be at least 1")
+        self._limit_policy = max_rows
+
+    def clear_limit_policy(self: SurrealDB) -> None:
+        """
+        Stops adding a limit to the select statements of this connection.
+
+        :return: None
+        """
+        self._limit_policy = None
+
+    def _limit(self: SurrealDB, query: str, unlimited: bool = False) -> str:
+        """
+        Adds the limit of the limit policy to the select statements of a query that have none.
+
+        :param query: the query to rewrite
+        :param unlimited: if True the query is returned as it is
+
+        :return: the rewritten query
+        """
+        max_rows = getattr(self, "_limit_policy", None)
+        if max_rows is None or unlimited:
+            return query
+        return rust_limit_selects(query, max_rows)
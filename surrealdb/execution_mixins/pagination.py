@@ -0,0 +1,85 @@
+"""This file defines the interface between python and the Rust SurrealDB library for paginating over tables."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Iterator, List, Optional, Tuple
+
+from surrealdb.asyncio_runtime import AsyncioRuntime
+from surrealdb.rust_surrealdb import (
+    rust_paginate_future,
+    rust_partition_table_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class PaginationMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for pagination."""
+
+    def paginate(
+        self: SurrealDB, name: str, page_size: int = 1000, token: Optional[str] = None
+    ) -> Tuple[List[dict], Optional[str]]:
+        """
+        Gets a page of records from a table or record range in record ID order.
+
+        :param name: the name of the table or a record range from partition_table to read
+        :param page_size: the maximum number of records in the page
+        :param token: the continuation token returned with the previous page, None for the first page
+
+        :return: the records of the page and the token for the next page, the token is None after the last page
+        """
+
+        async def _paginate(connection, name, page_size, token):
+            return await rust_paginate_future(connection, name, page_size, token)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            page = json.loads(
+                loop_manager.loop.run_until_complete(
+                    _paginate(self._connection, name, page_size, token)
+                )
+            )
+            return page["rows"], page["next"]
+        except Exception as e:
+            raise self._record_error("paginate", e) from None
+
+    def scan(self: SurrealDB, name: str, page_size: int = 1000) -> Iterator[dict]:
+        """
+        Iterates over all the records of a table or record range, reading them a page at a time.
+
+        :param name: the name of the table or a record range from partition_table to read
+        :param page_size: the number of records read from the database at once
+
+        :return: an iterator over the records
+        """
+        rows, token = self.paginate(name, page_size)
+        yield from rows
+        while token is not None:
+            rows, token = self.paginate(name, page_size, token)
+            yield from rows
+
+    def partition_table(self: SurrealDB, name: str, partitions: int = 4) -> List[dict]:
+        """
+        Splits a table into record ranges with about the same number of records in each. Each range
+        can be read by a separate worker with scan or paginate.
+
+        :param name: the name of the table to split
+        :param partitions: the number of ranges to split the table into, fewer are returned for small tables
+
+        :return: a list of dicts with the "resource" to read and the "count" of records it had
+        """
+
+        async def _partition_table(connection, name, partitions):
+            return await rust_partition_table_future(connection, name, partitions)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return json.loads(
+                loop_manager.loop.run_until_complete(
+                    _partition_table(self._connection, name, partitions)
+                )
+            )
+        except Exception as e:
+            raise self._record_error("partition_table", e) from None
@@ -0,0 +1,117 @@
+"""This file defines the client-side quotas on the records and storage of the database."""
+
+from __future__ import annotations
+
+import json
+import time
+from typing import TYPE_CHECKING, Any, Optional, Tuple
+
+from surrealdb.errors import QuotaExceeded
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class QuotaMixin:
+    """This class is responsible for stopping writes that would take the database over a quota."""
+
+    def set_quota(
+        self: SurrealDB,
+        max_records: Optional[int] = None,
+        max_bytes: Optional[int] = None,
+        refresh_seconds: float = 60,
+    ) -> None:
+        """
+        Sets a quota on the database of this connection that is checked by create, insert, update,
+        merge, import_file and materialize. The records and bytes in the database are read with
+        storage_report and cached, the successful writes of this connection are added to the
+        cached counters until they are read again. import_file and materialize only know how many
+        records they wrote once they are done, so they are refused once the database is over the
+        quota and counted afterwards.
+
+        :param max_records: the maximum number of records in the database, no maximum if None
+        :param max_bytes: the maximum estimated size of the database in bytes, no maximum if None
+        :param refresh_seconds: how long the counters are cached before they are read again
+
+        :return: None
+        """
+        self._quota = {
+            "max_records": max_records,
+            "max_bytes": max_bytes,
+            "refresh_seconds": refresh_seconds,
+            "refreshed_at": None,
+            "records": 0,
+            "bytes": 0,
+        }
+
+    def clear_quota(self: SurrealDB) -> None:
+        """
+        Stops checking writes against the quota for this connection.
+
+        :return: None
+        """
+        self._quota = None
+
+    def _check_quota(self: SurrealDB, records: int, size: int) -> None:
+        """
+        Checks that a write keeps the database within the quota, it is only counted once it succeeds.
+
+        :param records: the number of records the write adds
+        :param size: the estimated number of bytes the write adds
+
+        :return: None
+        """
+        quota = getattr(self, "_quota", None)
+        if quota is None:
+            return
+        refreshed_at = quota["refreshed_at"]
+        stale = time.monotonic() - (refreshed_at or 0) > quota["refresh_seconds"]
+        if refreshed_at is None or stale:
+            count_quota(quota, self.storage_report())
+        check_quota(quota, records, size)
+
+    def _count_quota(self: SurrealDB, records: int, size: Optional[int] = None) -> None:
+        """
+        Adds a successful write to the cached counters of the quota.
+
+        :param records: the number of records the write added
+        :param size: the estimated number of bytes the write added, estimated from the average
+            size of the records in the database if None
+
+        :return: None
+        """
+        quota = getattr(self, "_quota", None)
+        if quota is None:
+            return
+        if size is None:
+            size = records * quota["bytes"] // max(quota["records"], 1)
+        quota["records"] += records
+        quota["bytes"] += size
+
+
+def write_size(data: Any) -> Tuple[int, int]:
+    """Gets the number of records and the estimated number of bytes written with the data."""
+    return len(data) if isinstance(data, list) else 1, len(json.dumps(data))
+
+
+def count_quota(quota: dict, report: list) -> None:
+    """Resets the cached counters of a quota from a storage report."""
+    quota["records"] = sum(table["records"] for table in report)
+    quota["bytes"] = sum(table["estimated_bytes"] for table in report)
+    quota["refreshed_at"] = time.monotonic()
+
+
+def check_quota(quota: dict, records: int, size: int) -> None:
+    """Raises QuotaExceeded if a write would take the cached counters of a quota over it."""
+    records += quota["records"]
+    size += quota["bytes"]
+    if quota["max_records"] is not None and records > quota["max_records"]:
+        raise QuotaExceeded(
+            f"the write would take the database to {records} records, "
+            f"over the quota of {quota['max_records']}"
+        )
+    if quota["max_bytes"] is not None and size > quota["max_bytes"]:
+        raise QuotaExceeded(
+            f"the write would take the database to about {size} bytes, "
+            f"over the quota of {quota['max_bytes']}"
+        )
@@ -0,0 +1,101 @@
+"""This file defines the interface between python and the Rust SurrealDB library for suggesting a schema."""
+
+from __future__ import annotations
+
+import json
+import os
+from typing import TYPE_CHECKING, Any, Dict, List, Optional, Union
+
+from surrealdb.asyncio_runtime import AsyncioRuntime
+from surrealdb.rust_surrealdb import (
+    rust_field_metadata_future,
+    rust_suggest_schema_from_file,
+    rust_suggest_schema_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class SchemaMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for suggesting a schema."""
+
+    def suggest_schema(
+        self: SurrealDB, name: str, sample_size: Optional[int] = None
+    ) -> List[str]:
+        """
+        Suggests DEFINE statements for a table from a sample of its records, these can be reviewed and then run with query.
+
+        :param name: the name of the table to sample
+        :param sample_size: the maximum number of records sampled
+
+        :return: the suggested DEFINE TABLE, DEFINE FIELD and DEFINE INDEX statements
+        """
+
+        async def _suggest_schema(connection, name, sample_size):
+            return await rust_suggest_schema_future(connection, name, sample_size)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return loop_manager.loop.run_until_complete(
+                _suggest_schema(self._connection, name, sample_size)
+            )
+        except Exception as e:
+            raise self._record_error("suggest_schema", e) from None
+
+    def suggest_schema_from_file(
+        self: SurrealDB,
+        name: str,
+        path: str,
+        file_format: Optional[str] = None,
+        mapping: Optional[Dict[str, Any]] = None,
+        sample_size: Optional[int] = None,
+    ) -> List[str]:
+        """
+        Suggests DEFINE statements for a table from a file before it is imported with import_file.
+
+        :param name: the name of the table the file will be imported into
+        :param path: the path of the file
+        :param file_format: "ndjson" or "csv", taken from the file extension if not given
+        :param mapping: the field mapping that will be used for the import
+        :param sample_size: the maximum number of records sampled
+
+        :return: the suggested DEFINE TABLE, DEFINE FIELD and DEFINE INDEX statements
+        """
+        try:
+            return rust_suggest_schema_from_file(
+                name,
+                path,
+                file_format or os.path.splitext(path)[1].lstrip("."),
+                None if mapping is None else json.dumps(mapping),
+                sample_size,
+            )
+        except Exception as e:
+            raise self._record_error("suggest_schema_from_file", e) from None
+
+    def field_metadata(
+        self: SurrealDB, names: Union[str, List[str]]
+    ) -> Dict[str, Dict[str, dict]]:
+        """
+        Describes the fields defined on tables so Python display layers can format values by their
+        declared type, such as a decimal amount or an email address, without hardcoding them.
+
+        :param names: the name of a table or a list of names of tables
+
+        :return: a dict of each table to a dict of its field paths to the "type", "format",
+            "default", "assert" and "comment" of the field, each None if it is not declared
+        """
+        tables = [names] if isinstance(names, str) else names
+
+        async def _field_metadata(connection, tables):
+            return await rust_field_metadata_future(connection, tables)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return json.loads(
+                loop_manager.loop.run_until_complete(
+                    _field_metadata(self._connection, tables)
+                )
+            )
+        except Exception as e:
+            raise self._record_error("field_metadata", e) from None
@@ -0,0 +1,131 @@
+"""This file defines the interface between python and the Rust SurrealDB library for storing web sessions."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Any, Dict, Optional
+
+from surrealdb.asyncio_runtime import AsyncioRuntime
+from surrealdb.rust_surrealdb import (
+    rust_create_session_future,
+    rust_delete_session_future,
+    rust_get_session_future,
+    rust_purge_sessions_future,
+    rust_touch_session_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class SessionMixin:
+    """This class is responsible for the interface between python and the Rust SurrealDB library for sessions."""
+
+    def create_session(
+        self: SurrealDB, data: Dict[str, Any], ttl: int, table: str = "session"
+    ) -> str:
+        """
+        Creates a session that expires when it is not touched for the time to live.
+
+        :param data: the data of the session
+        :param ttl: the number of seconds the session lives for without being touched
+        :param table: the table to store the session in
+
+        :return: the ID of the session
+        """
+
+        async def _create_session(connection, table, data, ttl):
+            return await rust_create_session_future(connection, table, data, ttl)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return loop_manager.loop.run_until_complete(
+                _create_session(self._connection, table, json.dumps(data), ttl)
+            )
+        except Exception as e:
+            raise self._record_error("create_session", e) from None
+
+    def get_session(
+        self: SurrealDB, session_id: str, sliding: bool = True
+    ) -> Optional[Dict[str, Any]]:
+        """
+        Gets the data of a session that has not expired.
+
+        :param session_id: the ID of the session
+        :param sliding: whether the expiry of the session should be extended by its time to live
+
+        :return: the data of the session or None if it does not exist or has expired
+        """
+
+        async def _get_session(connection, session_id, sliding):
+            return await rust_get_session_future(connection, session_id, sliding)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return json.loads(
+                loop_manager.loop.run_until_complete(
+                    _get_session(self._connection, session_id, sliding)
+                )
+            )
+        except Exception as e:
+            raise self._record_error("get_session", e) from None
+
+    def touch_session(self: SurrealDB, session_id: str) -> bool:
+        """
+        Extends the expiry of a session by its time to live.
+
+        :param session_id: the ID of the session
+
+        :return: True if the session exists and was extended
+        """
+
+        async def _touch_session(connection, session_id):
+            return await rust_touch_session_future(connection, session_id)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return loop_manager.loop.run_until_complete(
+                _touch_session(self._connection, session_id)
+            )
+        except Exception as e:
+            raise self._record_error("touch_session", e) from None
+
+    def delete_session(self: SurrealDB, session_id: str) -> None:
+        """
+        Deletes a session.
+
+        :param session_id: the ID of the session
+
+        :return: None
+        """
+
+        async def _delete_session(connection, session_id):
+            return await rust_delete_session_future(connection, session_id)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            loop_manager.loop.run_until_complete(
+                _delete_session(self._connection, session_id)
+            )
+        except Exception as e:
+            raise self._record_error("delete_session", e) from None
+
+    def purge_sessions(self: SurrealDB, table: str = "session") -> int:
+        """
+        Deletes all the expired sessions of a table.
+
+        :param table: the table the sessions are stored in
+
+        :return: the number of sessions that were deleted
+        """
+
+        async def _purge_sessions(connection, table):
+            return await rust_purge_sessions_future(connection, table)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return loop_manager.loop.run_until_complete(
+                _purge_sessions(self._connection, table)
+            )
+        except Exception as e:
+            raise self._record_error("purge_sessions", e) from None
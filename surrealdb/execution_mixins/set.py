@@ -6,9 +6,9 @@ import json
 from typing import TYPE_CHECKING
 
 from surrealdb.asyncio_runtime import AsyncioRuntime
-from surrealdb.errors import SurrealDbError
 from surrealdb.rust_surrealdb import (
     rust_set_future,
+    rust_unset_future,
 )
 
 if TYPE_CHECKING:
@@ -36,7 +36,7 @@ class SetMixin:
             json_str = json.dumps(value)
         except json.JSONEncodeError as e:
             print(f"cannot serialize value {type(value)} to json")
-            raise SurrealDbError(e) from None
+            raise self._record_error("set", e) from None
 
         if json_str is not None:
             try:
@@ -45,4 +45,22 @@ class SetMixin:
                     _set(self._connection, key, json_str)
                 )
             except Exception as e:
-                raise SurrealDbError(e) from None
+                raise self._record_error("set", e) from None
+
+    def unset(self: SurrealDB, key: str) -> None:
+        """
+        Removes a variable that was set on the connection.
+
+        :param key: the name of the variable to remove
+
+        :return: None
+        """
+
+        async def _unset(connection, key):
+            return await rust_unset_future(connection, key)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            loop_manager.loop.run_until_complete(_unset(self._connection, key))
+        except Exception as e:
+            raise self._record_error("unset", e) from None
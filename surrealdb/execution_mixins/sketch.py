@@ -0,0 +1,88 @@
+"""This file defines the interface between python and the Rust SurrealDB library for approximate statistics."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, List, Optional
+
+from surrealdb.asyncio_runtime import AsyncioRuntime
+from surrealdb.rust_surrealdb import (
+    rust_approx_distinct_future,
+    rust_approx_percentiles_future,
+)
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+class SketchMixin:
+    """This class is responsible for approximating statistics of tables too big to compute exactly."""
+
+    def approx_distinct(
+        self: SurrealDB, name: str, field: str, page_size: Optional[int] = None
+    ) -> dict:
+        """
+        Approximates the number of distinct values of a field with a HyperLogLog, which has a
+        standard error of about 0.8%. The table is read a page at a time and only the field is
+        selected, null and missing values are not counted.
+
+        :param name: the name of the table to read
+        :param field: the dotted path of the field such as "address.city"
+        :param page_size: the number of records read per page, 1000 if None
+        :return: a dict with the "count" of values read and the approximate number of "distinct" values
+        """
+
+        async def _approx_distinct(connection, name, field, page_size):
+            return await rust_approx_distinct_future(connection, name, field, page_size)
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return json.loads(
+                loop_manager.loop.run_until_complete(
+                    _approx_distinct(self._connection, name, field, page_size)
+                )
+            )
+        except Exception as e:
+            raise self._record_error("approx_distinct", e) from None
+
+    def approx_percentiles(
+        self: SurrealDB,
+        name: str,
+        field: str,
+        percentiles: Optional[List[float]] = None,
+        page_size: Optional[int] = None,
+        compression: Optional[float] = None,
+    ) -> dict:
+        """
+        Approximates percentiles of a numeric field with a t-digest, which is most accurate towards
+        the tails. The table is read a page at a time and only the field is selected, values that
+        are not numbers are skipped.
+
+        :param name: the name of the table to read
+        :param field: the dotted path of the field such as "order.total"
+        :param percentiles: the percentiles as fractions, [0.5, 0.9, 0.99] if None
+        :param page_size: the number of records read per page, 1000 if None
+        :param compression: the compression of the t-digest, higher is more accurate, 100 if None
+        :return: a dict with the "count" of numbers read, their exact "min" and "max" and the
+            estimated "percentiles" as a dict of each fraction to its value
+        """
+        percentiles = [0.5, 0.9, 0.99] if percentiles is None else percentiles
+
+        async def _approx_percentiles(connection, name, field, percentiles):
+            return await rust_approx_percentiles_future(
+                connection, name, field, percentiles, page_size, compression
+            )
+
+        try:
+            loop_manager = AsyncioRuntime()
+            outcome = json.loads(
+                loop_manager.loop.run_until_complete(
+                    _approx_percentiles(self._connection, name, field, percentiles)
+                )
+            )
+        except Exception as e:
+            raise self._record_error("approx_percentiles", e) from None
+        outcome["percentiles"] = {
+            float(key): value for key, value in outcome["percentiles"].items()
+        }
+        return outcome
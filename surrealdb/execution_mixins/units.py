@@ -0,0 +1,141 @@
+"""This file defines the conversion between the units stored in the database and the Python types of fields."""
+
+from __future__ import annotations
+
+import copy
+from datetime import timedelta
+from decimal import Decimal
+from typing import TYPE_CHECKING, Any, Callable, Dict, List, Optional, Tuple
+
+if TYPE_CHECKING:
+    from surrealdb.connection_interface import SurrealDB
+
+
+def _to_cents(value: Any) -> int:
+    cents = Decimal(str(value)) * 100
+    if cents != cents.to_integral_value():
+        raise ValueError(f"{value} can not be stored in whole cents")
+    return int(cents)
+
+
+def _to_seconds(value: timedelta) -> float:
+    seconds = value.total_seconds()
+    return int(seconds) if seconds.is_integer() else seconds
+
+
+def _to_milliseconds(value: timedelta) -> int:
+    return round(value / timedelta(milliseconds=1))
+
+
+UNITS: Dict[str, Tuple[Callable[[Any], Any], Callable[[Any], Any]]] = {
+    "cents": (_to_cents, lambda value: Decimal(value) / 100),
+    "seconds": (_to_seconds, lambda value: timedelta(seconds=value)),
+    "milliseconds": (_to_milliseconds, lambda value: timedelta(milliseconds=value)),
+}
+"""The supported units with the functions converting a value to and from the stored unit."""
+
+
+class UnitMixin:
+    """This class is responsible for converting fields between the units stored in the database and Python types."""
+
+    def set_unit_policy(self: SurrealDB, fields: Dict[str, Dict[str, str]]) -> None:
+        """
+        Sets the unit each field is stored in for this connection. "cents" fields are stored as whole
+        cents and exposed as Decimal dollars, "seconds" and "milliseconds" fields are exposed as
+        timedelta. Fields are converted by create, insert, update and merge, in the records those
+        return and in the rows read with select and query_rows, where the table of a row is taken
+        from its id. Fields written with patch or queries and read with query are not converted.
+
+        :param fields: the unit of the fields per table such as {"order": {"total": "cents"}}
+
+        :return: None
+        """
+        for table in fields.values():
+            for field, unit in table.items():
+                if unit not in UNITS:
+                    raise ValueError(f"{field} has the unknown unit {unit}")
+        self._unit_policy = fields
+
+    def clear_unit_policy(self: SurrealDB) -> None:
+        """
+        Stops converting the units of fields for this connection.
+
+        :return: None
+        """
+        self._unit_policy = None
+
+    def _to_units(self: SurrealDB, resource: str, data: Any) -> Any:
+        """
+        Converts the fields of the data going to a resource to the units they are stored in.
+
+        :param resource: the resource the data is for such as "order" or "order:1"
+        :param data: the record or list of records
+
+        :return: a copy of the data with the fields converted
+        """
+        return self._convert_units(resource, data, 0)
+
+    def _from_units(self: SurrealDB, resource: str, data: Any) -> Any:
+        """
+        Converts the fields of the data coming from a resource from the units they are stored in.
+
+        :param resource: the resource the data is from such as "order" or "order:1"
+        :param data: the record or list of records
+
+        :return: the data with the fields converted
+        """
+        return self._convert_units(resource, data, 1)
+
+    def _rows_from_units(
+        self: SurrealDB, rows: List[Any], separator: Optional[str] = None
+    ) -> List[Any]:
+        """
+        Converts the fields of query rows from the units they are stored in with the policy of the
+        table of the id of each row, rows without an id are left as they are.
+
+        :param rows: the rows of the query
+        :param separator: the separator joining the keys of the rows if they were flattened
+
+        :return: the rows with the fields converted
+        """
+        if not getattr(self, "_unit_policy", None):
+            return rows
+        return [
+            self._convert_units(row["id"], row, 1, separator)
+            if isinstance(row, dict) and isinstance(row.get("id"), str)
+            else row
+            for row in rows
+        ]
+
+    def _convert_units(
+        self: SurrealDB,
+        resource: str,
+        data: Any,
+        direction: int,
+        separator: Optional[str] = None,
+    ) -> Any:
+        """
+        Converts the fields of a record or list of records with the policy of the table of the resource.
+
+        :param resource: the resource the data is for or from
+        :param data: the record or list of records
+        :param direction: 0 to convert to the stored units and 1 to convert from them
+        :param separator: the separator joining the keys of the records if they were flattened
+
+        :return: a copy of the data with the fields converted
+        """
+        policy = getattr(self, "_unit_policy", None)
+        fields = policy.get(resource.split(":")[0]) if policy else None
+        if not fields:
+            return data
+        data = copy.deepcopy(data)
+        for record in data if isinstance(data, list) else [data]:
+            for field, unit in fields.items():
+                flat = separator and field.replace(".", separator)
+                *parents, key = [flat] if flat in record else field.split(".")
+                parent = record
+                for name in parents:
+                    parent = parent.get(name) if isinstance(parent, dict) else None
+                if isinstance(parent, dict) and parent.get(key) is not None:
+                    parent[key] = UNITS[unit][direction](parent[key])
+        return data
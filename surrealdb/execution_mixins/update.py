@@ -3,14 +3,15 @@
 from __future__ import annotations
 
 import json
-from typing import TYPE_CHECKING, List, Union
+from typing import TYPE_CHECKING, List, Optional, Tuple, Union
 
 from surrealdb.asyncio_runtime import AsyncioRuntime
-from surrealdb.errors import SurrealDbError
 from surrealdb.rust_surrealdb import (
     rust_merge_future,
+    rust_merge_three_way_future,
     rust_patch_future,
     rust_update_future,
+    rust_update_where_future,
 )
 
 if TYPE_CHECKING:
@@ -32,15 +33,25 @@ class UpdateMixin:
         async def _update(connection, resource, data):
             return await rust_update_future(connection, resource, data)
 
+        with self._recording("update"):
+            data = self._to_units(resource, data)
+            size = len(json.dumps(data))
+            self._check_quota(0, size)
         try:
             loop_manager = AsyncioRuntime()
-            return json.loads(
-                loop_manager.loop.run_until_complete(
-                    _update(self._connection, resource, json.dumps(data))
+            outcome = loop_manager.loop.run_until_complete(
+                _update(
+                    self._connection,
+                    resource,
+                    self._encrypt(resource, json.dumps(data)),
                 )
             )
+            self._count_quota(0, size)
+            return self._from_units(
+                resource, json.loads(self._decrypt(resource, outcome))
+            )
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("update", e) from None
 
     def merge(self: SurrealDB, resource: str, data: dict) -> Union[List[dict], dict]:
         """
@@ -54,34 +65,123 @@ class UpdateMixin:
         async def _merge(connection, resource, data):
             return await rust_merge_future(connection, resource, data)
 
+        with self._recording("merge"):
+            data = self._to_units(resource, data)
+            size = len(json.dumps(data))
+            self._check_quota(0, size)
         try:
             loop_manager = AsyncioRuntime()
-            return json.loads(
-                loop_manager.loop.run_until_complete(
-                    _merge(self._connection, resource, json.dumps(data))
+            outcome = loop_manager.loop.run_until_complete(
+                _merge(
+                    self._connection,
+                    resource,
+                    self._encrypt(resource, json.dumps(data)),
                 )
             )
+            self._count_quota(0, size)
+            return self._from_units(
+                resource, json.loads(self._decrypt(resource, outcome))
+            )
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("merge", e) from None
 
-    def patch(self: SurrealDB, resource: str, data: dict) -> Union[List[dict], dict]:
+    def patch(
+        self: SurrealDB, resource: str, data: List[dict], return_diff: bool = False
+    ) -> Union[List[dict], dict]:
         """
         Patches the given resource with the given data.
 
         :param resource: the resource to update
-        :param data: the data to patch the resource with
+        :param data: the RFC 6902 patch operations to apply to the resource
+        :param return_diff: if True the diff of each patched record is returned instead of the record
         :return: the updated resource such as an individual row or a list of rows
         """
 
-        async def _patch(connection, resource, data):
-            return await rust_patch_future(connection, resource, data)
+        async def _patch(connection, resource, data, return_diff):
+            return await rust_patch_future(connection, resource, data, return_diff)
 
         try:
             loop_manager = AsyncioRuntime()
             return json.loads(
                 loop_manager.loop.run_until_complete(
-                    _patch(self._connection, resource, json.dumps(data))
+                    _patch(
+                        self._connection, resource, json.dumps(data), return_diff
+                    )
+                )
+            )
+        except Exception as e:
+            raise self._record_error("patch", e) from None
+
+    def update_where(
+        self: SurrealDB,
+        name: str,
+        where: str,
+        data: Union[dict, List[dict]],
+        batch_size: Optional[int] = None,
+    ) -> int:
+        """
+        Updates all the records in a table that match a condition on the server in batches.
+
+        :param name: the name of the table to update
+        :param where: the SurrealQL condition the records have to match such as "age > 18"
+        :param data: a dict is merged into the records, a list is applied as patch operations
+        :param batch_size: the maximum number of records updated per statement
+        :return: the number of records that were updated
+        """
+
+        async def _update_where(connection, name, where, data, batch_size):
+            return await rust_update_where_future(
+                connection, name, where, data, batch_size
+            )
+
+        try:
+            loop_manager = AsyncioRuntime()
+            return loop_manager.loop.run_until_complete(
+                _update_where(
+                    self._connection, name, where, json.dumps(data), batch_size
+                )
+            )
+        except Exception as e:
+            raise self._record_error("update_where", e) from None
+
+    def merge_three_way(
+        self: SurrealDB,
+        resource: str,
+        base: dict,
+        ours: dict,
+        policy: str = "fail",
+    ) -> Tuple[dict, List[str]]:
+        """
+        Merges an edit of a record with the changes other clients made since the edit started.
+        Fields changed by only one side are kept and fields changed differently by both sides are
+        resolved with the conflict policy. The merge is only written if the record did not change
+        again while it was being merged.
+
+        :param resource: the ID of the record to merge into
+        :param base: the record as it was when the edit started
+        :param ours: the record with the edit applied
+        :param policy: "ours" or "theirs" to pick a side for conflicts, "fail" to raise an error
+        :return: the merged record and the JSON pointers of the conflicting fields
+        """
+
+        async def _merge_three_way(connection, resource, base, ours, policy):
+            return await rust_merge_three_way_future(
+                connection, resource, base, ours, policy
+            )
+
+        try:
+            loop_manager = AsyncioRuntime()
+            outcome = json.loads(
+                loop_manager.loop.run_until_complete(
+                    _merge_three_way(
+                        self._connection,
+                        resource,
+                        json.dumps(base),
+                        json.dumps(ours),
+                        policy,
+                    )
                 )
             )
+            return outcome["record"], outcome["conflicts"]
         except Exception as e:
-            raise SurrealDbError(e) from None
+            raise self._record_error("merge_three_way", e) from None
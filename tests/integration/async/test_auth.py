@@ -29,7 +29,17 @@ class TestAsyncAuth(TestCase):
 
     def test_login_success(self):
         outcome = asyncio.run(self.login("root", "root"))
-        self.assertEqual(None, outcome)
+        self.assertEqual(3, len(outcome.split(".")))
+
+    def test_authenticate_and_invalidate(self):
+        async def authenticate():
+            token = await self.login("root", "root")
+            await self.connection.invalidate()
+            await self.connection.authenticate(token)
+            outcome = await self.connection.auth_info()
+            self.assertEqual("root", outcome["token"]["ID"])
+
+        asyncio.run(authenticate())
 
     def test_login_wrong_password(self):
         with self.assertRaises(RuntimeError) as context:
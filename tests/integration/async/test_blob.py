@@ -0,0 +1,66 @@
+"""
+Tests the content-addressable blob store of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from surrealdb.errors import SurrealDbError
+from tests.integration.url import Url
+
+HELLO_HASH = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+
+
+class TestAsyncBlob(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+            await self.connection.query("CREATE user:tobie;")
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        async def teardown_queries():
+            for table in ["user", "blob", "attachment"]:
+                await self.connection.query(f"DELETE {table};")
+
+        asyncio.run(teardown_queries())
+
+    def test_store_and_get_blob(self):
+        async def store_and_get_blob():
+            self.assertEqual(HELLO_HASH, await self.connection.store_blob(b"hello"))
+            self.assertEqual(HELLO_HASH, await self.connection.store_blob(b"hello"))
+            self.assertEqual(b"hello", await self.connection.get_blob(HELLO_HASH))
+            self.assertIsNone(await self.connection.get_blob("0" * 64))
+
+        asyncio.run(store_and_get_blob())
+
+    def test_link_and_gc_blobs(self):
+        async def link_and_gc_blobs():
+            linked = await self.connection.store_blob(b"linked")
+            unlinked = await self.connection.store_blob(b"unlinked")
+            await self.connection.link_blob("user:tobie", linked)
+            await self.connection.link_blob("user:tobie", unlinked)
+            await self.connection.unlink_blob("user:tobie", unlinked)
+            with self.assertRaises(SurrealDbError):
+                await self.connection.link_blob("user:tobie", "0" * 64)
+
+            self.assertEqual(0, await self.connection.gc_blobs())
+            self.assertEqual(1, await self.connection.gc_blobs(min_age=0))
+            self.assertIsNone(await self.connection.get_blob(unlinked))
+            self.assertEqual(b"linked", await self.connection.get_blob(linked))
+
+        asyncio.run(link_and_gc_blobs())
+
+
+if __name__ == "__main__":
+    main()
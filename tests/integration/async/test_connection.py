@@ -0,0 +1,71 @@
+"""
+Tests closing the connections of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from tests.integration.url import Url
+
+
+class TestAsyncConnection(TestCase):
+    def tearDown(self):
+        AsyncSurrealDB.close_all()
+
+    def test_close(self):
+        connection = AsyncSurrealDB(Url().url, keep_connection=True)
+        asyncio.run(connection.connect())
+        self.assertIs(
+            connection,
+            AsyncSurrealDB(Url().url, existing_connection_id=connection.id),
+        )
+
+        connection.close()
+        self.assertIsNone(connection._connection)
+        self.assertNotIn(connection.id, AsyncSurrealDB.instances)
+        connection.close()
+
+    def test_context_manager(self):
+        async def context_manager():
+            async with AsyncSurrealDB(Url().url, keep_connection=True) as connection:
+                self.assertIn(connection.id, AsyncSurrealDB.instances)
+                await connection.signin({"username": "root", "password": "root"})
+                self.assertEqual(
+                    [], await connection.query("SELECT * FROM person:nobody;")
+                )
+            return connection
+
+        connection = asyncio.run(context_manager())
+        self.assertIsNone(connection._connection)
+        self.assertNotIn(connection.id, AsyncSurrealDB.instances)
+
+    def test_context_manager_main_connection(self):
+        async def context_manager():
+            async with AsyncSurrealDB(Url().url, main_connection=True) as connection:
+                self.assertIs(connection, AsyncSurrealDB.main_connection)
+                self.assertIs(connection, AsyncSurrealDB(main_connection=True))
+
+        asyncio.run(context_manager())
+        self.assertIsNone(AsyncSurrealDB.main_connection)
+
+    def test_close_all(self):
+        kept = [AsyncSurrealDB(Url().url, keep_connection=True) for _ in range(2)]
+        main_connection = AsyncSurrealDB(Url().url, main_connection=True)
+
+        async def connect():
+            for connection in kept + [main_connection]:
+                await connection.connect()
+
+        asyncio.run(connect())
+        AsyncSurrealDB.close_all()
+        AsyncSurrealDB.close_all()
+        for connection in kept:
+            self.assertNotIn(connection.id, AsyncSurrealDB.instances)
+            self.assertIsNone(connection._connection)
+        self.assertIsNone(main_connection._connection)
+        self.assertIsNone(AsyncSurrealDB.main_connection)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,53 @@
+"""
+Tests the case-insensitive unique constraints of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from surrealdb.errors import SurrealDbError
+from tests.integration.url import Url
+
+
+class TestAsyncConstraint(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        asyncio.run(self.connection.query("REMOVE TABLE member;"))
+
+    def test_ci_unique(self):
+        async def ci_unique():
+            await self.connection.define_ci_unique("member", "email")
+            await self.connection.create("member:1", {"email": "Tobie@SurrealDB.com"})
+            with self.assertRaises(SurrealDbError):
+                await self.connection.create(
+                    "member:2", {"email": " tobie@surrealdb.com"}
+                )
+
+            found = await self.connection.find_by_ci(
+                "member", "email", "TOBIE@surrealdb.com "
+            )
+            self.assertEqual(["member:1"], [record["id"] for record in found])
+            self.assertEqual("Tobie@SurrealDB.com", found[0]["email"])
+            self.assertEqual(
+                [], await self.connection.find_by_ci("member", "email", "jaime")
+            )
+
+        asyncio.run(ci_unique())
+
+
+if __name__ == "__main__":
+    main()
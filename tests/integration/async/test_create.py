@@ -7,6 +7,7 @@ from typing import List
 from unittest import TestCase, main
 
 from surrealdb import AsyncSurrealDB
+from surrealdb.errors import InsertError
 from tests.integration.url import Url
 
 
@@ -120,6 +121,49 @@ class TestAsyncCreate(TestCase):
 
         asyncio.run(create_person_with_tags())
 
+    def test_insert(self):
+        self.queries = ["DELETE user;"]
+
+        async def insert():
+            outcome = await self.connection.insert(
+                "user",
+                [{"id": "tobie", "name": "Tobie"}, {"id": "jaime", "name": "Jaime"}],
+                chunk_size=1,
+            )
+            self.assertEqual(["user:tobie", "user:jaime"], outcome)
+
+            outcome = await self.connection.query("SELECT * FROM user;")
+            self.assertEqual(
+                [
+                    {"id": "user:jaime", "name": "Jaime"},
+                    {"id": "user:tobie", "name": "Tobie"},
+                ],
+                outcome,
+            )
+
+        asyncio.run(insert())
+
+    def test_insert_error_keeps_inserted_ids(self):
+        self.queries = ["REMOVE TABLE user;"]
+
+        async def insert():
+            await self.connection.query(
+                "DEFINE TABLE user SCHEMAFULL; DEFINE FIELD name ON user TYPE string;"
+            )
+            with self.assertRaises(InsertError) as context:
+                await self.connection.insert(
+                    "user",
+                    [{"id": "tobie", "name": "Tobie"}, {"id": "jaime", "name": 1}],
+                    chunk_size=1,
+                )
+            self.assertEqual(["user:tobie"], context.exception.inserted)
+            self.assertEqual("insert", self.connection.error_history()[-1]["route"])
+
+            outcome = await self.connection.query("SELECT * FROM user;")
+            self.assertEqual([{"id": "user:tobie", "name": "Tobie"}], outcome)
+
+        asyncio.run(insert())
+
 
 if __name__ == "__main__":
     main()
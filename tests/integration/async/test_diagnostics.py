@@ -0,0 +1,28 @@
+"""
+Tests the diagnostics report from async code.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import diagnostics
+from tests.integration.url import Url
+
+
+class TestAsyncDiagnostics(TestCase):
+    def test_diagnostics(self):
+        report = asyncio.run(diagnostics(Url().url))
+
+        self.assertTrue(report["connection"]["ok"])
+        self.assertTrue(report["serialization"]["ok"])
+        self.assertEqual("1.25", report["serialization"]["types"]["decimal"]["received"])
+
+    def test_diagnostics_unreachable(self):
+        report = asyncio.run(diagnostics("ws://localhost:1/database/namespace"))
+
+        self.assertFalse(report["ok"])
+        self.assertIn("error", report["connection"])
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,61 @@
+"""
+Tests the client-side field encryption of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from typing import List
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from tests.integration.url import Url
+
+
+class TestAsyncEncryption(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+        self.queries: List[str] = []
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        self.connection.clear_encryption_policy()
+
+        async def teardown_queries():
+            for query in self.queries:
+                await self.connection.query(query)
+
+        asyncio.run(teardown_queries())
+
+    def test_create_and_update_encrypted(self):
+        self.queries = ["DELETE person;"]
+        self.connection.set_encryption_policy(bytes(32), {"person": ["ssn"]})
+
+        async def create_and_update():
+            outcome = await self.connection.create(
+                "person:tobie", {"name": "Tobie", "ssn": "123"}
+            )
+            self.assertEqual(
+                {"id": "person:tobie", "name": "Tobie", "ssn": "123"}, outcome
+            )
+
+            outcome = await self.connection.merge("person:tobie", {"ssn": "456"})
+            self.assertEqual("456", outcome["ssn"])
+
+            stored = await self.connection.query("SELECT * FROM person;")
+            self.assertEqual("Tobie", stored[0]["name"])
+            self.assertTrue(stored[0]["ssn"].startswith("enc:v2:"))
+
+        asyncio.run(create_and_update())
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,89 @@
+"""
+Tests the error history of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from datetime import datetime
+from decimal import Decimal
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from surrealdb.errors import InjectionSuspected, QuotaExceeded, SurrealDbError
+from tests.integration.url import Url
+
+
+class TestAsyncErrorHistory(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        self.connection.clear_error_history()
+        self.connection.clear_injection_guard()
+        self.connection.clear_quota()
+        self.connection.clear_unit_policy()
+
+    def test_error_history(self):
+        self.connection.set_error_history_size(2)
+
+        async def fail():
+            for query in ["SELEC * FROM person;", "CREAT person;", "UPDAT person;"]:
+                with self.assertRaises(SurrealDbError):
+                    await self.connection.query(query)
+            with self.assertRaises(SurrealDbError):
+                await self.connection.query_rows("DELET person;")
+
+        asyncio.run(fail())
+
+        errors = self.connection.error_history()
+        self.assertEqual(["query", "query_rows"], [error["route"] for error in errors])
+        self.assertEqual("ParseError", errors[0]["code"])
+        self.assertIn("UPDAT", errors[0]["message"])
+        self.assertIsInstance(errors[0]["timestamp"], datetime)
+        self.assertEqual(errors[1:], self.connection.error_history(1))
+
+        self.connection.clear_error_history()
+        self.assertEqual([], self.connection.error_history())
+
+    def test_error_history_of_checks(self):
+        async def fail():
+            self.connection.set_injection_guard()
+            with self.assertRaises(InjectionSuspected):
+                await self.connection.query("SELECT * FROM person WHERE 1 = 1;")
+
+            self.connection.set_unit_policy({"order": {"total": "cents"}})
+            with self.assertRaises(ValueError):
+                await self.connection.create("order:1", {"total": Decimal("0.001")})
+
+            records = sum(
+                table["records"] for table in await self.connection.storage_report()
+            )
+            self.connection.set_quota(max_records=records)
+            with self.assertRaises(QuotaExceeded):
+                await self.connection.insert("person", [{"name": "Tobie"}])
+
+        asyncio.run(fail())
+
+        errors = self.connection.error_history()
+        self.assertEqual(
+            [
+                ("query", "InjectionSuspected"),
+                ("create", "ValueError"),
+                ("insert", "QuotaExceeded"),
+            ],
+            [(error["route"], error["code"]) for error in errors],
+        )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,57 @@
+"""
+Tests the injection guard of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from surrealdb.errors import InjectionSuspected
+from tests.integration.url import Url
+
+
+class TestAsyncGuard(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        self.connection.clear_injection_guard()
+        asyncio.run(self.connection.query("DELETE person;"))
+
+    def test_injection_guard(self):
+        allowed = "SELECT * FROM person WHERE status = 'active';"
+        self.connection.set_injection_guard(allow=[allowed])
+
+        async def guarded():
+            with self.assertRaises(InjectionSuspected):
+                await self.connection.query(
+                    "SELECT * FROM person WHERE name = '' OR 1 = 1;"
+                )
+            with self.assertRaises(InjectionSuspected):
+                await self.connection.query_rows("DELETE person; -- ")
+
+            self.assertEqual([], await self.connection.query(allowed))
+            self.assertEqual(
+                [],
+                await self.connection.query_rows(
+                    "SELECT * FROM person WHERE name = $name;",
+                    {"name": "' OR 1 = 1"},
+                ),
+            )
+
+        asyncio.run(guarded())
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,85 @@
+"""
+Tests reading tables and records as they were in the past with the AsyncSurrealDB class.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from surrealdb.errors import SurrealDbError
+from tests.integration.url import Url
+
+
+class TestAsyncHistory(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+            await self.connection.query("DEFINE TABLE ledger CHANGEFEED 1h;")
+            await self.connection.query("CREATE ledger:tobie SET balance = 10;")
+            await self.connection.query("UPDATE ledger:tobie SET balance = 20;")
+            await self.connection.query("DELETE ledger:tobie;")
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        asyncio.run(self.connection.query("REMOVE TABLE ledger;"))
+
+    def test_read_as_of(self):
+        async def read_as_of():
+            feed = await self.connection.query("SHOW CHANGES FOR TABLE ledger SINCE 0;")
+            versionstamps = [change_set["versionstamp"] for change_set in feed]
+
+            self.assertEqual(
+                [{"id": "ledger:tobie", "balance": 10}],
+                await self.connection.read_as_of("ledger", versionstamps[0]),
+            )
+            self.assertEqual(
+                [{"id": "ledger:tobie", "balance": 20}],
+                await self.connection.read_as_of(
+                    "ledger", versionstamp=versionstamps[1]
+                ),
+            )
+            self.assertEqual(
+                [], await self.connection.read_as_of("ledger", versionstamps[2])
+            )
+
+            with self.assertRaises(SurrealDbError):
+                await self.connection.read_as_of("ledger")
+
+        asyncio.run(read_as_of())
+
+    def test_record_history(self):
+        async def record_history():
+            history = await self.connection.record_history("ledger:tobie")
+            self.assertEqual(
+                ["create", "update", "delete"], [entry["action"] for entry in history]
+            )
+            self.assertEqual(
+                {"id": "ledger:tobie", "balance": 10}, history[0]["state"]
+            )
+            self.assertEqual(
+                [{"op": "replace", "path": "/balance", "value": 20}],
+                history[1]["diff"],
+            )
+            self.assertIsNone(history[2]["state"])
+
+            self.assertEqual(
+                history[2:],
+                await self.connection.record_history(
+                    "ledger:tobie", since=history[2]["versionstamp"]
+                ),
+            )
+
+        asyncio.run(record_history())
+
+
+if __name__ == "__main__":
+    main()
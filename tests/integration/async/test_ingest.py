@@ -0,0 +1,120 @@
+"""
+Tests importing files and materializing queries into tables with the AsyncSurrealDB class.
+"""
+
+import asyncio
+import os
+from tempfile import TemporaryDirectory
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from surrealdb.errors import SurrealDbError
+from tests.integration.url import Url
+
+
+class TestAsyncIngest(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+
+        asyncio.run(login())
+        self.directory = TemporaryDirectory()
+
+    def tearDown(self):
+        self.directory.cleanup()
+
+        async def teardown_queries():
+            await self.connection.query("DELETE user;")
+            await self.connection.query("REMOVE TABLE adult;")
+
+        asyncio.run(teardown_queries())
+
+    def write_file(self, name: str, content: str) -> str:
+        path = os.path.join(self.directory.name, name)
+        with open(path, "w") as file:
+            file.write(content)
+        return path
+
+    def test_import_file(self):
+        csv = self.write_file(
+            "users.csv", "user_id,first_name,age\ntobie,Tobie,32\njaime,Jaime,\n"
+        )
+        ndjson = self.write_file(
+            "users.ndjson", '{"id": "dave", "name": {"first": "Dave"}}\n'
+        )
+
+        async def import_file():
+            mapping = {
+                "id": "user_id",
+                "name.first": "first_name",
+                "age": {"type": "int"},
+            }
+            self.assertEqual(
+                2, await self.connection.import_file("user", csv, "csv", mapping, 1)
+            )
+            self.assertEqual(1, await self.connection.import_file("user", ndjson))
+            self.assertEqual(
+                [
+                    {"id": "user:dave", "name": {"first": "Dave"}},
+                    {"id": "user:jaime", "name": {"first": "Jaime"}},
+                    {"age": 32, "id": "user:tobie", "name": {"first": "Tobie"}},
+                ],
+                await self.connection.query("SELECT * FROM user ORDER BY id;"),
+            )
+
+        asyncio.run(import_file())
+
+    def test_materialize(self):
+        query = "SELECT id, name, friend FROM user WHERE age > $age;"
+
+        async def materialize():
+            await self.connection.query(
+                "CREATE user:1 SET name = 'Tobie', age = 32, friend = user:2;"
+            )
+            await self.connection.query("CREATE user:2 SET name = 'Jaime', age = 20;")
+
+            self.assertEqual(
+                1,
+                await self.connection.materialize(
+                    query, "adult", bindings={"age": 30}
+                ),
+            )
+            with self.assertRaises(SurrealDbError):
+                await self.connection.materialize(
+                    query, "adult", "create", {"age": 30}
+                )
+            self.assertEqual(
+                2,
+                await self.connection.materialize(
+                    query, "adult", "replace", {"age": 0}
+                ),
+            )
+            self.assertEqual(
+                2,
+                await self.connection.materialize(
+                    "SELECT name FROM user;", "adult", "append", keep_ids=False
+                ),
+            )
+            self.assertEqual(
+                [{"id": "adult:1", "link": True}],
+                await self.connection.query(
+                    "SELECT id, type::is::record(friend) AS link FROM adult:1;"
+                ),
+            )
+            self.assertEqual(
+                4, len(await self.connection.query("SELECT * FROM adult;"))
+            )
+
+        asyncio.run(materialize())
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,58 @@
+"""
+Tests the limit policy of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from typing import List
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from tests.integration.url import Url
+
+
+class TestAsyncLimit(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+        self.queries: List[str] = []
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        self.connection.clear_limit_policy()
+
+        async def teardown_queries():
+            for query in self.queries:
+                await self.connection.query(query)
+
+        asyncio.run(teardown_queries())
+
+    def test_limit_policy(self):
+        self.queries = ["DELETE person;"]
+
+        async def select_with_policy():
+            await self.connection.query(
+                "CREATE person:1; CREATE person:2; CREATE person:3;"
+            )
+            self.connection.set_limit_policy(2)
+
+            people = await self.connection.query("SELECT * FROM person;")
+            self.assertEqual(2, len(people))
+            people = await self.connection.query(
+                "SELECT * FROM person;", unlimited=True
+            )
+            self.assertEqual(3, len(people))
+
+        asyncio.run(select_with_policy())
+
+
+if __name__ == "__main__":
+    main()
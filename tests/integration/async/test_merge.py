@@ -7,6 +7,7 @@ from typing import List
 from unittest import TestCase, main
 
 from surrealdb import AsyncSurrealDB
+from surrealdb.errors import SurrealDbError
 from tests.integration.url import Url
 
 
@@ -56,6 +57,37 @@ class TestAsyncHttpMerge(TestCase):
 
         asyncio.run(merge_active())
 
+    def test_merge_three_way(self):
+        self.queries = ["DELETE user;"]
+        base = {"id": "user:tobie", "name": "Tobie", "age": 30, "tags": ["a"]}
+
+        async def merge_three_way():
+            await self.connection.query(
+                "CREATE user:tobie SET name = 'Tobie', age = 30, tags = ['a'];"
+            )
+            await self.connection.query("UPDATE user:tobie SET age = 31, tags = ['b'];")
+
+            record, conflicts = await self.connection.merge_three_way(
+                "user:tobie",
+                base,
+                {"name": "Tobias", "age": 30, "tags": ["c"]},
+                policy="theirs",
+            )
+            self.assertEqual(
+                {"id": "user:tobie", "name": "Tobias", "age": 31, "tags": ["b"]},
+                record,
+            )
+            self.assertEqual(["/tags"], conflicts)
+
+            with self.assertRaises(SurrealDbError):
+                await self.connection.merge_three_way(
+                    "user:tobie", {"age": 30}, {"age": 40}
+                )
+            stored = await self.connection.query("SELECT * FROM user:tobie;")
+            self.assertEqual(31, stored[0]["age"])
+
+        asyncio.run(merge_three_way())
+
 
 if __name__ == "__main__":
     main()
@@ -0,0 +1,94 @@
+"""
+Tests the pagination and partitioning of tables with the AsyncSurrealDB class.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from tests.integration.url import Url
+
+
+class TestAsyncPagination(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+            await self.connection.insert(
+                "person", [{"id": i, "name": f"person {i}"} for i in range(1, 6)]
+            )
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        asyncio.run(self.connection.query("DELETE person;"))
+
+    def test_paginate(self):
+        async def paginate():
+            rows, token = await self.connection.paginate("person", 2)
+            self.assertEqual(["person:1", "person:2"], [row["id"] for row in rows])
+            self.assertIsInstance(token, str)
+
+            rows, token = await self.connection.paginate("person", 2, token)
+            self.assertEqual(["person:3", "person:4"], [row["id"] for row in rows])
+
+            rows, token = await self.connection.paginate(
+                "person", page_size=2, token=token
+            )
+            self.assertEqual([{"id": "person:5", "name": "person 5"}], rows)
+            self.assertIsNone(token)
+
+        asyncio.run(paginate())
+
+    def test_scan(self):
+        async def scan():
+            return [row["name"] async for row in self.connection.scan("person", 2)]
+
+        self.assertEqual([f"person {i}" for i in range(1, 6)], asyncio.run(scan()))
+
+    def test_partition_table(self):
+        async def partition():
+            partitions = await self.connection.partition_table("person", 2)
+            self.assertEqual(2, len(partitions))
+            self.assertEqual(5, sum(partition["count"] for partition in partitions))
+
+            ids = []
+            for partition in partitions:
+                ids += [
+                    row["id"]
+                    async for row in self.connection.scan(partition["resource"], 2)
+                ]
+            self.assertEqual([f"person:{i}" for i in range(1, 6)], ids)
+
+        asyncio.run(partition())
+
+    def test_process_partitions(self):
+        names = []
+        progress = []
+
+        async def handler(row):
+            names.append(row["name"])
+
+        processed = asyncio.run(
+            self.connection.process_partitions(
+                "person",
+                handler,
+                partitions=2,
+                page_size=2,
+                progress=lambda done, total: progress.append((done, total)),
+            )
+        )
+        self.assertEqual(5, processed)
+        self.assertEqual([f"person {i}" for i in range(1, 6)], sorted(names))
+        self.assertEqual((5, 5), progress[-1])
+
+
+if __name__ == "__main__":
+    main()
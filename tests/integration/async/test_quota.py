@@ -0,0 +1,75 @@
+"""
+Tests the client-side quotas of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from typing import List
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from surrealdb.errors import QuotaExceeded, SurrealDbError
+from tests.integration.url import Url
+
+
+class TestAsyncQuota(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+        self.queries: List[str] = []
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        self.connection.clear_quota()
+
+        async def teardown_queries():
+            for query in self.queries:
+                await self.connection.query(query)
+
+        asyncio.run(teardown_queries())
+
+    def test_max_records(self):
+        self.queries = ["DELETE person;"]
+
+        async def create_over_quota():
+            report = await self.connection.storage_report()
+            records = sum(table["records"] for table in report)
+            self.connection.set_quota(max_records=records + 1)
+
+            await self.connection.create("person:tobie", {"name": "Tobie"})
+            with self.assertRaises(QuotaExceeded):
+                await self.connection.create("person:jaime", {"name": "Jaime"})
+
+            stored = await self.connection.query("SELECT * FROM person;")
+            self.assertEqual(["Tobie"], [row["name"] for row in stored])
+
+        asyncio.run(create_over_quota())
+
+    def test_failed_writes_are_not_counted(self):
+        self.queries = ["DELETE person;"]
+
+        async def create_twice():
+            await self.connection.query("CREATE person:tobie SET name = 'Tobie';")
+            report = await self.connection.storage_report()
+            records = sum(table["records"] for table in report)
+            self.connection.set_quota(max_records=records + 1)
+
+            with self.assertRaises(SurrealDbError):
+                await self.connection.create("person:tobie", {"name": "Tobie"})
+            await self.connection.create("person:jaime", {"name": "Jaime"})
+            with self.assertRaises(QuotaExceeded):
+                await self.connection.create("person:dave", {"name": "Dave"})
+
+        asyncio.run(create_twice())
+
+
+if __name__ == "__main__":
+    main()
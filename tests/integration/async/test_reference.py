@@ -0,0 +1,123 @@
+"""
+Tests fetching and checking the record links and graph edges with the AsyncSurrealDB class.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from tests.integration.url import Url
+
+
+class TestAsyncReference(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+            await self.connection.query("CREATE user:tobie SET name = 'Tobie';")
+            await self.connection.query("CREATE user:jaime SET name = 'Jaime';")
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        async def teardown_queries():
+            for table in ["user", "post", "wrote"]:
+                await self.connection.query(f"DELETE {table};")
+
+        asyncio.run(teardown_queries())
+
+    def test_fetch_references(self):
+        rows = [
+            {"id": "post:1", "author": "user:tobie", "editors": ["user:jaime"]},
+            {"id": "post:2", "author": "user:gone", "editors": []},
+        ]
+        fetched = asyncio.run(
+            self.connection.fetch_references(rows, ["author", "editors"])
+        )
+        self.assertEqual(
+            [
+                {
+                    "id": "post:1",
+                    "author": {"id": "user:tobie", "name": "Tobie"},
+                    "editors": [{"id": "user:jaime", "name": "Jaime"}],
+                },
+                {"id": "post:2", "author": "user:gone", "editors": []},
+            ],
+            fetched,
+        )
+        self.assertEqual("user:tobie", rows[0]["author"])
+
+    def test_sideload_references(self):
+        rows = [{"id": "post:1", "author": "user:tobie", "editors": ["user:jaime"]}]
+        self.assertEqual(
+            {
+                "user:tobie": {"id": "user:tobie", "name": "Tobie"},
+                "user:jaime": {"id": "user:jaime", "name": "Jaime"},
+            },
+            asyncio.run(
+                self.connection.sideload_references(rows, ["author", "editors"])
+            ),
+        )
+
+    def test_check_references(self):
+        async def check_references():
+            await self.connection.query("CREATE post:1 SET author = user:tobie;")
+            await self.connection.query("CREATE post:2 SET author = user:gone;")
+            await self.connection.query(
+                "CREATE post:3 SET author = <string> 'user:gone';"
+            )
+
+            dangling, token = await self.connection.check_references(
+                "post", ["author"], 2
+            )
+            self.assertEqual(
+                [{"id": "post:2", "field": "author", "missing": ["user:gone"]}],
+                dangling,
+            )
+            dangling, token = await self.connection.check_references(
+                "post", ["author"], 2, token
+            )
+            self.assertEqual([], dangling)
+            self.assertIsNone(token)
+
+            await self.connection.check_references("post", ["author"], fix="delete")
+            self.assertEqual(
+                [{"id": "post:1"}, {"id": "post:3"}],
+                await self.connection.query("SELECT id FROM post;"),
+            )
+
+        asyncio.run(check_references())
+
+    def test_clean_orphaned_edges(self):
+        progress = []
+
+        async def clean_orphaned_edges():
+            await self.connection.query("CREATE post:1;")
+            await self.connection.query("RELATE user:tobie->wrote:1->post:1;")
+            await self.connection.query("RELATE user:tobie->wrote:2->post:2;")
+
+            orphans = await self.connection.clean_orphaned_edges(
+                "wrote",
+                1,
+                True,
+                lambda checked, found: progress.append((checked, found)),
+            )
+            self.assertEqual([{"id": "wrote:2", "missing": ["post:2"]}], orphans)
+            self.assertEqual(
+                [{"id": "wrote:1"}],
+                await self.connection.query("SELECT id FROM wrote;"),
+            )
+
+        asyncio.run(clean_orphaned_edges())
+        self.assertEqual((2, 1), progress[-1])
+
+
+if __name__ == "__main__":
+    main()
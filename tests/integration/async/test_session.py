@@ -0,0 +1,76 @@
+"""
+Tests the session store of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from tests.integration.url import Url
+
+
+class TestAsyncSession(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        async def teardown_queries():
+            await self.connection.query("DELETE session;")
+            await self.connection.query("DELETE web_session;")
+
+        asyncio.run(teardown_queries())
+
+    def test_session_lifecycle(self):
+        async def session_lifecycle():
+            session_id = await self.connection.create_session({"user": "tobie"}, 60)
+            self.assertTrue(session_id.startswith("session:"))
+
+            self.assertEqual(
+                {"user": "tobie"}, await self.connection.get_session(session_id)
+            )
+            self.assertEqual(
+                {"user": "tobie"},
+                await self.connection.get_session(session_id, sliding=False),
+            )
+            self.assertTrue(await self.connection.touch_session(session_id))
+
+            await self.connection.delete_session(session_id)
+            self.assertIsNone(await self.connection.get_session(session_id))
+            self.assertFalse(await self.connection.touch_session(session_id))
+
+        asyncio.run(session_lifecycle())
+
+    def test_expired_sessions(self):
+        async def expired_sessions():
+            expired = await self.connection.create_session(
+                {"user": "tobie"}, 0, "web_session"
+            )
+            await self.connection.create_session(
+                {"user": "jaime"}, 0, table="web_session"
+            )
+            live = await self.connection.create_session(
+                {"user": "dave"}, 60, "web_session"
+            )
+            self.assertTrue(expired.startswith("web_session:"))
+
+            self.assertIsNone(await self.connection.get_session(expired))
+            self.assertEqual(1, await self.connection.purge_sessions("web_session"))
+            self.assertEqual(0, await self.connection.purge_sessions())
+            self.assertEqual({"user": "dave"}, await self.connection.get_session(live))
+
+        asyncio.run(expired_sessions())
+
+
+if __name__ == "__main__":
+    main()
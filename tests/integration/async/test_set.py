@@ -79,6 +79,19 @@ class TestAsyncSet(TestCase):
 
         asyncio.run(set())
 
+    def test_unset(self):
+        self.queries = ["DELETE person;"]
+        query = "CREATE person:100 SET name = $name;"
+
+        async def unset():
+            await self.connection.set("name", "Tobie")
+            await self.connection.unset("name")
+            _ = await self.connection.query(query)
+            outcome = await self.connection.query("SELECT * FROM person;")
+            self.assertEqual([{"id": "person:100"}], outcome)
+
+        asyncio.run(unset())
+
 
 if __name__ == "__main__":
     main()
@@ -0,0 +1,60 @@
+"""
+Tests the approximate distinct counts and percentiles of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from surrealdb.errors import SurrealDbError
+from tests.integration.url import Url
+
+
+class TestAsyncSketch(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+            await self.connection.insert(
+                "order",
+                [
+                    {"id": i, "total": i * 10, "customer": {"name": f"c{i % 3}"}}
+                    for i in range(1, 11)
+                ]
+                + [{"id": 11, "total": "n/a"}],
+            )
+
+        asyncio.run(login())
+
+    def tearDown(self):
+        asyncio.run(self.connection.query("DELETE order;"))
+
+    def test_approx_distinct(self):
+        self.assertEqual(
+            {"count": 10, "distinct": 3},
+            asyncio.run(self.connection.approx_distinct("order", "customer.name", 3)),
+        )
+
+    def test_approx_percentiles(self):
+        async def approx_percentiles():
+            self.assertEqual(
+                {"count": 10, "min": 10.0, "max": 100.0, "percentiles": {0.5: 55.0}},
+                await self.connection.approx_percentiles("order", "total", [0.5], 4),
+            )
+            outcome = await self.connection.approx_percentiles("order", "total")
+            self.assertEqual([0.5, 0.9, 0.99], list(outcome["percentiles"]))
+            with self.assertRaises(SurrealDbError):
+                await self.connection.approx_percentiles("order", "total", [50.0])
+
+        asyncio.run(approx_percentiles())
+
+
+if __name__ == "__main__":
+    main()
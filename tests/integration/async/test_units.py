@@ -0,0 +1,66 @@
+"""
+Tests the unit policy of the AsyncSurrealDB class.
+"""
+
+import asyncio
+from datetime import timedelta
+from decimal import Decimal
+from unittest import TestCase, main
+
+from surrealdb import AsyncSurrealDB
+from tests.integration.url import Url
+
+
+class TestAsyncUnits(TestCase):
+    def setUp(self):
+        self.connection = AsyncSurrealDB(Url().url)
+
+        async def login():
+            await self.connection.connect()
+            await self.connection.signin(
+                {
+                    "username": "root",
+                    "password": "root",
+                }
+            )
+
+        asyncio.run(login())
+        self.connection.set_unit_policy(
+            {"order": {"total": "cents", "delivery.wait": "seconds"}}
+        )
+
+    def tearDown(self):
+        self.connection.clear_unit_policy()
+        asyncio.run(self.connection.query("DELETE order;"))
+
+    def test_units(self):
+        async def units():
+            created = await self.connection.create(
+                "order:1",
+                {"total": Decimal("12.34"), "delivery": {"wait": timedelta(hours=1)}},
+            )
+            self.assertEqual(Decimal("12.34"), created["total"])
+            self.assertEqual(timedelta(hours=1), created["delivery"]["wait"])
+
+            stored = await self.connection.query("SELECT * FROM order;")
+            self.assertEqual(1234, stored[0]["total"])
+            self.assertEqual(3600, stored[0]["delivery"]["wait"])
+
+            selected = await self.connection.select("order:1")
+            self.assertEqual(Decimal("12.34"), selected["total"])
+            rows = await self.connection.query_rows(
+                "SELECT * FROM order;", flatten=True
+            )
+            self.assertEqual(timedelta(hours=1), rows[0]["delivery.wait"])
+
+            merged = await self.connection.merge("order:1", {"total": Decimal("0.5")})
+            self.assertEqual(Decimal("0.5"), merged["total"])
+
+            with self.assertRaises(ValueError):
+                await self.connection.update("order:1", {"total": Decimal("0.001")})
+
+        asyncio.run(units())
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,90 @@
+"""
+Tests the reports on the usage, storage and contents of tables with the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from tests.integration.url import Url
+
+
+class TestAnalysis(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+        self.connection.query("DEFINE TABLE audit_log;")
+        self.connection.query("DEFINE INDEX metric_name ON metric FIELDS name;")
+        self.connection.query("DEFINE INDEX metric_value ON metric FIELDS value;")
+        self.connection.insert(
+            "metric",
+            [
+                {"id": 1, "name": "cpu", "value": 1},
+                {"id": 2, "name": "cpu", "value": 2},
+                {"id": 3, "name": "memory", "value": 3},
+                {"id": 4, "name": None, "value": 4},
+            ],
+        )
+
+    def tearDown(self):
+        self.connection.query("REMOVE TABLE audit_log;")
+        self.connection.query("REMOVE TABLE metric;")
+
+    def test_usage_report(self):
+        report = self.connection.usage_report(
+            ["SELECT * FROM metric WHERE name = 'cpu';", "SELEC nonsense"]
+        )
+        self.assertIn("audit_log", report["unused_tables"])
+        self.assertNotIn("metric", report["unused_tables"])
+        self.assertIn("metric.metric_value", report["unused_indexes"])
+        self.assertNotIn("metric.metric_name", report["unused_indexes"])
+        self.assertEqual(1, report["skipped"])
+
+    def test_storage_report(self):
+        report = {
+            table["table"]: table for table in self.connection.storage_report(2)
+        }
+        self.assertEqual(4, report["metric"]["records"])
+        self.assertEqual(2, report["metric"]["sampled"])
+        self.assertFalse(report["metric"]["exact"])
+        self.assertEqual(0, report["audit_log"]["records"])
+
+    def test_profile(self):
+        profile = self.connection.profile("metric", 10, 1)
+        self.assertEqual(4, profile["sampled"])
+        self.assertEqual(
+            {
+                "count": 3,
+                "nulls": 1,
+                "null_rate": 0.25,
+                "distinct": 2,
+                "min": "cpu",
+                "max": "memory",
+                "top": [{"value": "cpu", "count": 2}],
+            },
+            profile["fields"]["name"],
+        )
+
+    def test_profile_query(self):
+        profile = self.connection.profile_query(
+            "SELECT value FROM metric WHERE value > $value;", {"value": 1}, 2
+        )
+        self.assertEqual(2, profile["sampled"])
+        self.assertEqual(2, profile["fields"]["value"]["min"])
+        self.assertEqual(3, profile["fields"]["value"]["max"])
+
+    def test_sample(self):
+        sample = self.connection.sample("metric", 2, "systematic")
+        self.assertIn(
+            [row["id"] for row in sample],
+            [["metric:1", "metric:3"], ["metric:2", "metric:4"]],
+        )
+        self.assertEqual(4, len(self.connection.sample("metric", 10)))
+
+
+if __name__ == "__main__":
+    main()
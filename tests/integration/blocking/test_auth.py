@@ -17,8 +17,8 @@ class TestAuth(TestCase):
     def tearDown(self):
         pass
 
-    def login(self, username: str, password: str) -> None:
-        self.connection.signin(
+    def login(self, username: str, password: str) -> str:
+        return self.connection.signin(
             {
                 "username": username,
                 "password": password,
@@ -26,7 +26,15 @@ class TestAuth(TestCase):
         )
 
     def test_login_success(self):
-        self.login("root", "root")
+        outcome = self.login("root", "root")
+        self.assertEqual(3, len(outcome.split(".")))
+
+    def test_authenticate_and_invalidate(self):
+        token = self.login("root", "root")
+        self.connection.invalidate()
+        self.connection.authenticate(token)
+        outcome = self.connection.auth_info()
+        self.assertEqual("root", outcome["token"]["ID"])
 
     def test_login_wrong_password(self):
         with self.assertRaises(SurrealDbError) as context:
@@ -0,0 +1,51 @@
+"""
+Tests the content-addressable blob store of the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from surrealdb.errors import SurrealDbError
+from tests.integration.url import Url
+
+HELLO_HASH = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+
+
+class TestBlob(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+        self.connection.query("CREATE user:tobie;")
+
+    def tearDown(self):
+        for table in ["user", "blob", "attachment"]:
+            self.connection.query(f"DELETE {table};")
+
+    def test_store_and_get_blob(self):
+        self.assertEqual(HELLO_HASH, self.connection.store_blob(b"hello"))
+        self.assertEqual(HELLO_HASH, self.connection.store_blob(b"hello"))
+        self.assertEqual(b"hello", self.connection.get_blob(HELLO_HASH))
+        self.assertIsNone(self.connection.get_blob("0" * 64))
+
+    def test_link_and_gc_blobs(self):
+        linked = self.connection.store_blob(b"linked")
+        unlinked = self.connection.store_blob(b"unlinked")
+        self.connection.link_blob("user:tobie", linked)
+        self.connection.link_blob("user:tobie", unlinked)
+        self.connection.unlink_blob("user:tobie", unlinked)
+        with self.assertRaises(SurrealDbError):
+            self.connection.link_blob("user:tobie", "0" * 64)
+
+        self.assertEqual(0, self.connection.gc_blobs())
+        self.assertEqual(1, self.connection.gc_blobs(min_age=0))
+        self.assertIsNone(self.connection.get_blob(unlinked))
+        self.assertEqual(b"linked", self.connection.get_blob(linked))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,54 @@
+"""
+Tests closing the connections of the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from tests.integration.url import Url
+
+
+class TestConnection(TestCase):
+    def tearDown(self):
+        SurrealDB.close_all()
+
+    def test_close(self):
+        connection = SurrealDB(Url().url, keep_connection=True)
+        self.assertIs(
+            connection, SurrealDB(Url().url, existing_connection_id=connection.id)
+        )
+
+        connection.close()
+        self.assertIsNone(connection._connection)
+        self.assertNotIn(connection.id, SurrealDB.instances)
+        connection.close()
+
+    def test_context_manager(self):
+        with SurrealDB(Url().url, keep_connection=True) as connection:
+            self.assertIn(connection.id, SurrealDB.instances)
+            connection.signin({"username": "root", "password": "root"})
+            self.assertEqual([], connection.query("SELECT * FROM person:nobody;"))
+        self.assertIsNone(connection._connection)
+        self.assertNotIn(connection.id, SurrealDB.instances)
+
+    def test_context_manager_main_connection(self):
+        with SurrealDB(Url().url, main_connection=True) as connection:
+            self.assertIs(connection, SurrealDB.main_connection)
+            self.assertIs(connection, SurrealDB(main_connection=True))
+        self.assertIsNone(SurrealDB.main_connection)
+
+    def test_close_all(self):
+        kept = [SurrealDB(Url().url, keep_connection=True) for _ in range(2)]
+        main_connection = SurrealDB(Url().url, main_connection=True)
+
+        SurrealDB.close_all()
+        SurrealDB.close_all()
+        for connection in kept:
+            self.assertNotIn(connection.id, SurrealDB.instances)
+            self.assertIsNone(connection._connection)
+        self.assertIsNone(main_connection._connection)
+        self.assertIsNone(SurrealDB.main_connection)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,38 @@
+"""
+Tests the case-insensitive unique constraints of the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from surrealdb.errors import SurrealDbError
+from tests.integration.url import Url
+
+
+class TestConstraint(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+
+    def tearDown(self):
+        self.connection.query("REMOVE TABLE member;")
+
+    def test_ci_unique(self):
+        self.connection.define_ci_unique("member", "email")
+        self.connection.create("member:1", {"email": "Tobie@SurrealDB.com"})
+        with self.assertRaises(SurrealDbError):
+            self.connection.create("member:2", {"email": " tobie@surrealdb.com"})
+
+        found = self.connection.find_by_ci("member", "email", "TOBIE@surrealdb.com ")
+        self.assertEqual(["member:1"], [record["id"] for record in found])
+        self.assertEqual("Tobie@SurrealDB.com", found[0]["email"])
+        self.assertEqual([], self.connection.find_by_ci("member", "email", "jaime"))
+
+
+if __name__ == "__main__":
+    main()
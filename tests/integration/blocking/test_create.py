@@ -6,6 +6,7 @@ from typing import List
 from unittest import TestCase, main
 
 from surrealdb import SurrealDB
+from surrealdb.errors import InsertError
 from tests.integration.url import Url
 
 
@@ -98,6 +99,41 @@ class TestCreate(TestCase):
             outcome,
         )
 
+    def test_insert(self):
+        self.queries = ["DELETE user;"]
+        outcome = self.connection.insert(
+            "user",
+            [{"id": "tobie", "name": "Tobie"}, {"id": "jaime", "name": "Jaime"}],
+            chunk_size=1,
+        )
+        self.assertEqual(["user:tobie", "user:jaime"], outcome)
+
+        outcome = self.connection.query("SELECT * FROM user;")
+        self.assertEqual(
+            [
+                {"id": "user:jaime", "name": "Jaime"},
+                {"id": "user:tobie", "name": "Tobie"},
+            ],
+            outcome,
+        )
+
+    def test_insert_error_keeps_inserted_ids(self):
+        self.queries = ["REMOVE TABLE user;"]
+        self.connection.query(
+            "DEFINE TABLE user SCHEMAFULL; DEFINE FIELD name ON user TYPE string;"
+        )
+        with self.assertRaises(InsertError) as context:
+            self.connection.insert(
+                "user",
+                [{"id": "tobie", "name": "Tobie"}, {"id": "jaime", "name": 1}],
+                chunk_size=1,
+            )
+        self.assertEqual(["user:tobie"], context.exception.inserted)
+        self.assertEqual("insert", self.connection.error_history()[-1]["route"])
+
+        outcome = self.connection.query("SELECT * FROM user;")
+        self.assertEqual([{"id": "user:tobie", "name": "Tobie"}], outcome)
+
 
 if __name__ == "__main__":
     main()
@@ -0,0 +1,63 @@
+"""
+Tests the client-side field encryption of the SurrealDB class.
+"""
+
+from typing import List
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from tests.integration.url import Url
+
+
+class TestEncryption(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.queries: List[str] = []
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+
+    def tearDown(self):
+        self.connection.clear_encryption_policy()
+        self.connection.clear_tokenization_policy()
+        for query in self.queries:
+            self.connection.query(query)
+
+    def test_create_and_update_encrypted(self):
+        self.queries = ["DELETE person;"]
+        self.connection.set_encryption_policy(bytes(32), {"person": ["ssn"]})
+
+        outcome = self.connection.create(
+            "person:tobie", {"name": "Tobie", "ssn": "123"}
+        )
+        self.assertEqual({"id": "person:tobie", "name": "Tobie", "ssn": "123"}, outcome)
+
+        outcome = self.connection.merge("person:tobie", {"ssn": "456"})
+        self.assertEqual("456", outcome["ssn"])
+
+        stored = self.connection.query("SELECT * FROM person;")
+        self.assertEqual("Tobie", stored[0]["name"])
+        self.assertTrue(stored[0]["ssn"].startswith("enc:v2:"))
+
+    def test_create_tokenized(self):
+        self.queries = ["DELETE person;"]
+        self.connection.set_tokenization_policy(b"secret", {"person": ["email"]})
+
+        self.connection.create("person:tobie", {"email": "tobie@surrealdb.com"})
+        self.connection.create("person:jaime", {"email": "tobie@surrealdb.com"})
+        token = self.connection.tokenize("tobie@surrealdb.com")
+
+        stored = self.connection.query("SELECT * FROM person;")
+        self.assertEqual([token, token], [row["email"] for row in stored])
+        self.assertTrue(token.startswith("tok:v1:"))
+
+    def test_invalid_key(self):
+        with self.assertRaises(ValueError):
+            self.connection.set_encryption_policy(bytes(16), {"person": ["ssn"]})
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,45 @@
+"""
+Tests the injection guard of the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from surrealdb.errors import InjectionSuspected
+from tests.integration.url import Url
+
+
+class TestGuard(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+
+    def tearDown(self):
+        self.connection.clear_injection_guard()
+        self.connection.query("DELETE person;")
+
+    def test_injection_guard(self):
+        allowed = "SELECT * FROM person WHERE status = 'active';"
+        self.connection.set_injection_guard(allow=[allowed])
+
+        with self.assertRaises(InjectionSuspected):
+            self.connection.query("SELECT * FROM person WHERE name = '' OR 1 = 1;")
+        with self.assertRaises(InjectionSuspected):
+            self.connection.query_rows("DELETE person; -- ")
+
+        self.assertEqual([], self.connection.query(allowed))
+        self.assertEqual(
+            [],
+            self.connection.query_rows(
+                "SELECT * FROM person WHERE name = $name;", {"name": "' OR 1 = 1"}
+            ),
+        )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,66 @@
+"""
+Tests reading tables and records as they were in the past with the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from surrealdb.errors import SurrealDbError
+from tests.integration.url import Url
+
+
+class TestHistory(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+        self.connection.query("DEFINE TABLE ledger CHANGEFEED 1h;")
+        self.connection.query("CREATE ledger:tobie SET balance = 10;")
+        self.connection.query("UPDATE ledger:tobie SET balance = 20;")
+        self.connection.query("DELETE ledger:tobie;")
+
+    def tearDown(self):
+        self.connection.query("REMOVE TABLE ledger;")
+
+    def test_read_as_of(self):
+        feed = self.connection.query("SHOW CHANGES FOR TABLE ledger SINCE 0;")
+        versionstamps = [change_set["versionstamp"] for change_set in feed]
+
+        self.assertEqual(
+            [{"id": "ledger:tobie", "balance": 10}],
+            self.connection.read_as_of("ledger", versionstamps[0]),
+        )
+        self.assertEqual(
+            [{"id": "ledger:tobie", "balance": 20}],
+            self.connection.read_as_of("ledger", versionstamp=versionstamps[1]),
+        )
+        self.assertEqual([], self.connection.read_as_of("ledger", versionstamps[2]))
+
+        with self.assertRaises(SurrealDbError):
+            self.connection.read_as_of("ledger")
+
+    def test_record_history(self):
+        history = self.connection.record_history("ledger:tobie")
+        self.assertEqual(
+            ["create", "update", "delete"], [entry["action"] for entry in history]
+        )
+        self.assertEqual({"id": "ledger:tobie", "balance": 10}, history[0]["state"])
+        self.assertEqual(
+            [{"op": "replace", "path": "/balance", "value": 20}], history[1]["diff"]
+        )
+        self.assertIsNone(history[2]["state"])
+
+        self.assertEqual(
+            history[2:],
+            self.connection.record_history(
+                "ledger:tobie", since=history[2]["versionstamp"]
+            ),
+        )
+
+
+if __name__ == "__main__":
+    main()
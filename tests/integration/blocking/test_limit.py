@@ -0,0 +1,47 @@
+"""
+Tests the limit policy of the SurrealDB class.
+"""
+
+from typing import List
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from tests.integration.url import Url
+
+
+class TestLimit(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.queries: List[str] = []
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+
+    def tearDown(self):
+        self.connection.clear_limit_policy()
+        for query in self.queries:
+            self.connection.query(query)
+
+    def test_limit_policy(self):
+        self.queries = ["DELETE person;"]
+        self.connection.query("CREATE person:1; CREATE person:2; CREATE person:3;")
+        self.connection.set_limit_policy(2)
+
+        self.assertEqual(2, len(self.connection.query("SELECT * FROM person;")))
+        self.assertEqual(
+            1, len(self.connection.query("SELECT * FROM person LIMIT 1;"))
+        )
+        self.assertEqual(
+            3,
+            len(self.connection.query("SELECT * FROM person;", unlimited=True)),
+        )
+        self.assertEqual(
+            2, len(self.connection.query_rows("SELECT * FROM person;"))
+        )
+
+
+if __name__ == "__main__":
+    main()
@@ -6,6 +6,7 @@ from typing import List
 from unittest import TestCase, main
 
 from surrealdb import SurrealDB
+from surrealdb.errors import SurrealDbError
 from tests.integration.url import Url
 
 
@@ -44,6 +45,31 @@ class TestMerge(TestCase):
             self.connection.query("SELECT * FROM user;"),
         )
 
+    def test_merge_three_way(self):
+        self.queries = ["DELETE user;"]
+        base = {"id": "user:tobie", "name": "Tobie", "age": 30, "tags": ["a"]}
+        self.connection.query(
+            "CREATE user:tobie SET name = 'Tobie', age = 30, tags = ['a'];"
+        )
+        self.connection.query("UPDATE user:tobie SET age = 31, tags = ['b'];")
+
+        record, conflicts = self.connection.merge_three_way(
+            "user:tobie",
+            base,
+            {"name": "Tobias", "age": 30, "tags": ["c"]},
+            policy="theirs",
+        )
+        self.assertEqual(
+            {"id": "user:tobie", "name": "Tobias", "age": 31, "tags": ["b"]}, record
+        )
+        self.assertEqual(["/tags"], conflicts)
+
+        with self.assertRaises(SurrealDbError):
+            self.connection.merge_three_way("user:tobie", {"age": 30}, {"age": 40})
+        self.assertEqual(
+            31, self.connection.query("SELECT * FROM user:tobie;")[0]["age"]
+        )
+
 
 if __name__ == "__main__":
     main()
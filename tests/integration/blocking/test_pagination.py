@@ -0,0 +1,59 @@
+"""
+Tests the pagination and partitioning of tables with the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from tests.integration.url import Url
+
+
+class TestPagination(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+        self.connection.insert(
+            "person", [{"id": i, "name": f"person {i}"} for i in range(1, 6)]
+        )
+
+    def tearDown(self):
+        self.connection.query("DELETE person;")
+
+    def test_paginate(self):
+        rows, token = self.connection.paginate("person", 2)
+        self.assertEqual(["person:1", "person:2"], [row["id"] for row in rows])
+        self.assertIsInstance(token, str)
+
+        rows, token = self.connection.paginate("person", 2, token)
+        self.assertEqual(["person:3", "person:4"], [row["id"] for row in rows])
+
+        rows, token = self.connection.paginate("person", page_size=2, token=token)
+        self.assertEqual([{"id": "person:5", "name": "person 5"}], rows)
+        self.assertIsNone(token)
+
+    def test_scan(self):
+        self.assertEqual(
+            [f"person {i}" for i in range(1, 6)],
+            [row["name"] for row in self.connection.scan("person", page_size=2)],
+        )
+
+    def test_partition_table(self):
+        partitions = self.connection.partition_table("person", 2)
+        self.assertEqual(2, len(partitions))
+        self.assertEqual(5, sum(partition["count"] for partition in partitions))
+
+        ids = [
+            row["id"]
+            for partition in partitions
+            for row in self.connection.scan(partition["resource"], 2)
+        ]
+        self.assertEqual([f"person:{i}" for i in range(1, 6)], ids)
+
+
+if __name__ == "__main__":
+    main()
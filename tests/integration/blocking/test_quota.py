@@ -0,0 +1,81 @@
+"""
+Tests the client-side quotas of the SurrealDB class.
+"""
+
+from typing import List
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from surrealdb.errors import QuotaExceeded, SurrealDbError
+from tests.integration.url import Url
+
+
+class TestQuota(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.queries: List[str] = []
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+
+    def tearDown(self):
+        self.connection.clear_quota()
+        for query in self.queries:
+            self.connection.query(query)
+
+    def test_max_records(self):
+        self.queries = ["DELETE person;"]
+        records = sum(table["records"] for table in self.connection.storage_report())
+        self.connection.set_quota(max_records=records + 2)
+
+        self.connection.create("person:tobie", {"name": "Tobie"})
+        with self.assertRaises(QuotaExceeded):
+            self.connection.insert(
+                "person", [{"name": "Jaime"}, {"name": "Tobias"}]
+            )
+
+        stored = self.connection.query("SELECT * FROM person;")
+        self.assertEqual(["Tobie"], [row["name"] for row in stored])
+
+    def test_max_bytes(self):
+        self.queries = ["DELETE person;"]
+        size = sum(
+            table["estimated_bytes"] for table in self.connection.storage_report()
+        )
+        self.connection.set_quota(max_bytes=size + 10)
+
+        with self.assertRaises(QuotaExceeded):
+            self.connection.create("person:tobie", {"name": "Tobie" * 10})
+
+    def test_failed_writes_are_not_counted(self):
+        self.queries = ["DELETE person;"]
+        self.connection.query("CREATE person:tobie SET name = 'Tobie';")
+        records = sum(table["records"] for table in self.connection.storage_report())
+        self.connection.set_quota(max_records=records + 1)
+
+        with self.assertRaises(SurrealDbError):
+            self.connection.create("person:tobie", {"name": "Tobie"})
+        self.connection.create("person:jaime", {"name": "Jaime"})
+        with self.assertRaises(QuotaExceeded):
+            self.connection.create("person:dave", {"name": "Dave"})
+
+    def test_update_and_merge(self):
+        self.queries = ["DELETE person;"]
+        self.connection.query("CREATE person:tobie SET name = 'Tobie';")
+        size = sum(
+            table["estimated_bytes"] for table in self.connection.storage_report()
+        )
+        self.connection.set_quota(max_bytes=size + 20)
+
+        with self.assertRaises(QuotaExceeded):
+            self.connection.update("person:tobie", {"name": "Tobie" * 10})
+        with self.assertRaises(QuotaExceeded):
+            self.connection.merge("person:tobie", {"name": "Tobie" * 10})
+        self.connection.merge("person:tobie", {"age": 1})
+
+
+if __name__ == "__main__":
+    main()
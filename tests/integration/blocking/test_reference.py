@@ -0,0 +1,94 @@
+"""
+Tests fetching and checking the record links and graph edges with the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from tests.integration.url import Url
+
+
+class TestReference(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+        self.connection.query("CREATE user:tobie SET name = 'Tobie';")
+        self.connection.query("CREATE user:jaime SET name = 'Jaime';")
+
+    def tearDown(self):
+        for table in ["user", "post", "wrote"]:
+            self.connection.query(f"DELETE {table};")
+
+    def test_fetch_references(self):
+        rows = [
+            {"id": "post:1", "author": "user:tobie", "editors": ["user:jaime"]},
+            {"id": "post:2", "author": "user:gone", "editors": []},
+        ]
+        fetched = self.connection.fetch_references(rows, ["author", "editors"])
+        self.assertEqual(
+            [
+                {
+                    "id": "post:1",
+                    "author": {"id": "user:tobie", "name": "Tobie"},
+                    "editors": [{"id": "user:jaime", "name": "Jaime"}],
+                },
+                {"id": "post:2", "author": "user:gone", "editors": []},
+            ],
+            fetched,
+        )
+        self.assertEqual("user:tobie", rows[0]["author"])
+
+    def test_sideload_references(self):
+        rows = [{"id": "post:1", "author": "user:tobie", "editors": ["user:jaime"]}]
+        self.assertEqual(
+            {
+                "user:tobie": {"id": "user:tobie", "name": "Tobie"},
+                "user:jaime": {"id": "user:jaime", "name": "Jaime"},
+            },
+            self.connection.sideload_references(rows, ["author", "editors"]),
+        )
+
+    def test_check_references(self):
+        self.connection.query("CREATE post:1 SET author = user:tobie;")
+        self.connection.query("CREATE post:2 SET author = user:gone;")
+        self.connection.query("CREATE post:3 SET author = <string> 'user:gone';")
+
+        dangling, token = self.connection.check_references("post", ["author"], 2)
+        self.assertEqual(
+            [{"id": "post:2", "field": "author", "missing": ["user:gone"]}], dangling
+        )
+        dangling, token = self.connection.check_references(
+            "post", ["author"], 2, token
+        )
+        self.assertEqual([], dangling)
+        self.assertIsNone(token)
+
+        self.connection.check_references("post", ["author"], fix="delete")
+        self.assertEqual(
+            [{"id": "post:1"}, {"id": "post:3"}],
+            self.connection.query("SELECT id FROM post;"),
+        )
+
+    def test_clean_orphaned_edges(self):
+        self.connection.query("CREATE post:1;")
+        self.connection.query("RELATE user:tobie->wrote:1->post:1;")
+        self.connection.query("RELATE user:tobie->wrote:2->post:2;")
+        progress = []
+
+        orphans = self.connection.clean_orphaned_edges(
+            "wrote", 1, True, lambda checked, found: progress.append((checked, found))
+        )
+        self.assertEqual([{"id": "wrote:2", "missing": ["post:2"]}], orphans)
+        self.assertEqual((2, 1), progress[-1])
+        self.assertEqual(
+            [{"id": "wrote:1"}], self.connection.query("SELECT id FROM wrote;")
+        )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,52 @@
+"""
+Tests the session store of the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from tests.integration.url import Url
+
+
+class TestSession(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+
+    def tearDown(self):
+        self.connection.query("DELETE session;")
+        self.connection.query("DELETE web_session;")
+
+    def test_session_lifecycle(self):
+        session_id = self.connection.create_session({"user": "tobie"}, 60)
+        self.assertTrue(session_id.startswith("session:"))
+
+        self.assertEqual({"user": "tobie"}, self.connection.get_session(session_id))
+        self.assertEqual(
+            {"user": "tobie"}, self.connection.get_session(session_id, sliding=False)
+        )
+        self.assertTrue(self.connection.touch_session(session_id))
+
+        self.connection.delete_session(session_id)
+        self.assertIsNone(self.connection.get_session(session_id))
+        self.assertFalse(self.connection.touch_session(session_id))
+
+    def test_expired_sessions(self):
+        expired = self.connection.create_session({"user": "tobie"}, 0, "web_session")
+        self.connection.create_session({"user": "jaime"}, 0, table="web_session")
+        live = self.connection.create_session({"user": "dave"}, 60, "web_session")
+        self.assertTrue(expired.startswith("web_session:"))
+
+        self.assertIsNone(self.connection.get_session(expired))
+        self.assertEqual(1, self.connection.purge_sessions("web_session"))
+        self.assertEqual(0, self.connection.purge_sessions())
+        self.assertEqual({"user": "dave"}, self.connection.get_session(live))
+
+
+if __name__ == "__main__":
+    main()
@@ -63,6 +63,16 @@ class TestSet(TestCase):
             outcome,
         )
 
+    def test_unset(self):
+        self.queries = ["DELETE person;"]
+        query = "CREATE person:100 SET name = $name;"
+
+        self.connection.set("name", "Tobie")
+        self.connection.unset("name")
+        _ = self.connection.query(query)
+        outcome = self.connection.query("SELECT * FROM person;")
+        self.assertEqual([{"id": "person:100"}], outcome)
+
 
 if __name__ == "__main__":
     main()
@@ -0,0 +1,53 @@
+"""
+Tests the approximate distinct counts and percentiles of the SurrealDB class.
+"""
+
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from surrealdb.errors import SurrealDbError
+from tests.integration.url import Url
+
+
+class TestSketch(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+        self.connection.insert(
+            "order",
+            [
+                {"id": i, "total": i * 10, "customer": {"name": f"c{i % 3}"}}
+                for i in range(1, 11)
+            ]
+            + [{"id": 11, "total": "n/a"}],
+        )
+
+    def tearDown(self):
+        self.connection.query("DELETE order;")
+
+    def test_approx_distinct(self):
+        self.assertEqual(
+            {"count": 10, "distinct": 3},
+            self.connection.approx_distinct("order", "customer.name", 3),
+        )
+
+    def test_approx_percentiles(self):
+        self.assertEqual(
+            {"count": 10, "min": 10.0, "max": 100.0, "percentiles": {0.5: 55.0}},
+            self.connection.approx_percentiles("order", "total", [0.5], 4),
+        )
+        self.assertEqual(
+            [0.5, 0.9, 0.99],
+            list(self.connection.approx_percentiles("order", "total")["percentiles"]),
+        )
+        with self.assertRaises(SurrealDbError):
+            self.connection.approx_percentiles("order", "total", [50.0])
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,55 @@
+"""
+Tests the unit policy of the SurrealDB class.
+"""
+
+from datetime import timedelta
+from decimal import Decimal
+from unittest import TestCase, main
+
+from surrealdb import SurrealDB
+from tests.integration.url import Url
+
+
+class TestUnits(TestCase):
+    def setUp(self):
+        self.connection = SurrealDB(Url().url)
+        self.connection.signin(
+            {
+                "username": "root",
+                "password": "root",
+            }
+        )
+        self.connection.set_unit_policy(
+            {"order": {"total": "cents", "delivery.wait": "seconds"}}
+        )
+
+    def tearDown(self):
+        self.connection.clear_unit_policy()
+        self.connection.query("DELETE order;")
+
+    def test_units(self):
+        created = self.connection.create(
+            "order:1",
+            {"total": Decimal("12.34"), "delivery": {"wait": timedelta(hours=1)}},
+        )
+        self.assertEqual(Decimal("12.34"), created["total"])
+        self.assertEqual(timedelta(hours=1), created["delivery"]["wait"])
+
+        stored = self.connection.query("SELECT * FROM order;")
+        self.assertEqual(1234, stored[0]["total"])
+        self.assertEqual(3600, stored[0]["delivery"]["wait"])
+
+        selected = self.connection.select("order:1")
+        self.assertEqual(Decimal("12.34"), selected["total"])
+        rows = self.connection.query_rows("SELECT * FROM order;", flatten=True)
+        self.assertEqual(timedelta(hours=1), rows[0]["delivery.wait"])
+
+        merged = self.connection.merge("order:1", {"total": Decimal("0.5")})
+        self.assertEqual(Decimal("0.5"), merged["total"])
+
+        with self.assertRaises(ValueError):
+            self.connection.update("order:1", {"total": Decimal("0.001")})
+
+
+if __name__ == "__main__":
+    main()